@@ -0,0 +1,99 @@
+#![allow(clippy::needless_return)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use rust_json::{
+    build_index, decode_string, locate_pointer, parse, parse_borrowed, unescape_cow, TokenKind,
+    Tokenizer, UTF8Reader,
+};
+
+/// Records made mostly of plain ASCII strings, so nearly nothing needs
+/// unescaping.
+fn string_heavy() -> String {
+    let records: Vec<String> = (0..5_000)
+        .map(|i| {
+            format!(
+                "{{\"id\": \"record-{}\", \"title\": \"The quick brown fox jumps over the lazy dog\", \"tags\": [\"alpha\", \"beta\", \"gamma\"]}}",
+                i
+            )
+        })
+        .collect();
+    return format!("[{}]", records.join(","));
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let input = string_heavy();
+    let document = UTF8Reader::new(&input);
+
+    let mut group = c.benchmark_group("parse_string_heavy");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("owned", |b| b.iter(|| parse(black_box(&document))));
+    group.bench_function("borrowed", |b| {
+        b.iter(|| parse_borrowed(black_box(&document)))
+    });
+    group.finish();
+}
+
+fn bench_unescape(c: &mut Criterion) {
+    let input = string_heavy();
+    let document = UTF8Reader::new(&input);
+    let starts: Vec<usize> = Tokenizer::new(&document)
+        .filter_map(Result::ok)
+        .filter(|token| token.kind == TokenKind::String)
+        .map(|token| token.start)
+        .collect();
+
+    let mut group = c.benchmark_group("unescape_string_heavy");
+    group.throughput(Throughput::Elements(starts.len() as u64));
+    group.bench_function("decode_string", |b| {
+        b.iter(|| {
+            for start in &starts {
+                let _ = black_box(decode_string(&document, *start));
+            }
+        })
+    });
+    group.bench_function("unescape_cow", |b| {
+        b.iter(|| {
+            for start in &starts {
+                let _ = black_box(unescape_cow(&document, *start));
+            }
+        })
+    });
+    group.finish();
+}
+
+/// Many lookups against one document, as a service extracting fields from
+/// the same payload would make.
+fn bench_pointer_lookup(c: &mut Criterion) {
+    let input = string_heavy();
+    let document = UTF8Reader::new(&input);
+    let pointers: Vec<String> = (0..5_000)
+        .step_by(50)
+        .map(|i| format!("/{}/tags/2", i))
+        .collect();
+    let index = build_index(&document).unwrap();
+
+    let mut group = c.benchmark_group("pointer_lookup_string_heavy");
+    group.throughput(Throughput::Elements(pointers.len() as u64));
+    group.bench_function("locate_pointer", |b| {
+        b.iter(|| {
+            for pointer in &pointers {
+                black_box(locate_pointer(&document, pointer));
+            }
+        })
+    });
+    group.bench_function("index_get", |b| {
+        b.iter(|| {
+            for pointer in &pointers {
+                black_box(index.get(pointer));
+            }
+        })
+    });
+    group.bench_function("build_index", |b| {
+        b.iter(|| build_index(black_box(&document)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_unescape, bench_pointer_lookup);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+#![allow(clippy::needless_return)]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use rust_json::{validate, UTF8Reader};
+
+/// Counts allocations, so the benchmark can report them next to the timings.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        return System.alloc(layout);
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Many small request bodies, as a server would see them.
+fn small_documents() -> Vec<String> {
+    return (0..1_000)
+        .map(|i| {
+            format!(
+                "{{\"id\": {}, \"name\": \"user-{}\", \"active\": true}}",
+                i, i
+            )
+        })
+        .collect();
+}
+
+fn with_new(documents: &[String]) {
+    for document in documents.iter() {
+        let reader = UTF8Reader::new(document);
+        let _ = black_box(validate(&reader));
+    }
+}
+
+fn with_reset(documents: &[String]) {
+    let mut reader = UTF8Reader::new("");
+    for document in documents.iter() {
+        reader.reset(document);
+        let _ = black_box(validate(&reader));
+    }
+}
+
+fn allocations_of<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    return ALLOCATIONS.load(Ordering::Relaxed) - before;
+}
+
+fn bench_reader(c: &mut Criterion) {
+    let documents = small_documents();
+
+    println!(
+        "allocations for {} documents: new {}, reset {}",
+        documents.len(),
+        allocations_of(|| with_new(&documents)),
+        allocations_of(|| with_reset(&documents))
+    );
+
+    let mut group = c.benchmark_group("reader_small_documents");
+    group.bench_function("new", |b| b.iter(|| with_new(black_box(&documents))));
+    group.bench_function("reset", |b| b.iter(|| with_reset(black_box(&documents))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_reader);
+criterion_main!(benches);
@@ -0,0 +1,111 @@
+#![allow(clippy::needless_return)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use rust_json::{validate_str, validate_with_warnings, UTF8Reader, ValidateOptions};
+
+/// Objects nested just under the depth limit, each with a few members.
+fn nested_object() -> String {
+    let depth = 99;
+    let mut document = String::new();
+    for level in 0..depth {
+        document.push_str(&format!(
+            "{{\"level\": {}, \"name\": \"node\", \"child\": ",
+            level
+        ));
+    }
+    document.push_str("null");
+    for _ in 0..depth {
+        document.push('}');
+    }
+    return document;
+}
+
+/// A flat array of integers, decimals and exponents.
+fn flat_numbers() -> String {
+    let numbers: Vec<String> = (0..50_000)
+        .map(|i| match i % 3 {
+            0 => format!("{}", i),
+            1 => format!("-{}.{}", i, i % 97),
+            _ => format!("{}e-{}", i, i % 12),
+        })
+        .collect();
+    return format!("[{}]", numbers.join(","));
+}
+
+/// Records made mostly of plain ASCII strings.
+fn string_heavy() -> String {
+    let records: Vec<String> = (0..5_000)
+        .map(|i| {
+            format!(
+                "{{\"id\": \"record-{}\", \"title\": \"The quick brown fox jumps over the lazy dog\", \"tags\": [\"alpha\", \"beta\", \"gamma\"]}}",
+                i
+            )
+        })
+        .collect();
+    return format!("[{}]", records.join(","));
+}
+
+/// Strings full of escape sequences and non-ASCII text.
+fn escaped_unicode() -> String {
+    let records: Vec<String> = (0..5_000)
+        .map(|i| {
+            format!(
+                "{{\"text\": \"line {}\\n\\t\\\"quoted\\\" \\u00e9\\u4e2d\\\\path\\/to\", \"native\": \"café 中文 ελληνικά 😀\"}}",
+                i
+            )
+        })
+        .collect();
+    return format!("[{}]", records.join(","));
+}
+
+/// A pretty-printed array with a negative zero on every line, so each line
+/// produces a warning whose position must be looked up.
+fn many_warnings() -> String {
+    let lines: Vec<String> = (0..20_000)
+        .map(|i| format!("  {{\"id\": {}, \"offset\": -0.0}}", i))
+        .collect();
+    return format!("[\n{}\n]", lines.join(",\n"));
+}
+
+fn bench_validate_str(c: &mut Criterion) {
+    let fixtures = [
+        ("nested_object", nested_object()),
+        ("flat_numbers", flat_numbers()),
+        ("string_heavy", string_heavy()),
+        ("escaped_unicode", escaped_unicode()),
+    ];
+
+    let mut group = c.benchmark_group("validate_str");
+    for (name, document) in fixtures.iter() {
+        assert!(
+            validate_str(document).is_ok(),
+            "fixture {} is invalid",
+            name
+        );
+
+        group.throughput(Throughput::Bytes(document.len() as u64));
+        group.bench_function(*name, |b| b.iter(|| validate_str(black_box(document))));
+    }
+    group.finish();
+}
+
+fn bench_many_warnings(c: &mut Criterion) {
+    let document = many_warnings();
+    let options = ValidateOptions::default().warn_negative_zero(true);
+
+    let mut group = c.benchmark_group("validate_with_warnings");
+    group.throughput(Throughput::Bytes(document.len() as u64));
+    group.bench_function("many_warnings", |b| {
+        b.iter(|| {
+            let reader = UTF8Reader::new(black_box(&document));
+            let (result, warnings) = validate_with_warnings(&reader, &options);
+            assert!(result.is_ok());
+            assert_eq!(warnings.len(), 20_000);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_validate_str, bench_many_warnings);
+criterion_main!(benches);
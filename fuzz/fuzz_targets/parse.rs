@@ -0,0 +1,30 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_json::{parse, to_string, validate, ErrorKind, UTF8Reader};
+
+// The validator and the parser must agree, and whatever parses must read
+// back as the same value once serialized. The one exception is an unpaired
+// surrogate escape, which is valid JSON but can not be decoded into a
+// `String`.
+fuzz_target!(|data: &[u8]| {
+    let text = match std::str::from_utf8(data) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+
+    let document = UTF8Reader::new(text);
+    let accepted = validate(&document).is_ok();
+
+    match parse(&document) {
+        Ok(value) => {
+            assert!(accepted, "parsed a document the validator rejects");
+
+            let output = to_string(&value).expect("a parsed value should serialize");
+            let reparsed = parse(&UTF8Reader::new(&output)).expect("serialized output should parse");
+            assert_eq!(reparsed, value);
+        }
+        Err(error) if error.kind() == &ErrorKind::UnpairedSurrogate => {}
+        Err(_) => assert!(!accepted, "validated a document the parser rejects"),
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_json::{validate_bytes, validate_with_options, UTF8Reader, ValidateOptions};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = validate_bytes(data);
+
+    if let Ok(text) = std::str::from_utf8(data) {
+        let document = UTF8Reader::new(text);
+        let _ = validate_with_options(&document, &ValidateOptions::json5());
+    }
+});
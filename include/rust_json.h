@@ -0,0 +1,27 @@
+#ifndef RUST_JSON_H
+#define RUST_JSON_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define RUST_JSON_OK 0
+#define RUST_JSON_INVALID (-1)
+#define RUST_JSON_NULL_POINTER (-2)
+#define RUST_JSON_PANIC (-3)
+
+/* Validates the `len` bytes at `ptr` as JSON. Unless `err_out` is NULL it
+ * receives a description of the failure, or NULL on success; release it
+ * with rust_json_free. */
+int32_t rust_json_validate(const uint8_t *ptr, size_t len, char **err_out);
+
+void rust_json_free(char *message);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
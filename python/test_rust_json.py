@@ -0,0 +1,32 @@
+"""Run with pytest after building the extension, see the README."""
+
+import pytest
+
+import rust_json
+
+
+def test_validate_accepts_valid_json():
+    assert rust_json.validate('{"a": [1, 2]}') is None
+
+
+def test_validate_reports_line_and_column():
+    with pytest.raises(ValueError, match=r"line 2, column 5"):
+        rust_json.validate('[1,\n 2, }')
+
+
+def test_parse_builds_native_values():
+    assert rust_json.parse('{"a": [1, 2.5, "x", true, null], "b": {}}') == {
+        "a": [1, 2.5, "x", True, None],
+        "b": {},
+    }
+
+
+def test_parse_maps_numbers_to_int_or_float():
+    values = rust_json.parse("[1, -7, 1.0, 1e2, 123456789012345678901234567890]")
+    assert [type(v) for v in values] == [int, int, float, float, int]
+    assert values[4] == 123456789012345678901234567890
+
+
+def test_parse_rejects_invalid_json():
+    with pytest.raises(ValueError):
+        rust_json.parse("[1,]")
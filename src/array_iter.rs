@@ -0,0 +1,150 @@
+use alloc::{format, string::String};
+
+use super::error::{ErrorKind, Fault, ValidationError};
+use super::utils::{UTF8Reader, UTF8ReaderResult};
+use super::validator::{
+    end_of_input_fault, scan_value, skip_whitespace, trailing_comma_fault, trailing_data_reason,
+    ValidateOptions,
+};
+
+/// Lazily walks the elements of a top-level array; see `iter_top_level_array`.
+pub struct ArrayIter<'r, 'a> {
+    document: &'r UTF8Reader<'a>,
+    options: ValidateOptions,
+    ptr: usize,
+    position: usize,
+    state: State,
+}
+
+enum State {
+    FirstElement,
+    PostElement,
+    Done,
+}
+
+/// Checks that the document is an array and returns an iterator over its
+/// elements. Each element is validated only when the iterator reaches it and
+/// is yielded as its start and end indices, so memory use stays constant
+/// however long the array is. Use `UTF8Reader::look_ahead` for the text of
+/// an element.
+///
+/// After the last element the rest of the document is checked as well. The
+/// first error found ends the iteration.
+pub fn iter_top_level_array<'r, 'a>(
+    document: &'r UTF8Reader<'a>,
+) -> Result<ArrayIter<'r, 'a>, ValidationError> {
+    let options = ValidateOptions::default();
+    let start = skip_whitespace(document, 0, &options);
+
+    return match document.look_ahead(start, 1) {
+        UTF8ReaderResult::Ok("[") => Ok(ArrayIter {
+            document,
+            options,
+            ptr: start + 1,
+            position: 0,
+            state: State::FirstElement,
+        }),
+        UTF8ReaderResult::Ok(chr) => {
+            let reason = format!("Top-level value should be an array, but found \"{}\"", chr);
+            let fault = Fault::new(ErrorKind::UnexpectedTopLevel, reason);
+            Err(ValidationError::from_fault(document, start, 0, fault))
+        }
+        UTF8ReaderResult::OutOfBoundError(_) => {
+            let (index, reason) = match document.is_empty() {
+                true => (0, "JSON document can not be empty"),
+                false => (start, "No valid JSON value found"),
+            };
+            let fault = Fault::new(ErrorKind::EmptyDocument, String::from(reason));
+            Err(ValidationError::from_fault(document, index, 0, fault))
+        }
+    };
+}
+
+impl<'r, 'a> ArrayIter<'r, 'a> {
+    fn fail(
+        &mut self,
+        index: usize,
+        fault: Fault,
+    ) -> Option<Result<(usize, usize), ValidationError>> {
+        self.state = State::Done;
+        return Some(Err(ValidationError::from_fault(
+            self.document,
+            index,
+            0,
+            fault,
+        )));
+    }
+
+    /// Ends the iteration at the closing bracket, checking that nothing but
+    /// whitespace follows it.
+    fn close(&mut self, index: usize) -> Option<Result<(usize, usize), ValidationError>> {
+        self.state = State::Done;
+
+        let rest = skip_whitespace(self.document, index + 1, &self.options);
+        if rest < self.document.len() {
+            let reason = trailing_data_reason(self.document, rest, &self.options);
+            return self.fail(rest, Fault::new(ErrorKind::TrailingData, reason));
+        }
+        return None;
+    }
+
+    fn element(&mut self, index: usize) -> Option<Result<(usize, usize), ValidationError>> {
+        let (result, step) = scan_value(self.document, index, 1, &self.options);
+
+        if let Err(fault) = result {
+            self.state = State::Done;
+            let position = self.position;
+            let fault = fault.in_element(position);
+            let error = ValidationError::from_fault(self.document, index + step, 0, fault);
+            return Some(Err(error));
+        }
+
+        self.ptr = index + step;
+        self.position += 1;
+        self.state = State::PostElement;
+        return Some(Ok((index, index + step)));
+    }
+}
+
+impl<'r, 'a> Iterator for ArrayIter<'r, 'a> {
+    type Item = Result<(usize, usize), ValidationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = skip_whitespace(self.document, self.ptr, &self.options);
+        let chr = match self.document.look_ahead(index, 1) {
+            UTF8ReaderResult::Ok(chr) => chr,
+            UTF8ReaderResult::OutOfBoundError(_) => match self.state {
+                State::Done => return None,
+                State::FirstElement => {
+                    return self.fail(index, end_of_input_fault("a value or \"]\""))
+                }
+                State::PostElement => {
+                    return self.fail(index, end_of_input_fault("\",\" or \"]\""))
+                }
+            },
+        };
+
+        return match self.state {
+            State::Done => None,
+            State::FirstElement if chr == "]" => self.close(index),
+            State::FirstElement => self.element(index),
+            State::PostElement => match chr {
+                "]" => self.close(index),
+                "," => {
+                    let next = skip_whitespace(self.document, index + 1, &self.options);
+                    match self.document.look_ahead(next, 1) {
+                        UTF8ReaderResult::Ok("]") => self.fail(next, trailing_comma_fault("]")),
+                        UTF8ReaderResult::Ok(_) => self.element(next),
+                        UTF8ReaderResult::OutOfBoundError(_) => {
+                            self.fail(next, end_of_input_fault("the next element"))
+                        }
+                    }
+                }
+                _ => self.fail(
+                    index,
+                    Fault::from(format!("Invalid character: \"{}\"", chr)),
+                ),
+            },
+        };
+    }
+}
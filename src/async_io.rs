@@ -0,0 +1,26 @@
+use alloc::vec;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::error::JsonFileError;
+use super::utils::ChunkedUtf8Validator;
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Validates a document as it is read from `reader`, without waiting for
+/// the whole of it. A multi-byte character split between two reads is held
+/// back until the rest of it arrives.
+pub async fn validate_async<R: AsyncRead + Unpin>(mut reader: R) -> Result<(), JsonFileError> {
+    let mut validator = ChunkedUtf8Validator::new();
+    let mut buffer = vec![0; CHUNK_SIZE];
+
+    loop {
+        let read = reader.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        validator.feed(&buffer[..read])?;
+    }
+
+    validator.finish()?;
+    return Ok(());
+}
@@ -0,0 +1,127 @@
+use alloc::{borrow::Cow, vec, vec::Vec};
+
+use super::cursor::{Cursor, Token, TokenKind};
+use super::decode::unescape_validated_string;
+use super::error::{Fault, ValidationError};
+use super::utils::UTF8Reader;
+use super::validator::{validate_with_options, ValidateOptions};
+use super::value::{JsonNumber, JsonValue};
+
+/// A parsed value that borrows from the document where it can. Strings
+/// without escape sequences, and every number lexeme, point into the input;
+/// only strings that need unescaping are allocated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValueRef<'a> {
+    Null,
+    Bool(bool),
+    /// The number as written in the source.
+    Number(&'a str),
+    String(Cow<'a, str>),
+    Array(Vec<JsonValueRef<'a>>),
+    Object(Vec<(Cow<'a, str>, JsonValueRef<'a>)>),
+}
+
+impl<'a> From<JsonValueRef<'a>> for JsonValue {
+    fn from(value: JsonValueRef<'a>) -> Self {
+        return match value {
+            JsonValueRef::Null => JsonValue::Null,
+            JsonValueRef::Bool(b) => JsonValue::Bool(b),
+            JsonValueRef::Number(lexeme) => JsonValue::Number(JsonNumber::new(lexeme)),
+            JsonValueRef::String(s) => JsonValue::String(s.into_owned()),
+            JsonValueRef::Array(elements) => {
+                JsonValue::Array(elements.into_iter().map(JsonValue::from).collect())
+            }
+            JsonValueRef::Object(members) => JsonValue::Object(
+                members
+                    .into_iter()
+                    .map(|(key, value)| (key.into_owned(), JsonValue::from(value)))
+                    .collect(),
+            ),
+        };
+    }
+}
+
+/// Like `parse`, but strings and numbers borrow from the document instead
+/// of being copied.
+pub fn parse_borrowed<'a>(document: &UTF8Reader<'a>) -> Result<JsonValueRef<'a>, ValidationError> {
+    return parse_borrowed_with_options(document, &ValidateOptions::default());
+}
+
+pub fn parse_borrowed_with_options<'a>(
+    document: &UTF8Reader<'a>,
+    options: &ValidateOptions,
+) -> Result<JsonValueRef<'a>, ValidationError> {
+    validate_with_options(document, options)?;
+
+    let mut cursor = Cursor::new(document, options);
+    let origin = cursor.position();
+    let token = cursor.next_token();
+    return build_value(&mut cursor, token).map_err(|(fault, index)| {
+        return ValidationError::from_fault(document, index, origin, fault);
+    });
+}
+
+/// Builds the value that starts with `token`, leaving `cursor` past it. The
+/// document must already be known to be valid; only decoding an escaped
+/// string can fail.
+fn build_value<'a>(
+    cursor: &mut Cursor<'_, 'a>,
+    token: Token<'a>,
+) -> Result<JsonValueRef<'a>, (Fault, usize)> {
+    match token.kind {
+        TokenKind::BeginObject => {
+            let mut members = vec![];
+            loop {
+                let key = cursor.next_token();
+                match key.kind {
+                    TokenKind::Comma => {}
+                    TokenKind::Key => {
+                        // Past the ":"
+                        cursor.next_token();
+                        let value = cursor.next_token();
+                        members.push((build_string(cursor, key)?, build_value(cursor, value)?));
+                    }
+                    _ => return Ok(JsonValueRef::Object(members)),
+                }
+            }
+        }
+        TokenKind::BeginArray => {
+            let mut elements = vec![];
+            loop {
+                let element = cursor.next_token();
+                match element.kind {
+                    TokenKind::Comma => {}
+                    TokenKind::EndArray | TokenKind::End => {
+                        return Ok(JsonValueRef::Array(elements))
+                    }
+                    _ => elements.push(build_value(cursor, element)?),
+                }
+            }
+        }
+        _ => {
+            return match token.lexeme {
+                "true" => Ok(JsonValueRef::Bool(true)),
+                "false" => Ok(JsonValueRef::Bool(false)),
+                "null" => Ok(JsonValueRef::Null),
+                lexeme if lexeme.starts_with('"') || lexeme.starts_with('\'') => {
+                    build_string(cursor, token).map(JsonValueRef::String)
+                }
+                lexeme => Ok(JsonValueRef::Number(lexeme)),
+            };
+        }
+    }
+}
+
+/// Borrows the content of a string or identifier unless it has escapes.
+fn build_string<'a>(
+    cursor: &Cursor<'_, 'a>,
+    token: Token<'a>,
+) -> Result<Cow<'a, str>, (Fault, usize)> {
+    if !token.lexeme.starts_with('"') && !token.lexeme.starts_with('\'') {
+        return Ok(Cow::Borrowed(token.lexeme));
+    }
+
+    let width = token.end - token.start;
+    return unescape_validated_string(cursor.document(), token.start, width, cursor.options())
+        .map_err(|(fault, offset)| (fault, token.start + offset));
+}
@@ -0,0 +1,138 @@
+use super::error::ValidationError;
+use super::parser::parse_with_options;
+use super::utils::UTF8Reader;
+use super::validator::{
+    check_document_size, validate_bytes_with_options, validate_str_with_options, BomPolicy,
+    LoneSurrogatePolicy, SolidusPolicy, TopLevelType, ValidateOptions, ValidateResult,
+};
+use super::value::JsonValue;
+
+/// A chainable way to configure validation and parsing, for callers who
+/// would rather not build a `ValidateOptions` themselves. Each setter has
+/// the name of the option it sets, and `ValidateOptions` has the same one.
+///
+/// ```
+/// use rust_json::Validator;
+///
+/// let validator = Validator::new()
+///     .max_depth(200)
+///     .allow_single_quotes(true)
+///     .allow_unquoted_keys(true)
+///     .max_string_length(64);
+///
+/// assert!(validator.validate("{ name: 'rust-json' }").is_ok());
+/// assert!(validator.validate(&format!("['{}']", "x".repeat(65))).is_err());
+///
+/// let nested = format!("{}{}", "[".repeat(150), "]".repeat(150));
+/// assert!(validator.validate(&nested).is_ok());
+/// assert!(Validator::new().validate(&nested).is_err());
+/// ```
+///
+/// One validator can be reused for any number of documents:
+///
+/// ```
+/// use rust_json::{JsonValue, Validator};
+///
+/// let validator = Validator::json5().max_bytes(1024);
+///
+/// let value = validator.parse("[+1, .5, 0xFF]").unwrap();
+/// assert_eq!(value.get_index(2).and_then(JsonValue::as_i64), Some(255));
+///
+/// let error = validator.validate_bytes(b"[\"\xFF\"]").unwrap_err();
+/// assert_eq!(error.byte_offset(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Validator {
+    options: ValidateOptions,
+}
+
+impl Validator {
+    /// Plain RFC 8259 JSON with no limits but the default nesting depth of
+    /// 100 levels.
+    pub fn new() -> Self {
+        return Validator::default();
+    }
+
+    /// Starts from `ValidateOptions::json5()`.
+    pub fn json5() -> Self {
+        return Validator::from(ValidateOptions::json5());
+    }
+
+    pub fn options(&self) -> &ValidateOptions {
+        return &self.options;
+    }
+
+    pub fn validate(&self, input: &str) -> ValidateResult {
+        return validate_str_with_options(input, &self.options);
+    }
+
+    pub fn validate_bytes(&self, input: &[u8]) -> ValidateResult {
+        return validate_bytes_with_options(input, &self.options);
+    }
+
+    pub fn parse(&self, input: &str) -> Result<JsonValue, ValidationError> {
+        check_document_size(input.len(), &self.options)?;
+        return parse_with_options(&UTF8Reader::new(input), &self.options);
+    }
+}
+
+impl From<ValidateOptions> for Validator {
+    fn from(options: ValidateOptions) -> Self {
+        return Validator { options };
+    }
+}
+
+/// Defines a chainable setter named after each option's field on both
+/// `ValidateOptions` and `Validator`, so that the two spell every option the
+/// same way and a new option is added in one place.
+macro_rules! option_setters {
+    ($($name:ident($arg:ident: $type:ty) => $value:expr;)*) => {
+        impl ValidateOptions {
+            $(
+                pub fn $name(mut self, $arg: $type) -> Self {
+                    self.$name = $value;
+                    return self;
+                }
+            )*
+        }
+
+        impl Validator {
+            $(
+                pub fn $name(mut self, $arg: $type) -> Self {
+                    self.options.$name = $value;
+                    return self;
+                }
+            )*
+        }
+    };
+}
+
+// Limits are optional fields, set to `Some`.
+option_setters! {
+    bom_policy(policy: BomPolicy) => policy;
+    require_top_level(top_level: TopLevelType) => top_level;
+    solidus_policy(policy: SolidusPolicy) => policy;
+    lone_surrogate_policy(policy: LoneSurrogatePolicy) => policy;
+    max_bytes(limit: usize) => Some(limit);
+    max_depth(limit: usize) => Some(limit);
+    max_string_length(limit: usize) => Some(limit);
+    max_escapes_per_string(limit: usize) => Some(limit);
+    max_decoded_string_bytes(limit: usize) => Some(limit);
+    max_array_elements(limit: usize) => Some(limit);
+    max_object_members(limit: usize) => Some(limit);
+    max_total_nodes(limit: usize) => Some(limit);
+    max_exponent_digits(limit: usize) => Some(limit);
+    warn_depth(limit: usize) => Some(limit);
+    allow_single_quotes(allow: bool) => allow;
+    allow_unquoted_keys(allow: bool) => allow;
+    allow_non_finite(allow: bool) => allow;
+    allow_leading_plus(allow: bool) => allow;
+    allow_hex_numbers(allow: bool) => allow;
+    allow_leading_zeros(allow: bool) => allow;
+    allow_bare_decimal(allow: bool) => allow;
+    allow_unicode_whitespace(allow: bool) => allow;
+    forbid_js_unsafe_separators(forbid: bool) => forbid;
+    warn_negative_zero(warn: bool) => warn;
+    warn_precision_loss(warn: bool) => warn;
+    warn_noncharacters(warn: bool) => warn;
+}
@@ -0,0 +1,76 @@
+use alloc::string::ToString;
+use core::ptr;
+use core::slice;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+
+use super::validator::validate_bytes;
+
+/// The input is valid JSON.
+pub const RUST_JSON_OK: i32 = 0;
+/// The input is not valid JSON, or not UTF-8.
+pub const RUST_JSON_INVALID: i32 = -1;
+/// `ptr` was null while `len` was not zero.
+pub const RUST_JSON_NULL_POINTER: i32 = -2;
+/// The validator panicked; this is a bug.
+pub const RUST_JSON_PANIC: i32 = -3;
+
+/// Validates the `len` bytes at `ptr` as a JSON document, returning
+/// `RUST_JSON_OK` or one of the negative `RUST_JSON_*` codes.
+///
+/// Unless `err_out` is null, it receives a NUL-terminated description of the
+/// failure, to be released with `rust_json_free`, or null when there is
+/// nothing to report.
+///
+/// # Safety
+///
+/// `ptr` must point to `len` readable bytes, and `err_out` must be null or
+/// point to writable storage for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rust_json_validate(
+    ptr: *const u8,
+    len: usize,
+    err_out: *mut *mut c_char,
+) -> i32 {
+    let (code, message) = if ptr.is_null() && len > 0 {
+        (
+            RUST_JSON_NULL_POINTER,
+            Some("Input pointer is null".to_string()),
+        )
+    } else {
+        let input = if len == 0 {
+            &[][..]
+        } else {
+            slice::from_raw_parts(ptr, len)
+        };
+        match panic::catch_unwind(AssertUnwindSafe(|| validate_bytes(input))) {
+            Ok(Ok(_)) => (RUST_JSON_OK, None),
+            Ok(Err(error)) => (RUST_JSON_INVALID, Some(error.to_string())),
+            Err(_) => (RUST_JSON_PANIC, Some("Validator panicked".to_string())),
+        }
+    };
+
+    if !err_out.is_null() {
+        *err_out = match message {
+            // A reason may quote a NUL from the input, which a C string can not hold.
+            Some(message) => CString::new(message.replace('\0', "\\u0000"))
+                .map_or(ptr::null_mut(), CString::into_raw),
+            None => ptr::null_mut(),
+        };
+    }
+    return code;
+}
+
+/// Releases an error string written by `rust_json_validate`. Null is ignored.
+///
+/// # Safety
+///
+/// `message` must be null or a pointer from `rust_json_validate` that has not
+/// been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn rust_json_free(message: *mut c_char) {
+    if !message.is_null() {
+        drop(CString::from_raw(message));
+    }
+}
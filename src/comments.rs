@@ -0,0 +1,175 @@
+use alloc::{format, string::String, vec, vec::Vec};
+use core::mem;
+
+use super::error::{member_segment, ValidationError};
+use super::spanned::{parse_with_spans, SpannedKind, SpannedValue};
+use super::utils::{UTF8Reader, UTF8ReaderResult};
+use super::value::JsonValue;
+
+/// A `// line` or `/* block */` comment found by `parse_with_comments`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    /// What is between the markers, up to but not including the line break
+    /// that ends a line comment.
+    pub text: String,
+    /// The start and end indices of the comment, markers included.
+    pub span: (usize, usize),
+    /// The path, such as `$.server.port`, of the first key or value that
+    /// starts after the comment; `None` if nothing does.
+    pub path: Option<String>,
+}
+
+/// Parses JSON with comments, as found in JSONC configuration files, and
+/// returns the comments next to the value instead of dropping them. A
+/// comment above a key is attached to that member, which is what
+/// documentation tools usually want.
+///
+/// Positions in errors are those of the original input.
+pub fn parse_with_comments(
+    document: &UTF8Reader,
+) -> Result<(JsonValue, Vec<Comment>), ValidationError> {
+    let (blanked, mut comments) = blank_comments(document)?;
+
+    // Every unit of a comment became one space, so indices, lines and
+    // columns carry over to the original; only byte offsets need mapping back.
+    let value = parse_with_spans(&document.with_same_units(&blanked))
+        .map_err(|error| error.with_byte_offset_in(document))?;
+
+    let mut starts = vec![];
+    collect_starts(&value, String::from("$"), &mut starts);
+    for comment in comments.iter_mut() {
+        let next = starts.partition_point(|(start, _)| *start < comment.span.1);
+        comment.path = starts.get(next).map(|(_, path)| path.clone());
+    }
+
+    return Ok((JsonValue::from(value), comments));
+}
+
+/// The document with every unit of every comment replaced by a space, line
+/// breaks kept, and the comments that were removed.
+fn blank_comments(document: &UTF8Reader) -> Result<(String, Vec<Comment>), ValidationError> {
+    enum State {
+        Outside,
+        Quoted { escaped: bool },
+        // A "/" that may open a comment
+        Slash,
+        Line { start: usize },
+        Block { start: usize, star: bool },
+    }
+
+    let mut state = State::Outside;
+    let mut blanked = String::new();
+    let mut comments = vec![];
+    let mut text = String::new();
+
+    for index in 0..document.len() {
+        let unit = match document.look_ahead(index, 1) {
+            UTF8ReaderResult::Ok(unit) => unit,
+            UTF8ReaderResult::OutOfBoundError(_) => break,
+        };
+        let is_line_break = matches!(unit, "\n" | "\r");
+
+        state = match state {
+            State::Outside | State::Slash if unit == "\"" => State::Quoted { escaped: false },
+            State::Outside => match unit {
+                "/" => State::Slash,
+                _ => State::Outside,
+            },
+            State::Quoted { escaped } => match unit {
+                _ if escaped => State::Quoted { escaped: false },
+                "\\" => State::Quoted { escaped: true },
+                "\"" => State::Outside,
+                _ => State::Quoted { escaped: false },
+            },
+            State::Slash => match unit {
+                "/" | "*" => {
+                    // Take back the "/" written as is.
+                    blanked.pop();
+                    blanked.push_str("  ");
+                    state = match unit {
+                        "/" => State::Line { start: index - 1 },
+                        _ => State::Block {
+                            start: index - 1,
+                            star: false,
+                        },
+                    };
+                    continue;
+                }
+                _ => State::Outside,
+            },
+            State::Line { start } if is_line_break => {
+                comments.push(comment(&mut text, start, index));
+                State::Outside
+            }
+            State::Line { start } => {
+                text.push_str(unit);
+                blanked.push(' ');
+                state = State::Line { start };
+                continue;
+            }
+            State::Block { start, star: true } if unit == "/" => {
+                // The "*" was taken as text.
+                text.pop();
+                comments.push(comment(&mut text, start, index + 1));
+                blanked.push(' ');
+                state = State::Outside;
+                continue;
+            }
+            State::Block { start, .. } => {
+                text.push_str(unit);
+                blanked.push_str(if is_line_break { unit } else { " " });
+                state = State::Block {
+                    start,
+                    star: unit == "*",
+                };
+                continue;
+            }
+        };
+
+        blanked.push_str(unit);
+    }
+
+    match state {
+        State::Line { start } => comments.push(comment(&mut text, start, document.len())),
+        State::Block { start, .. } => {
+            return Err(ValidationError::new(
+                document,
+                start,
+                0,
+                "Block comment is never closed",
+            ))
+        }
+        _ => {}
+    }
+
+    return Ok((blanked, comments));
+}
+
+fn comment(text: &mut String, start: usize, end: usize) -> Comment {
+    return Comment {
+        text: mem::take(text),
+        span: (start, end),
+        path: None,
+    };
+}
+
+/// Where each key and value starts, with its path, in document order.
+fn collect_starts(value: &SpannedValue, path: String, starts: &mut Vec<(usize, String)>) {
+    starts.push((value.span.0, path.clone()));
+
+    match &value.kind {
+        SpannedKind::Array(elements) => {
+            for (position, element) in elements.iter().enumerate() {
+                collect_starts(element, format!("{}[{}]", path, position), starts);
+            }
+        }
+        SpannedKind::Object(members) => {
+            for member in members {
+                let path = format!("{}{}", path, member_segment(&member.key));
+                starts.push((member.key_span.0, path.clone()));
+                collect_starts(&member.value, path, starts);
+            }
+        }
+        _ => {}
+    }
+}
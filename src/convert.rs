@@ -0,0 +1,158 @@
+use alloc::{format, string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{collections::HashMap, error::Error};
+
+#[cfg(feature = "std")]
+use super::error::member_segment;
+use super::schema::describe_value;
+use super::value::JsonValue;
+
+/// A value that did not have the type a `TryFrom<&JsonValue>` conversion
+/// asked for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionError {
+    expected: &'static str,
+    found: &'static str,
+    /// The steps from the converted value down to the mismatch, innermost
+    /// first.
+    path: Vec<String>,
+}
+
+impl ConversionError {
+    pub(crate) fn new(expected: &'static str, value: &JsonValue) -> Self {
+        return ConversionError {
+            expected,
+            found: describe_value(value),
+            path: vec![],
+        };
+    }
+
+    pub(crate) fn in_segment(mut self, segment: String) -> Self {
+        self.path.push(segment);
+        return self;
+    }
+
+    /// The type the conversion wanted, such as `string` or `i64`.
+    pub fn expected(&self) -> &'static str {
+        return self.expected;
+    }
+
+    /// The JSON type that was there instead, such as `null` or `array`.
+    pub fn found(&self) -> &'static str {
+        return self.found;
+    }
+
+    /// Where the mismatch is, relative to the converted value, as a path
+    /// such as `$.users[3].age`.
+    pub fn path(&self) -> String {
+        let mut path = String::from("$");
+        for segment in self.path.iter().rev() {
+            path.push_str(segment);
+        }
+        return path;
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Expected {} at {}, found {}",
+            self.expected,
+            self.path(),
+            self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ConversionError {}
+
+impl TryFrom<&JsonValue> for String {
+    type Error = ConversionError;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        return match value {
+            JsonValue::String(s) => Ok(s.clone()),
+            _ => Err(ConversionError::new("string", value)),
+        };
+    }
+}
+
+impl TryFrom<&JsonValue> for f64 {
+    type Error = ConversionError;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        return value
+            .as_f64()
+            .ok_or_else(|| ConversionError::new("number", value));
+    }
+}
+
+/// Integers only; a fraction, an exponent or a value outside the `i64`
+/// range is a mismatch, found as `number`.
+impl TryFrom<&JsonValue> for i64 {
+    type Error = ConversionError;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        return value
+            .as_i64()
+            .ok_or_else(|| ConversionError::new("i64", value));
+    }
+}
+
+impl TryFrom<&JsonValue> for bool {
+    type Error = ConversionError;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        return value
+            .as_bool()
+            .ok_or_else(|| ConversionError::new("boolean", value));
+    }
+}
+
+impl<'a, T> TryFrom<&'a JsonValue> for Vec<T>
+where
+    T: TryFrom<&'a JsonValue, Error = ConversionError>,
+{
+    type Error = ConversionError;
+
+    fn try_from(value: &'a JsonValue) -> Result<Self, Self::Error> {
+        let elements = match value {
+            JsonValue::Array(elements) => elements,
+            _ => return Err(ConversionError::new("array", value)),
+        };
+        return elements
+            .iter()
+            .enumerate()
+            .map(|(position, element)| {
+                T::try_from(element).map_err(|error| error.in_segment(format!("[{}]", position)))
+            })
+            .collect();
+    }
+}
+
+/// When a key repeats, the last member wins.
+#[cfg(feature = "std")]
+impl<'a, T> TryFrom<&'a JsonValue> for HashMap<String, T>
+where
+    T: TryFrom<&'a JsonValue, Error = ConversionError>,
+{
+    type Error = ConversionError;
+
+    fn try_from(value: &'a JsonValue) -> Result<Self, Self::Error> {
+        let members = match value {
+            JsonValue::Object(members) => members,
+            _ => return Err(ConversionError::new("object", value)),
+        };
+        return members
+            .iter()
+            .map(|(key, member)| match T::try_from(member) {
+                Ok(converted) => Ok((key.clone(), converted)),
+                Err(error) => Err(error.in_segment(member_segment(key))),
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,95 @@
+use super::tokenizer::{self, Tokenizer};
+use super::utils::{UTF8Reader, UTF8ReaderResult};
+use super::validator::ValidateOptions;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    BeginObject,
+    EndObject,
+    BeginArray,
+    EndArray,
+    Colon,
+    Comma,
+    Key,
+    Scalar,
+    /// Nothing but whitespace is left.
+    End,
+}
+
+/// A token of a valid document, from `start` up to `end`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Token<'a> {
+    pub(crate) kind: TokenKind,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) lexeme: &'a str,
+}
+
+/// Walks a document already known to be valid, one `Tokenizer` token at a
+/// time, for the parsers that build something out of it. Keys are told
+/// apart from values, and each token carries its text. Whitespace between
+/// tokens is skipped; what it spans lies between one token's `end` and the
+/// next one's `start`.
+pub(crate) struct Cursor<'r, 'a> {
+    document: &'r UTF8Reader<'a>,
+    options: &'r ValidateOptions,
+    tokens: Tokenizer<'r>,
+}
+
+impl<'r, 'a> Cursor<'r, 'a> {
+    /// Starts past a byte order mark, if the options let one through.
+    pub(crate) fn new(document: &'r UTF8Reader<'a>, options: &'r ValidateOptions) -> Self {
+        return Cursor {
+            document,
+            options,
+            tokens: Tokenizer::with_options(document, options.clone()),
+        };
+    }
+
+    pub(crate) fn document(&self) -> &'r UTF8Reader<'a> {
+        return self.document;
+    }
+
+    pub(crate) fn options(&self) -> &'r ValidateOptions {
+        return self.options;
+    }
+
+    /// The index just past the last token; before the first, past the
+    /// byte order mark.
+    pub(crate) fn position(&self) -> usize {
+        return self.tokens.position();
+    }
+
+    pub(crate) fn next_token(&mut self) -> Token<'a> {
+        let expects_key = self.tokens.expects_key();
+        let (kind, start, width) = match self.tokens.next() {
+            Some(Ok(token)) => {
+                let kind = match token.kind {
+                    tokenizer::TokenKind::BeginObject => TokenKind::BeginObject,
+                    tokenizer::TokenKind::EndObject => TokenKind::EndObject,
+                    tokenizer::TokenKind::BeginArray => TokenKind::BeginArray,
+                    tokenizer::TokenKind::EndArray => TokenKind::EndArray,
+                    tokenizer::TokenKind::Colon => TokenKind::Colon,
+                    tokenizer::TokenKind::Comma => TokenKind::Comma,
+                    _ if expects_key => TokenKind::Key,
+                    _ => TokenKind::Scalar,
+                };
+                (kind, token.start, token.length)
+            }
+            // The document is valid, so the tokens only run out at its end.
+            Some(Err(_)) | None => (TokenKind::End, self.tokens.position(), 0),
+        };
+
+        let lexeme = match self.document.look_ahead(start, width) {
+            UTF8ReaderResult::Ok(lexeme) => lexeme,
+            UTF8ReaderResult::OutOfBoundError(_) => "",
+        };
+
+        return Token {
+            kind,
+            start,
+            end: start + width,
+            lexeme,
+        };
+    }
+}
@@ -0,0 +1,202 @@
+use super::error::{ErrorKind, Fault, ValidationError};
+use super::utils::{UTF8Reader, UTF8ReaderResult};
+use super::validator::{validate_string, LoneSurrogatePolicy, ValidateOptions};
+use alloc::{borrow::Cow, format, string::String, vec, vec::Vec};
+
+/// Scans the string starting at `start` like `validate_string`, and returns
+/// its value with every escape sequence decoded. A surrogate pair escape such
+/// as `\uD83D\uDE00` becomes a single `char`; an unpaired surrogate can not be
+/// represented in a `String` and is an error.
+pub fn decode_string(
+    document: &UTF8Reader,
+    start: usize,
+) -> (Result<String, ValidationError>, usize) {
+    return decode_string_with_options(document, start, &ValidateOptions::default());
+}
+
+/// Like `decode_string`, but scans and decodes under `options`; with
+/// `LoneSurrogatePolicy::Replace`, an unpaired surrogate becomes U+FFFD
+/// instead of an error, and `max_decoded_string_bytes` caps the result.
+pub fn decode_string_with_options(
+    document: &UTF8Reader,
+    start: usize,
+    options: &ValidateOptions,
+) -> (Result<String, ValidationError>, usize) {
+    let (result, step) = scan_and_decode_string(document, start, options);
+
+    return match result {
+        Ok(decoded) => (Ok(decoded), step),
+        Err(fault) => {
+            let error = ValidationError::from_fault(document, start + step, 0, fault);
+            (Err(error), step)
+        }
+    };
+}
+
+/// Like `decode_string`, but borrows the value from the document when the
+/// string has no escape sequence, and only allocates when one needs decoding.
+pub fn unescape_cow<'a>(
+    document: &UTF8Reader<'a>,
+    start: usize,
+) -> (Result<Cow<'a, str>, ValidationError>, usize) {
+    return unescape_cow_with_options(document, start, &ValidateOptions::default());
+}
+
+/// Like `unescape_cow`, but scans and decodes under `options`, the same as
+/// `decode_string_with_options`.
+pub fn unescape_cow_with_options<'a>(
+    document: &UTF8Reader<'a>,
+    start: usize,
+    options: &ValidateOptions,
+) -> (Result<Cow<'a, str>, ValidationError>, usize) {
+    let (result, step) = validate_string(document, start, options);
+    let result = match result {
+        Ok(_) => unescape_validated_string(document, start, step, options),
+        Err(fault) => Err((fault, step)),
+    };
+
+    return match result {
+        Ok(unescaped) => (Ok(unescaped), step),
+        Err((fault, offset)) => {
+            let error = ValidationError::from_fault(document, start + offset, 0, fault);
+            (Err(error), offset)
+        }
+    };
+}
+
+pub(crate) fn scan_and_decode_string(
+    document: &UTF8Reader,
+    start: usize,
+    options: &ValidateOptions,
+) -> (Result<String, Fault>, usize) {
+    let (result, step) = validate_string(document, start, options);
+    if let Err(fault) = result {
+        return (Err(fault), step);
+    }
+
+    return match decode_validated_string(document, start, step, options) {
+        Ok(decoded) => (Ok(decoded), step),
+        Err((fault, offset)) => (Err(fault), offset),
+    };
+}
+
+/// Like `decode_validated_string`, but borrows the content between the quotes
+/// when there is nothing to decode.
+pub(crate) fn unescape_validated_string<'a>(
+    document: &UTF8Reader<'a>,
+    start: usize,
+    width: usize,
+    options: &ValidateOptions,
+) -> Result<Cow<'a, str>, (Fault, usize)> {
+    let lexeme = match document.look_ahead(start, width) {
+        UTF8ReaderResult::Ok(s) => s,
+        UTF8ReaderResult::OutOfBoundError(_) => "",
+    };
+
+    // A string over the cap goes the long way, which finds where it crosses.
+    let fits = !matches!(options.max_decoded_string_bytes, Some(limit) if lexeme.len() > limit + 2);
+    if lexeme.len() >= 2 && !lexeme.contains('\\') && fits {
+        return Ok(Cow::Borrowed(&lexeme[1..lexeme.len() - 1]));
+    }
+    return decode_validated_string(document, start, width, options).map(Cow::Owned);
+}
+
+/// Decodes a string of `width` units that `validate_string` already accepted.
+/// On failure, returns the offset of the offending escape from `start`, or of
+/// the char that took the value past `max_decoded_string_bytes`.
+pub(crate) fn decode_validated_string(
+    document: &UTF8Reader,
+    start: usize,
+    width: usize,
+    options: &ValidateOptions,
+) -> Result<String, (Fault, usize)> {
+    fn read_hex(chars: &[(usize, char)]) -> Option<u32> {
+        if chars.len() < 4 {
+            return None;
+        }
+        return chars[..4]
+            .iter()
+            .try_fold(0, |code, (_, c)| Some(code * 16 + c.to_digit(16)?));
+    }
+
+    // Every char between the quotes, paired with the offset of its unit.
+    let mut chars: Vec<(usize, char)> = vec![];
+    for offset in 1..width.saturating_sub(1) {
+        if let UTF8ReaderResult::Ok(unit) = document.look_ahead(start + offset, 1) {
+            chars.extend(unit.chars().map(|c| (offset, c)));
+        }
+    }
+
+    let check_length = |decoded: &String, offset: usize| match options.max_decoded_string_bytes {
+        Some(limit) if decoded.len() > limit => {
+            let reason = format!("String decodes to more than {} bytes", limit);
+            Err((
+                Fault::new(ErrorKind::DecodedStringTooLong { limit }, reason),
+                offset,
+            ))
+        }
+        _ => Ok(()),
+    };
+
+    let mut decoded = String::with_capacity(chars.len());
+    let mut i = 0;
+    // The offset of the char decoded last, to blame when the cap is crossed.
+    let mut last = 0;
+
+    while i < chars.len() {
+        let (offset, c) = chars[i];
+        check_length(&decoded, last)?;
+        last = offset;
+
+        if c != '\\' {
+            decoded.push(c);
+            i += 1;
+            continue;
+        }
+
+        let escaped = chars.get(i + 1).map(|(_, c)| *c);
+        i += 2;
+
+        match escaped {
+            Some('b') => decoded.push('\u{0008}'),
+            Some('f') => decoded.push('\u{000C}'),
+            Some('n') => decoded.push('\n'),
+            Some('r') => decoded.push('\r'),
+            Some('t') => decoded.push('\t'),
+            Some('u') => {
+                let code = read_hex(&chars[i..]).unwrap_or_default();
+                i += 4;
+
+                if (0xD800..=0xDBFF).contains(&code) {
+                    let is_escape = |j: usize, expected: char| matches!(chars.get(j), Some((_, c)) if *c == expected);
+                    if is_escape(i, '\\') && is_escape(i + 1, 'u') {
+                        let low = read_hex(&chars[i + 2..]).unwrap_or_default();
+                        if (0xDC00..=0xDFFF).contains(&low) {
+                            let code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            decoded.extend(core::char::from_u32(code));
+                            i += 6;
+                            continue;
+                        }
+                    }
+                }
+
+                match core::char::from_u32(code) {
+                    Some(c) => decoded.push(c),
+                    None if options.lone_surrogate_policy == LoneSurrogatePolicy::Replace => {
+                        decoded.push(core::char::REPLACEMENT_CHARACTER)
+                    }
+                    None => {
+                        let reason =
+                            format!("Unpaired surrogate \\u{:04X} can not be decoded", code);
+                        return Err((Fault::new(ErrorKind::UnpairedSurrogate, reason), offset));
+                    }
+                }
+            }
+            Some(escaped) => decoded.push(escaped),
+            None => {}
+        }
+    }
+
+    check_length(&decoded, last)?;
+    return Ok(decoded);
+}
@@ -0,0 +1,90 @@
+use alloc::{format, string::String, vec, vec::Vec};
+
+use super::pointer::push_token;
+use super::value::JsonValue;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    Added(JsonValue),
+    Removed(JsonValue),
+    Replaced { old: JsonValue, new: JsonValue },
+}
+
+/// One difference between two values, located by a JSON Pointer such as
+/// `/servers/0/port`. The empty path is the root.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+/// Lists what changed from `a` to `b`.
+///
+/// Object members are matched by key, so key order does not matter; array
+/// elements are matched by position, so an insertion shows up as a run of
+/// replacements followed by an addition. Numbers are compared by lexeme.
+pub fn diff(a: &JsonValue, b: &JsonValue) -> Vec<Change> {
+    let mut changes = vec![];
+    diff_at(&mut String::new(), a, b, &mut changes);
+    return changes;
+}
+
+fn diff_at(path: &mut String, a: &JsonValue, b: &JsonValue, changes: &mut Vec<Change>) {
+    match (a, b) {
+        (JsonValue::Object(old), JsonValue::Object(new)) => {
+            for (key, old_value) in old.iter() {
+                let length = path.len();
+                push_token(path, key);
+                match new.iter().find(|(name, _)| name == key) {
+                    Some((_, new_value)) => diff_at(path, old_value, new_value, changes),
+                    None => changes.push(Change {
+                        path: path.clone(),
+                        kind: ChangeKind::Removed(old_value.clone()),
+                    }),
+                }
+                path.truncate(length);
+            }
+
+            for (key, new_value) in new.iter() {
+                if !old.iter().any(|(name, _)| name == key) {
+                    let length = path.len();
+                    push_token(path, key);
+                    changes.push(Change {
+                        path: path.clone(),
+                        kind: ChangeKind::Added(new_value.clone()),
+                    });
+                    path.truncate(length);
+                }
+            }
+        }
+        (JsonValue::Array(old), JsonValue::Array(new)) => {
+            for position in 0..old.len().max(new.len()) {
+                let length = path.len();
+                push_token(path, &format!("{}", position));
+                match (old.get(position), new.get(position)) {
+                    (Some(old_value), Some(new_value)) => {
+                        diff_at(path, old_value, new_value, changes)
+                    }
+                    (Some(old_value), None) => changes.push(Change {
+                        path: path.clone(),
+                        kind: ChangeKind::Removed(old_value.clone()),
+                    }),
+                    (None, Some(new_value)) => changes.push(Change {
+                        path: path.clone(),
+                        kind: ChangeKind::Added(new_value.clone()),
+                    }),
+                    (None, None) => {}
+                }
+                path.truncate(length);
+            }
+        }
+        _ if a == b => {}
+        _ => changes.push(Change {
+            path: path.clone(),
+            kind: ChangeKind::Replaced {
+                old: a.clone(),
+                new: b.clone(),
+            },
+        }),
+    }
+}
@@ -0,0 +1,268 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::{self, Write};
+
+use super::cursor::{Cursor, Token, TokenKind};
+use super::error::ValidationError;
+use super::serialize::write_string;
+use super::utils::{UTF8Reader, UTF8ReaderResult};
+use super::validator::{validate_with_options, ValidateOptions};
+use super::value::JsonValue;
+
+/// A parsed document that keeps what `parse` throws away: the whitespace
+/// between tokens, member order, and every key, string and number exactly
+/// as written. Writing an untouched `Document` with `write_preserving`
+/// gives back the bytes it was parsed from, so a tool can change one value
+/// and leave the rest of the file alone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    /// Everything before the value, including a byte order mark the options
+    /// let through.
+    pub leading: String,
+    pub value: Node,
+    pub trailing: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// A string, number, `true`, `false` or `null`, as written.
+    Scalar(String),
+    /// The elements, and the whitespace inside the brackets when there are
+    /// none.
+    Array(Vec<Element>, String),
+    /// The members, and the whitespace inside the braces when there are
+    /// none.
+    Object(Vec<Member>, String),
+}
+
+/// An array element with the whitespace on either side of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    pub before: String,
+    pub value: Node,
+    /// Up to the comma or bracket that follows.
+    pub after: String,
+}
+
+/// An object member with the whitespace around each of its tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub before: String,
+    /// The key as written, quotes included.
+    pub key: String,
+    pub before_colon: String,
+    pub after_colon: String,
+    pub value: Node,
+    /// Up to the comma or brace that follows.
+    pub after: String,
+}
+
+/// A compact node for a value built in code, to put into a `Document`.
+/// Numbers keep their lexeme, as everything else in a `Document` does.
+impl From<&JsonValue> for Node {
+    fn from(value: &JsonValue) -> Self {
+        return match value {
+            JsonValue::Array(elements) => Node::Array(
+                elements
+                    .iter()
+                    .map(|element| Element {
+                        before: String::new(),
+                        value: Node::from(element),
+                        after: String::new(),
+                    })
+                    .collect(),
+                String::new(),
+            ),
+            JsonValue::Object(members) => Node::Object(
+                members
+                    .iter()
+                    .map(|(key, member)| Member {
+                        before: String::new(),
+                        key: quoted(key),
+                        before_colon: String::new(),
+                        after_colon: String::new(),
+                        value: Node::from(member),
+                        after: String::new(),
+                    })
+                    .collect(),
+                String::new(),
+            ),
+            JsonValue::Number(number) => Node::Scalar(String::from(number.as_str())),
+            JsonValue::String(s) => Node::Scalar(quoted(s)),
+            JsonValue::Bool(true) => Node::Scalar(String::from("true")),
+            JsonValue::Bool(false) => Node::Scalar(String::from("false")),
+            JsonValue::Null => Node::Scalar(String::from("null")),
+        };
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write_preserving(self, f);
+    }
+}
+
+/// Like `parse`, but keeps the formatting of the document; see `Document`.
+pub fn parse_document(document: &UTF8Reader) -> Result<Document, ValidationError> {
+    return parse_document_with_options(document, &ValidateOptions::default());
+}
+
+pub fn parse_document_with_options(
+    document: &UTF8Reader,
+    options: &ValidateOptions,
+) -> Result<Document, ValidationError> {
+    validate_with_options(document, options)?;
+
+    // A byte order mark is only left in front of a valid document when the
+    // options allow it, so it can be taken as leading whitespace.
+    let mut cursor = Cursor::new(document, options);
+    let first = cursor.next_token();
+    let leading = slice(document, 0, first.start);
+
+    let value = build_node(&mut cursor, first);
+
+    let value_end = cursor.position();
+    let end = cursor.next_token();
+    let trailing = slice(document, value_end, end.start);
+
+    return Ok(Document {
+        leading,
+        value,
+        trailing,
+    });
+}
+
+/// Writes `document` back out with its formatting; nodes built from a
+/// `JsonValue` come out compact.
+pub fn write_preserving<W: Write>(document: &Document, output: &mut W) -> fmt::Result {
+    output.write_str(&document.leading)?;
+    write_node(&document.value, output)?;
+    return output.write_str(&document.trailing);
+}
+
+fn write_node<W: Write>(node: &Node, output: &mut W) -> fmt::Result {
+    match node {
+        Node::Scalar(source) => output.write_str(source)?,
+        Node::Array(elements, inner) => {
+            output.write_char('[')?;
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    output.write_char(',')?;
+                }
+                output.write_str(&element.before)?;
+                write_node(&element.value, output)?;
+                output.write_str(&element.after)?;
+            }
+            if elements.is_empty() {
+                output.write_str(inner)?;
+            }
+            output.write_char(']')?;
+        }
+        Node::Object(members, inner) => {
+            output.write_char('{')?;
+            for (i, member) in members.iter().enumerate() {
+                if i > 0 {
+                    output.write_char(',')?;
+                }
+                output.write_str(&member.before)?;
+                output.write_str(&member.key)?;
+                output.write_str(&member.before_colon)?;
+                output.write_char(':')?;
+                output.write_str(&member.after_colon)?;
+                write_node(&member.value, output)?;
+                output.write_str(&member.after)?;
+            }
+            if members.is_empty() {
+                output.write_str(inner)?;
+            }
+            output.write_char('}')?;
+        }
+    }
+    return Ok(());
+}
+
+/// Builds the node that starts with `token`, leaving `cursor` past it. The
+/// document must already be known to be valid.
+fn build_node(cursor: &mut Cursor, token: Token) -> Node {
+    let document = cursor.document();
+
+    return match token.kind {
+        TokenKind::BeginObject => {
+            let mut members = vec![];
+            let mut previous = token.end;
+            let inner = loop {
+                let key = cursor.next_token();
+                let before = slice(document, previous, key.start);
+                if key.kind != TokenKind::Key {
+                    break before;
+                }
+
+                let colon = cursor.next_token();
+                let first = cursor.next_token();
+                let before_colon = slice(document, key.end, colon.start);
+                let after_colon = slice(document, colon.end, first.start);
+                let value = build_node(cursor, first);
+                let value_end = cursor.position();
+
+                // The "," or the "}"
+                let delimiter = cursor.next_token();
+                members.push(Member {
+                    before,
+                    key: String::from(key.lexeme),
+                    before_colon,
+                    after_colon,
+                    value,
+                    after: slice(document, value_end, delimiter.start),
+                });
+
+                if delimiter.kind != TokenKind::Comma {
+                    break String::new();
+                }
+                previous = delimiter.end;
+            };
+            Node::Object(members, inner)
+        }
+        TokenKind::BeginArray => {
+            let mut elements = vec![];
+            let mut previous = token.end;
+            let inner = loop {
+                let first = cursor.next_token();
+                let before = slice(document, previous, first.start);
+                if let TokenKind::EndArray | TokenKind::End = first.kind {
+                    break before;
+                }
+
+                let value = build_node(cursor, first);
+                let value_end = cursor.position();
+
+                // The "," or the "]"
+                let delimiter = cursor.next_token();
+                elements.push(Element {
+                    before,
+                    value,
+                    after: slice(document, value_end, delimiter.start),
+                });
+
+                if delimiter.kind != TokenKind::Comma {
+                    break String::new();
+                }
+                previous = delimiter.end;
+            };
+            Node::Array(elements, inner)
+        }
+        _ => Node::Scalar(String::from(token.lexeme)),
+    };
+}
+
+fn quoted(s: &str) -> String {
+    let mut output = String::new();
+    // Writing into a `String` can not fail.
+    let _ = write_string(&mut output, s);
+    return output;
+}
+
+fn slice(document: &UTF8Reader, start: usize, end: usize) -> String {
+    return match document.look_ahead(start, end - start) {
+        UTF8ReaderResult::Ok(s) => String::from(s),
+        UTF8ReaderResult::OutOfBoundError(_) => String::new(),
+    };
+}
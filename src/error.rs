@@ -0,0 +1,481 @@
+use alloc::{format, string::String, vec, vec::Vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::{io, str::Utf8Error};
+
+use super::serialize::write_string;
+use super::utils::UTF8Reader;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The document does not follow the JSON grammar in a way none of the
+    /// kinds below describes, such as an unexpected character.
+    Syntax,
+    /// The document is empty or holds only whitespace.
+    EmptyDocument,
+    /// The input ends inside an object or an array.
+    UnexpectedEnd,
+    /// Something other than whitespace follows the top-level value.
+    TrailingData,
+    /// A `,` is followed by the `]` or `}` that closes its container.
+    TrailingComma,
+    /// A number starts with a `0` followed by more digits.
+    LeadingZero,
+    /// A number breaks the number grammar some other way.
+    InvalidNumber,
+    /// A misspelled or truncated `true`, `false`, `null`, `Infinity` or `NaN`.
+    InvalidLiteral,
+    /// An object key that is not a string, or not an identifier either when
+    /// `ValidateOptions::allow_unquoted_keys` is set.
+    InvalidKey,
+    /// A string runs to the end of the input.
+    UnterminatedString,
+    /// A `\` is followed by something that is not a valid escape, or by `/`
+    /// under `SolidusPolicy::ForbidEscaped`.
+    InvalidEscape,
+    /// A character that must be escaped in a string, such as a control
+    /// character, appears as is.
+    UnescapedCharacter,
+    /// Decoding met a `\u` escape for half of a surrogate pair on its own,
+    /// under `LoneSurrogatePolicy::Error`.
+    UnpairedSurrogate,
+    /// A NUL byte, a stray byte order mark or a Unicode space that is not
+    /// JSON whitespace.
+    StrayCharacter,
+    /// Containers nest deeper than the hard limit of 100 levels.
+    TooDeep,
+    /// The byte order mark is missing under `BomPolicy::Require`.
+    MissingByteOrderMark,
+    /// The top-level value is not of the type `ValidateOptions::require_top_level` asks for.
+    UnexpectedTopLevel,
+    /// The input bytes could not be decoded as text, before any JSON was
+    /// looked at.
+    Encoding,
+    /// The input is larger than `ValidateOptions::max_bytes`.
+    DocumentTooLarge { limit: usize, actual: usize },
+    /// A string is longer than `ValidateOptions::max_string_length`.
+    StringTooLong { limit: usize },
+    /// A decoded string is longer than `ValidateOptions::max_decoded_string_bytes`.
+    DecodedStringTooLong { limit: usize },
+    /// A string has more escapes than `ValidateOptions::max_escapes_per_string`.
+    TooManyEscapes { limit: usize },
+    /// An array has more elements than `ValidateOptions::max_array_elements`.
+    TooManyArrayElements { limit: usize },
+    /// An object has more members than `ValidateOptions::max_object_members`.
+    TooManyObjectMembers { limit: usize },
+    /// The document has more values than `ValidateOptions::max_total_nodes`.
+    TooManyNodes { limit: usize },
+    /// An exponent has more digits than `ValidateOptions::max_exponent_digits`.
+    ExponentTooLong { limit: usize },
+    /// Warning: a `-0` was found while `ValidateOptions::warn_negative_zero` is set.
+    NegativeZero,
+    /// Warning: a number may lose precision in an `f64`, found while
+    /// `ValidateOptions::warn_precision_loss` is set. Also the error
+    /// `to_serde_value` gives for a number serde_json can not hold exactly.
+    PrecisionLoss,
+    /// Warning: containers nest deeper than `ValidateOptions::warn_depth`.
+    DeepNesting { limit: usize },
+    /// Warning: a `\u` escape stands for a Unicode noncharacter, found while
+    /// `ValidateOptions::warn_noncharacters` is set.
+    Noncharacter,
+}
+
+impl ErrorKind {
+    /// A stable, upper snake case name for the kind, such as `STRING_TOO_LONG`.
+    pub fn code(&self) -> &'static str {
+        return match self {
+            ErrorKind::Syntax => "SYNTAX",
+            ErrorKind::EmptyDocument => "EMPTY_DOCUMENT",
+            ErrorKind::UnexpectedEnd => "UNEXPECTED_END",
+            ErrorKind::TrailingData => "TRAILING_DATA",
+            ErrorKind::TrailingComma => "TRAILING_COMMA",
+            ErrorKind::LeadingZero => "LEADING_ZERO",
+            ErrorKind::InvalidNumber => "INVALID_NUMBER",
+            ErrorKind::InvalidLiteral => "INVALID_LITERAL",
+            ErrorKind::InvalidKey => "INVALID_KEY",
+            ErrorKind::UnterminatedString => "UNTERMINATED_STRING",
+            ErrorKind::InvalidEscape => "INVALID_ESCAPE",
+            ErrorKind::UnescapedCharacter => "UNESCAPED_CHARACTER",
+            ErrorKind::UnpairedSurrogate => "UNPAIRED_SURROGATE",
+            ErrorKind::StrayCharacter => "STRAY_CHARACTER",
+            ErrorKind::TooDeep => "TOO_DEEP",
+            ErrorKind::MissingByteOrderMark => "MISSING_BYTE_ORDER_MARK",
+            ErrorKind::UnexpectedTopLevel => "UNEXPECTED_TOP_LEVEL",
+            ErrorKind::Encoding => "ENCODING",
+            ErrorKind::DocumentTooLarge { .. } => "DOCUMENT_TOO_LARGE",
+            ErrorKind::StringTooLong { .. } => "STRING_TOO_LONG",
+            ErrorKind::DecodedStringTooLong { .. } => "DECODED_STRING_TOO_LONG",
+            ErrorKind::TooManyEscapes { .. } => "TOO_MANY_ESCAPES",
+            ErrorKind::TooManyArrayElements { .. } => "TOO_MANY_ARRAY_ELEMENTS",
+            ErrorKind::TooManyObjectMembers { .. } => "TOO_MANY_OBJECT_MEMBERS",
+            ErrorKind::TooManyNodes { .. } => "TOO_MANY_NODES",
+            ErrorKind::ExponentTooLong { .. } => "EXPONENT_TOO_LONG",
+            ErrorKind::NegativeZero => "NEGATIVE_ZERO",
+            ErrorKind::PrecisionLoss => "PRECISION_LOSS",
+            ErrorKind::DeepNesting { .. } => "DEEP_NESTING",
+            ErrorKind::Noncharacter => "NONCHARACTER",
+        };
+    }
+}
+
+/// A failure raised by one of the scanners, before its position in the
+/// document is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Fault {
+    pub kind: ErrorKind,
+    pub reason: String,
+    /// The steps from the root down to the failing value, innermost first,
+    /// e.g. `[3]` then `.users`.
+    pub path: Vec<String>,
+    /// Where the value that was never closed began.
+    pub opened_at: Option<usize>,
+}
+
+impl Fault {
+    pub fn new(kind: ErrorKind, reason: String) -> Self {
+        return Fault {
+            kind,
+            reason,
+            path: vec![],
+            opened_at: None,
+        };
+    }
+
+    /// Records that the failure is a value opened at `index` and never
+    /// closed, so the error can point back at its start.
+    pub fn opened_at(mut self, index: usize) -> Self {
+        self.opened_at = Some(index);
+        return self;
+    }
+
+    /// Records that the failing value sits under the member `key`.
+    pub fn in_member(mut self, key: &str) -> Self {
+        self.path.push(member_segment(key));
+        return self;
+    }
+
+    /// Gives a fault of the generic `Syntax` kind a more precise one, keeping
+    /// any other kind it already has.
+    pub fn classified(mut self, kind: ErrorKind) -> Self {
+        if self.kind == ErrorKind::Syntax {
+            self.kind = kind;
+        }
+        return self;
+    }
+
+    /// Records that the failing value sits under the element at `position`.
+    pub fn in_element(mut self, position: usize) -> Self {
+        self.path.push(format!("[{}]", position));
+        return self;
+    }
+}
+
+/// `reason` followed by where the unclosed value began.
+pub(crate) fn with_opening(reason: &str, line: usize, column: usize) -> String {
+    return format!("{}, opened at line {}, column {}", reason, line, column);
+}
+
+/// The path step to the member `key`: `.key` for identifiers, `["key"]`
+/// for anything else.
+pub(crate) fn member_segment(key: &str) -> String {
+    let is_identifier = key.chars().enumerate().all(|(i, c)| {
+        c == '_' || c == '$' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+    });
+
+    let mut segment = String::new();
+    if is_identifier && !key.is_empty() {
+        segment.push('.');
+        segment.push_str(key);
+    } else {
+        segment.push('[');
+        // Writing into a `String` can not fail.
+        let _ = write_string(&mut segment, key);
+        segment.push(']');
+    }
+    return segment;
+}
+
+impl From<String> for Fault {
+    fn from(reason: String) -> Self {
+        return Fault::new(ErrorKind::Syntax, reason);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    kind: ErrorKind,
+    index: usize,
+    byte_offset: usize,
+    line: usize,
+    column: usize,
+    path: String,
+    reason: String,
+    opened_at: Option<usize>,
+}
+
+impl ValidationError {
+    /// `origin` is the index of the first scalar an editor would show, i.e. the
+    /// one after a skipped byte order mark.
+    pub(crate) fn new(document: &UTF8Reader, index: usize, origin: usize, reason: &str) -> Self {
+        let fault = Fault::new(ErrorKind::Syntax, String::from(reason));
+        return ValidationError::from_fault(document, index, origin, fault);
+    }
+
+    pub(crate) fn from_fault(
+        document: &UTF8Reader,
+        index: usize,
+        origin: usize,
+        mut fault: Fault,
+    ) -> Self {
+        if let Some(opened_at) = fault.opened_at {
+            let (line, column) = line_and_column(document, opened_at, origin);
+            fault.reason = with_opening(&fault.reason, line, column);
+        }
+
+        let (line, column) = line_and_column(document, index, origin);
+        return ValidationError::at(index, document.byte_offset_of(index), line, column, fault);
+    }
+
+    /// Builds an error from a position the caller tracked itself.
+    pub(crate) fn at(
+        index: usize,
+        byte_offset: usize,
+        line: usize,
+        column: usize,
+        fault: Fault,
+    ) -> Self {
+        let mut path = String::from("$");
+        for segment in fault.path.iter().rev() {
+            path.push_str(segment);
+        }
+
+        let reason = if fault.path.is_empty() {
+            fault.reason
+        } else {
+            format!("Invalid value at {}: {}", path, fault.reason)
+        };
+
+        return ValidationError {
+            kind: fault.kind,
+            index,
+            byte_offset,
+            line,
+            column,
+            path,
+            reason,
+            opened_at: fault.opened_at,
+        };
+    }
+
+    pub(crate) fn document_too_large(limit: usize, actual: usize) -> Self {
+        return ValidationError {
+            kind: ErrorKind::DocumentTooLarge { limit, actual },
+            index: 0,
+            byte_offset: 0,
+            line: 1,
+            column: 1,
+            path: String::from("$"),
+            reason: format!(
+                "JSON document is {} bytes long, which exceeds the limit of {} bytes",
+                actual, limit
+            ),
+            opened_at: None,
+        };
+    }
+
+    /// Moves the error to `byte_offset`, for inputs whose bytes are not the
+    /// UTF-8 the reader scanned.
+    pub(crate) fn with_byte_offset(mut self, byte_offset: usize) -> Self {
+        self.byte_offset = byte_offset;
+        return self;
+    }
+
+    /// Moves the error into a larger text in which `document` starts at
+    /// `base`: a byte offset, a 1-based line and a 1-based column. The index
+    /// stays an index into `document`.
+    pub(crate) fn moved_into(
+        mut self,
+        document: &UTF8Reader,
+        origin: usize,
+        base: (usize, usize, usize),
+    ) -> Self {
+        let (byte_offset, line, column) = base;
+
+        if let Some(opened_at) = self.opened_at {
+            let opening = line_and_column(document, opened_at, origin);
+            let suffix = with_opening("", opening.0, opening.1);
+            if let Some(reason) = self.reason.strip_suffix(&suffix) {
+                let (line, column) = moved_position(opening, (line, column));
+                self.reason = with_opening(reason, line, column);
+            }
+        }
+
+        let position = moved_position((self.line, self.column), (line, column));
+        self.line = position.0;
+        self.column = position.1;
+        self.byte_offset = byte_offset.saturating_add(self.byte_offset);
+        return self;
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        return &self.kind;
+    }
+
+    /// Recomputes the byte offset against `document`, for an error found in
+    /// a copy of it whose units sit at the same indices but differ in size.
+    pub(crate) fn with_byte_offset_in(mut self, document: &UTF8Reader) -> Self {
+        self.byte_offset = document.byte_offset_of(self.index);
+        return self;
+    }
+
+    /// The position of the error, counted in units of the `UTF8Reader`.
+    pub fn index(&self) -> usize {
+        return self.index;
+    }
+
+    /// The position of the error in bytes from the start of the input.
+    pub fn byte_offset(&self) -> usize {
+        return self.byte_offset;
+    }
+
+    /// The 1-based line of the error.
+    pub fn line(&self) -> usize {
+        return self.line;
+    }
+
+    /// The 1-based column of the error, as an editor would display it.
+    pub fn column(&self) -> usize {
+        return self.column;
+    }
+
+    /// Where the failing value sits, as a path such as `$.users[3].profile`;
+    /// just `$` for the top-level value and for errors outside any value.
+    pub fn path(&self) -> &str {
+        return &self.path;
+    }
+
+    pub fn reason(&self) -> &str {
+        return &self.reason;
+    }
+
+    /// For a string that runs to the end of the input, the index of its
+    /// opening quote; the error itself sits at the end.
+    pub fn opened_at(&self) -> Option<usize> {
+        return self.opened_at;
+    }
+}
+
+/// Renders the error as a JSON object for other tools, e.g.
+/// `{"line":2,"column":14,"byte_offset":37,"code":"LEADING_ZERO","message":"..."}`.
+pub fn error_to_json(err: &ValidationError) -> String {
+    let mut output = format!(
+        "{{\"line\":{},\"column\":{},\"byte_offset\":{},\"code\":",
+        err.line, err.column, err.byte_offset
+    );
+    // Writing into a `String` can not fail.
+    let _ = write_string(&mut output, err.kind.code());
+    output.push_str(",\"message\":");
+    let _ = write_string(&mut output, &err.reason);
+    output.push('}');
+    return output;
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(
+            f,
+            "Validation Error @ {}:{}\nReason: {}",
+            self.line, self.column, self.reason
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ValidationError {}
+
+/// Why a file or stream could not be validated.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum JsonFileError {
+    /// The input could not be opened or read.
+    Io(io::Error),
+    /// The input is not valid UTF-8.
+    Utf8(Utf8Error),
+    /// The input was read but is not valid JSON.
+    Invalid(ValidationError),
+    /// The input is gzip-compressed but could not be inflated. Only
+    /// reported with the `gzip` feature, but always present so that the
+    /// enum is the same whichever features are enabled.
+    Decompress(io::Error),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for JsonFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            JsonFileError::Io(why) => write!(f, "couldn't read input: {}", why),
+            JsonFileError::Utf8(why) => write!(f, "input is not valid UTF-8: {}", why),
+            JsonFileError::Invalid(why) => write!(f, "{}", why),
+            JsonFileError::Decompress(why) => write!(f, "couldn't decompress input: {}", why),
+        };
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for JsonFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            JsonFileError::Io(why) => Some(why),
+            JsonFileError::Utf8(why) => Some(why),
+            JsonFileError::Invalid(why) => Some(why),
+            JsonFileError::Decompress(why) => Some(why),
+        };
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for JsonFileError {
+    fn from(why: io::Error) -> Self {
+        return JsonFileError::Io(why);
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Utf8Error> for JsonFileError {
+    fn from(why: Utf8Error) -> Self {
+        return JsonFileError::Utf8(why);
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ValidationError> for JsonFileError {
+    fn from(why: ValidationError) -> Self {
+        return JsonFileError::Invalid(why);
+    }
+}
+
+/// A line and column in a document, as seen from a larger text in which the
+/// document starts at `base`. Only the first line is shifted sideways.
+fn moved_position(position: (usize, usize), base: (usize, usize)) -> (usize, usize) {
+    let (line, column) = position;
+    let (base_line, base_column) = base;
+    if line <= 1 {
+        return (
+            base_line,
+            base_column.saturating_add(column.saturating_sub(1)),
+        );
+    }
+    return (base_line.saturating_add(line - 1), column);
+}
+
+/// Columns on the first line are counted from `origin`, past any byte order
+/// mark.
+fn line_and_column(document: &UTF8Reader, index: usize, origin: usize) -> (usize, usize) {
+    let (line, column) = document.line_col(index.max(origin));
+    if line == 1 {
+        return (line, column - origin);
+    }
+    return (line, column);
+}
@@ -0,0 +1,32 @@
+use super::utils::UTF8Reader;
+use super::validator::{validate_document, Context, ValidateOptions, ValidateResult};
+use super::value::JsonValue;
+use alloc::string::String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonEvent {
+    BeginObject,
+    Key(String),
+    EndObject,
+    BeginArray,
+    EndArray,
+    /// A scalar value: never an `Array` or `Object`.
+    Value(JsonValue),
+}
+
+/// Validates the document while reporting its structure to `callback` in
+/// document order. Events already emitted stay emitted if validation fails
+/// later on, so consumers should treat them as tentative until this returns
+/// `Ok`.
+pub fn parse_events<F: FnMut(JsonEvent)>(document: &UTF8Reader, callback: F) -> ValidateResult {
+    return parse_events_with_options(document, &ValidateOptions::default(), callback);
+}
+
+pub fn parse_events_with_options<F: FnMut(JsonEvent)>(
+    document: &UTF8Reader,
+    options: &ValidateOptions,
+    mut callback: F,
+) -> ValidateResult {
+    let mut ctx = Context::new(options, Some(&mut callback));
+    return validate_document(document, &mut ctx);
+}
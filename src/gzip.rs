@@ -0,0 +1,51 @@
+use alloc::vec;
+use flate2::read::MultiGzDecoder;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use super::error::JsonFileError;
+use super::utils::ChunkedUtf8Validator;
+
+const CHUNK_SIZE: usize = 8 * 1024;
+const MAGIC: [u8; 2] = [0x1F, 0x8B];
+
+/// A file is taken to be gzip when it is named `*.gz` or starts with the
+/// gzip magic bytes.
+pub(crate) fn is_gzip(path: &Path) -> io::Result<bool> {
+    if path.extension() == Some("gz".as_ref()) {
+        return Ok(true);
+    }
+
+    let mut header = [0; 2];
+    let mut file = File::open(path)?;
+    let mut read = 0;
+    while read < header.len() {
+        match file.read(&mut header[read..])? {
+            0 => break,
+            n => read += n,
+        }
+    }
+    return Ok(read == header.len() && header == MAGIC);
+}
+
+/// Validates a gzip-compressed document as it is inflated, so the whole of it
+/// is never held in memory.
+pub(crate) fn validate_gzip_file(path: &Path) -> Result<(), JsonFileError> {
+    let mut decoder = MultiGzDecoder::new(File::open(path)?);
+    let mut validator = ChunkedUtf8Validator::new();
+    let mut buffer = vec![0; CHUNK_SIZE];
+
+    loop {
+        let read = decoder
+            .read(&mut buffer)
+            .map_err(JsonFileError::Decompress)?;
+        if read == 0 {
+            break;
+        }
+        validator.feed(&buffer[..read])?;
+    }
+
+    validator.finish()?;
+    return Ok(());
+}
@@ -0,0 +1,600 @@
+use super::error::{with_opening, ErrorKind, Fault, ValidationError};
+use super::utils::UTF8Reader;
+use super::validator::{
+    check_top_level, decode_key, end_of_input_fault, invalid_key, is_end_of_number,
+    is_limit_reached, is_stray_character, is_whitespace, scan_value, stray_character_fault,
+    too_deep_fault, too_many_array_elements, too_many_nodes, too_many_object_members,
+    trailing_comma_fault, unicode_whitespace_fault, validate_key, BomPolicy, ValidateOptions,
+};
+use alloc::{format, string::String, vec::Vec};
+
+/// How far an `IncrementalValidator` has got after a `feed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// Characters consumed so far, across all chunks.
+    pub consumed: usize,
+    /// Number of currently open objects and arrays.
+    pub depth: usize,
+    /// Whether a complete top-level value has been seen. A top-level number
+    /// or literal name is never complete before `finish`, as more of it may
+    /// still follow.
+    pub complete: bool,
+}
+
+/// Where a character sits in the document.
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    index: usize,
+    byte_offset: usize,
+    line: usize,
+    column: usize,
+    after_carriage_return: bool,
+}
+
+impl Position {
+    fn advance(&mut self, chr: char) {
+        match chr {
+            '\n' if self.after_carriage_return => {}
+            '\n' | '\r' => {
+                self.line += 1;
+                self.column = 1;
+            }
+            // A byte order mark in front of the document takes no column.
+            '\u{FEFF}' if self.index == 0 => {}
+            _ => self.column += 1,
+        }
+
+        self.index += 1;
+        self.byte_offset += chr.len_utf8();
+        self.after_carriage_return = chr == '\r';
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Frame {
+    Object { members: usize, key: String },
+    Array { elements: usize },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mode {
+    PreDocument,
+    // A "]" may only close the array before its first element, not after a ","
+    Value {
+        closing_allowed: bool,
+    },
+    Key {
+        closing_allowed: bool,
+    },
+    Colon,
+    PostValue,
+    // A string, number or literal name; unquoted ones end at the first
+    // character that can not be part of them.
+    Token {
+        is_key: bool,
+        quote: Option<char>,
+        escaped: bool,
+    },
+    PostDocument,
+}
+
+/// Where a fault happened.
+#[derive(Debug, Clone, Copy)]
+enum At {
+    Current,
+    /// This many scalars into the current token.
+    Token(usize),
+    TopLevel,
+}
+
+/// A fault, where it happened, and whether it was the current value of the
+/// innermost container that failed rather than the container.
+type Failure = (Fault, At, bool);
+
+/// Validates a JSON document that arrives in pieces.
+///
+/// The document may be split anywhere, even inside a string, number or
+/// literal name, so feeding `"[tr"` and then `"ue]"` is valid. A number or
+/// name at the end of a chunk is left open rather than ended, so `"12"` and
+/// then `"34"` is the single number `1234`; only `finish` signals that no
+/// more input is coming.
+///
+/// Each string, number and name is buffered until it ends and then checked
+/// by the same scanners `validate` uses, so errors read the same, but are
+/// only reported once the token is complete. `max_bytes` counts the bytes
+/// fed so far.
+#[derive(Debug, Clone)]
+pub struct IncrementalValidator {
+    options: ValidateOptions,
+    mode: Mode,
+    stack: Vec<Frame>,
+    position: Position,
+    /// The current or last token, and where it starts.
+    token: String,
+    token_start: Position,
+    /// The first character of the top-level value and where it starts.
+    top_level: Option<(char, Position)>,
+    /// Values begun so far, for `max_total_nodes`.
+    nodes: usize,
+    error: Option<ValidationError>,
+}
+
+impl Default for IncrementalValidator {
+    fn default() -> Self {
+        IncrementalValidator::new()
+    }
+}
+
+impl IncrementalValidator {
+    pub fn new() -> Self {
+        return IncrementalValidator::with_options(ValidateOptions::default());
+    }
+
+    pub fn with_options(options: ValidateOptions) -> Self {
+        let start = Position {
+            index: 0,
+            byte_offset: 0,
+            line: 1,
+            column: 1,
+            after_carriage_return: false,
+        };
+
+        return IncrementalValidator {
+            options,
+            mode: Mode::PreDocument,
+            stack: Vec::new(),
+            position: start,
+            token: String::new(),
+            token_start: start,
+            top_level: None,
+            nodes: 0,
+            error: None,
+        };
+    }
+
+    /// Validates the next chunk of the document.
+    ///
+    /// Once an error has been reported, every later call reports it again.
+    pub fn feed(&mut self, chunk: &str) -> Result<Progress, ValidationError> {
+        if let Some(error) = &self.error {
+            return Err(error.clone());
+        }
+
+        if let Some(limit) = self.options.max_bytes {
+            let actual = self.position.byte_offset + chunk.len();
+            if actual > limit {
+                let error = ValidationError::document_too_large(limit, actual);
+                self.error = Some(error.clone());
+                return Err(error);
+            }
+        }
+
+        for chr in chunk.chars() {
+            if let Err(failure) = self.push(chr) {
+                return Err(self.fail(failure));
+            }
+            self.position.advance(chr);
+        }
+
+        return Ok(self.progress());
+    }
+
+    /// Signals that no more input is coming, ending a number or name still
+    /// open at the end of the last chunk and reporting anything left
+    /// unfinished.
+    pub fn finish(mut self) -> Result<(), ValidationError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        if let Err(failure) = self.end_token(None) {
+            return Err(self.fail(failure));
+        }
+
+        let fault = match self.mode {
+            Mode::PostDocument => return Ok(()),
+            Mode::PreDocument if self.position.index == 0 => Fault::new(
+                ErrorKind::EmptyDocument,
+                String::from("JSON document can not be empty"),
+            ),
+            Mode::PreDocument => Fault::new(
+                ErrorKind::EmptyDocument,
+                String::from("No valid JSON value found"),
+            ),
+            Mode::Key {
+                closing_allowed: true,
+            } => end_of_input_fault("an object key or \"}\""),
+            Mode::Key { .. } => end_of_input_fault("an object key"),
+            Mode::Colon => end_of_input_fault("\":\""),
+            _ => match (self.mode, self.stack.last()) {
+                (Mode::Value { .. }, Some(Frame::Object { .. })) => end_of_input_fault("a value"),
+                (
+                    Mode::Value {
+                        closing_allowed: true,
+                    },
+                    _,
+                ) => end_of_input_fault("a value or \"]\""),
+                (Mode::Value { .. }, _) => end_of_input_fault("the next element"),
+                (_, Some(Frame::Object { .. })) => end_of_input_fault("\",\" or \"}\""),
+                _ => end_of_input_fault("\",\" or \"]\""),
+            },
+        };
+
+        return Err(self.fail((fault, At::Current, false)));
+    }
+
+    /// Fails at the current position because the input could not be
+    /// decoded, as `validate_bytes` would.
+    #[cfg(any(feature = "async", feature = "gzip"))]
+    pub(crate) fn reject(&mut self, reason: String) -> ValidationError {
+        let fault = Fault::new(ErrorKind::Encoding, reason);
+        return self.fail((fault, At::Current, false));
+    }
+
+    fn progress(&self) -> Progress {
+        return Progress {
+            consumed: self.position.index,
+            depth: self.stack.len(),
+            complete: matches!(self.mode, Mode::PostDocument),
+        };
+    }
+
+    fn fail(&mut self, failure: Failure) -> ValidationError {
+        let (mut fault, at, in_value) = failure;
+        let at = match at {
+            At::Current => self.position,
+            At::Token(offset) => self.token_position(offset),
+            At::TopLevel => self.top_level.map_or(self.position, |(_, start)| start),
+        };
+
+        if let Some(opened_at) = fault.opened_at {
+            let opening = self.token_position(opened_at);
+            fault.reason = with_opening(&fault.reason, opening.line, opening.column);
+            fault.opened_at = Some(opening.index);
+        }
+
+        // Each container around the failure names the member or element it
+        // failed in, innermost first.
+        let owners = if in_value {
+            self.stack.len()
+        } else {
+            self.stack.len().saturating_sub(1)
+        };
+        for frame in self.stack[..owners].iter().rev() {
+            fault = match frame {
+                Frame::Object { key, .. } => fault.in_member(key),
+                Frame::Array { elements } => fault.in_element(elements - 1),
+            };
+        }
+
+        let error = ValidationError::at(at.index, at.byte_offset, at.line, at.column, fault);
+        self.error = Some(error.clone());
+        return error;
+    }
+
+    /// Where the scalar at `offset` in the current token sits.
+    fn token_position(&self, offset: usize) -> Position {
+        let mut position = self.token_start;
+        for chr in self.token.chars().take(offset) {
+            position.advance(chr);
+        }
+        return position;
+    }
+
+    fn push(&mut self, chr: char) -> Result<(), Failure> {
+        let mut buffer = [0; 4];
+        let text: &str = chr.encode_utf8(&mut buffer);
+
+        if let Mode::Token {
+            is_key,
+            quote,
+            escaped,
+        } = self.mode
+        {
+            match quote {
+                Some(quote) => {
+                    self.token.push(chr);
+                    if chr == quote && !escaped {
+                        return self.end_token(None);
+                    }
+                    self.mode = Mode::Token {
+                        is_key,
+                        quote: Some(quote),
+                        escaped: !escaped && chr == '\\',
+                    };
+                    return Ok(());
+                }
+                // The character that ends a token belongs to whatever follows it.
+                None if is_end_of_number(text, &self.options) || (is_key && chr == ':') => {
+                    self.end_token(Some(chr))?;
+                }
+                None => {
+                    self.token.push(chr);
+                    return Ok(());
+                }
+            }
+        }
+
+        match self.mode {
+            Mode::PreDocument => {
+                if self.position.index == 0 {
+                    match (chr, self.options.bom_policy) {
+                        ('\u{FEFF}', BomPolicy::Forbid) => {
+                            return Err((unicode_whitespace_fault(text), At::Current, false))
+                        }
+                        ('\u{FEFF}', _) => return Ok(()),
+                        (_, BomPolicy::Require) => {
+                            let reason =
+                                String::from("JSON document should start with a byte order mark");
+                            let fault = Fault::new(ErrorKind::MissingByteOrderMark, reason);
+                            return Err((fault, At::Current, false));
+                        }
+                        _ => {}
+                    }
+                }
+
+                if is_whitespace(text, &self.options) {
+                    return Ok(());
+                }
+                if is_stray_character(text) {
+                    return Err((stray_character_fault(text), At::Current, false));
+                }
+                self.top_level = Some((chr, self.position));
+                return self.begin_value(chr);
+            }
+            Mode::Value { closing_allowed } => {
+                if let Some(Frame::Array { elements }) = self.stack.last_mut() {
+                    if chr == ']' && closing_allowed {
+                        return self.close();
+                    }
+                    // Reported at the element the "," promised
+                    if chr == ']' {
+                        *elements += 1;
+                        return Err((trailing_comma_fault("]"), At::Current, true));
+                    }
+                }
+                if is_whitespace(text, &self.options) {
+                    return Ok(());
+                }
+                if is_stray_character(text) {
+                    return Err((stray_character_fault(text), At::Current, false));
+                }
+                if let Some(Frame::Array { elements }) = self.stack.last_mut() {
+                    if is_limit_reached(self.options.max_array_elements, *elements) {
+                        return Err((too_many_array_elements(&self.options), At::Current, false));
+                    }
+                    *elements += 1;
+                }
+                return self.begin_value(chr);
+            }
+            Mode::Key { closing_allowed } => {
+                match chr {
+                    '}' if closing_allowed => return self.close(),
+                    '}' => return Err((trailing_comma_fault("}"), At::Current, false)),
+                    _ if is_whitespace(text, &self.options) => return Ok(()),
+                    _ if is_stray_character(text) => {
+                        return Err((stray_character_fault(text), At::Current, false))
+                    }
+                    _ => {}
+                }
+
+                if let Some(Frame::Object { members, .. }) = self.stack.last_mut() {
+                    if is_limit_reached(self.options.max_object_members, *members) {
+                        return Err((too_many_object_members(&self.options), At::Current, false));
+                    }
+                    *members += 1;
+                }
+
+                let quote = match chr {
+                    '"' => Some(chr),
+                    '\'' if self.options.allow_single_quotes => Some(chr),
+                    _ if self.options.allow_unquoted_keys => None,
+                    _ => return Err((invalid_key(&self.options), At::Current, false)),
+                };
+                self.begin_token(chr, true, quote);
+            }
+            Mode::Colon => match chr {
+                ':' => {
+                    self.mode = Mode::Value {
+                        closing_allowed: false,
+                    }
+                }
+                _ if is_whitespace(text, &self.options) => {}
+                _ if is_stray_character(text) => {
+                    return Err((stray_character_fault(text), At::Current, false))
+                }
+                _ => {
+                    let reason = format!("Invalid character after object key: \"{}\"", chr);
+                    return Err((reason.into(), At::Current, false));
+                }
+            },
+            Mode::PostValue => match (chr, self.stack.last()) {
+                ('}', Some(Frame::Object { .. })) | (']', Some(Frame::Array { .. })) => {
+                    return self.close()
+                }
+                (',', Some(Frame::Object { .. })) => {
+                    self.mode = Mode::Key {
+                        closing_allowed: false,
+                    }
+                }
+                (',', _) => {
+                    self.mode = Mode::Value {
+                        closing_allowed: false,
+                    }
+                }
+                _ if is_whitespace(text, &self.options) => {}
+                _ if is_stray_character(text) => {
+                    return Err((stray_character_fault(text), At::Current, false))
+                }
+                (_, Some(Frame::Object { .. })) => {
+                    let reason = format!("Invalid character after object value: \"{}\"", chr);
+                    return Err((reason.into(), At::Current, false));
+                }
+                _ => {
+                    return Err((
+                        format!("Invalid character: \"{}\"", chr).into(),
+                        At::Current,
+                        false,
+                    ))
+                }
+            },
+            Mode::PostDocument => {
+                if is_whitespace(text, &self.options) {
+                    return Ok(());
+                }
+                if is_stray_character(text) {
+                    return Err((stray_character_fault(text), At::Current, false));
+                }
+                let reason = format!("Expect EOF, but found \"{}\"", chr);
+                return Err((
+                    Fault::new(ErrorKind::TrailingData, reason),
+                    At::Current,
+                    false,
+                ));
+            }
+            Mode::Token { .. } => {}
+        }
+
+        return Ok(());
+    }
+
+    fn begin_value(&mut self, chr: char) -> Result<(), Failure> {
+        if is_limit_reached(self.options.max_total_nodes, self.nodes) {
+            return Err((too_many_nodes(&self.options), At::Current, true));
+        }
+        self.nodes += 1;
+
+        let frame = match chr {
+            '{' => Frame::Object {
+                members: 0,
+                key: String::new(),
+            },
+            '[' => Frame::Array { elements: 0 },
+            '"' => {
+                self.begin_token(chr, false, Some(chr));
+                return Ok(());
+            }
+            '\'' if self.options.allow_single_quotes => {
+                self.begin_token(chr, false, Some(chr));
+                return Ok(());
+            }
+            _ => {
+                self.begin_token(chr, false, None);
+                // Nothing starts with a character that would end it, and the
+                // scanner says why.
+                let mut buffer = [0; 4];
+                if is_end_of_number(chr.encode_utf8(&mut buffer), &self.options) {
+                    return self.end_token(None);
+                }
+                return Ok(());
+            }
+        };
+
+        if self.stack.len() >= self.options.depth_limit() {
+            return Err((too_deep_fault(), At::Current, true));
+        }
+
+        self.mode = match frame {
+            Frame::Object { .. } => Mode::Key {
+                closing_allowed: true,
+            },
+            Frame::Array { .. } => Mode::Value {
+                closing_allowed: true,
+            },
+        };
+        self.stack.push(frame);
+        return Ok(());
+    }
+
+    fn begin_token(&mut self, chr: char, is_key: bool, quote: Option<char>) {
+        self.token.clear();
+        self.token.push(chr);
+        self.token_start = self.position;
+        self.mode = Mode::Token {
+            is_key,
+            quote,
+            escaped: false,
+        };
+    }
+
+    /// Checks the buffered token with the validator's scanners, which also
+    /// see the character `next` that ended it, as they would in a whole
+    /// document. Anything after the part they accept is fed back in, and
+    /// fails as it would after any value.
+    fn end_token(&mut self, next: Option<char>) -> Result<(), Failure> {
+        let (is_key, quote) = match self.mode {
+            Mode::Token { is_key, quote, .. } => (is_key, quote),
+            _ => return Ok(()),
+        };
+
+        let length = self.token.chars().count();
+        self.token.extend(next);
+
+        let document = UTF8Reader::new(&self.token);
+        let (result, step) = if is_key {
+            validate_key(&document, 0, &self.options)
+        } else {
+            scan_value(&document, 0, 0, &self.options)
+        };
+        let key = match result {
+            Ok(_) if is_key => decode_key(&document, 0, step, &self.options).ok(),
+            _ => None,
+        };
+
+        if next.is_some() {
+            self.token.pop();
+        }
+
+        if let Err(fault) = result {
+            // A fault inside a quoted key says more than a generic key error.
+            let fault = if is_key && fault.kind == ErrorKind::Syntax && quote.is_none() {
+                invalid_key(&self.options)
+            } else {
+                fault
+            };
+            return Err((fault, At::Token(step), !is_key));
+        }
+
+        if is_key {
+            if let Some(Frame::Object { key: current, .. }) = self.stack.last_mut() {
+                *current = key.unwrap_or_else(|| String::from("?"));
+            }
+            self.mode = Mode::Colon;
+        } else {
+            self.end_value()?;
+        }
+
+        let rest: String = self.token.chars().take(length).skip(step).collect();
+        let resume = self.position;
+        self.position = self.token_position(step);
+        for chr in rest.chars() {
+            self.push(chr)?;
+            self.position.advance(chr);
+        }
+        self.position = resume;
+
+        return Ok(());
+    }
+
+    fn close(&mut self) -> Result<(), Failure> {
+        self.stack.pop();
+        return self.end_value();
+    }
+
+    fn end_value(&mut self) -> Result<(), Failure> {
+        if !self.stack.is_empty() {
+            self.mode = Mode::PostValue;
+            return Ok(());
+        }
+
+        self.mode = Mode::PostDocument;
+        if let Some((chr, _)) = self.top_level {
+            let mut buffer = [0; 4];
+            let text = chr.encode_utf8(&mut buffer);
+            if let Err(fault) = check_top_level(text, self.options.require_top_level) {
+                return Err((fault, At::TopLevel, false));
+            }
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,146 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use super::cursor::{Cursor, Token, TokenKind};
+use super::error::{Fault, ValidationError};
+use super::pointer::{parse_array_index, parse_pointer};
+use super::utils::UTF8Reader;
+use super::validator::{decode_key, validate_with_options, ValidateOptions};
+
+/// The span of every value in a document, built in one pass so that many
+/// JSON Pointers can be resolved against it without scanning the document
+/// again. Each lookup costs one step per pointer token, where
+/// `locate_pointer` walks past every sibling on the way.
+#[derive(Debug, Clone)]
+pub struct JsonIndex {
+    /// The root is the first entry.
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    span: (usize, usize),
+    children: Children,
+}
+
+#[derive(Debug, Clone)]
+enum Children {
+    Scalar,
+    Array(Vec<usize>),
+    /// Sorted by key. Only the first of duplicate keys is kept, as in
+    /// `locate_pointer`.
+    Object(Vec<(String, usize)>),
+}
+
+impl JsonIndex {
+    /// The start and end indices of the value addressed by an RFC 6901
+    /// JSON Pointer, the same as `locate_pointer` would give. Returns `None`
+    /// if the pointer is malformed or nothing is found at that path.
+    pub fn get(&self, pointer: &str) -> Option<(usize, usize)> {
+        let mut entry = &self.entries[0];
+
+        for token in parse_pointer(pointer)?.iter() {
+            let child = match &entry.children {
+                Children::Scalar => return None,
+                Children::Array(elements) => *elements.get(parse_array_index(token)?)?,
+                Children::Object(members) => {
+                    let position = members
+                        .binary_search_by(|(key, _)| key.as_str().cmp(token))
+                        .ok()?;
+                    members[position].1
+                }
+            };
+            entry = &self.entries[child];
+        }
+
+        return Some(entry.span);
+    }
+}
+
+/// Validates the document and indexes every value in it; see `JsonIndex`.
+pub fn build_index(document: &UTF8Reader) -> Result<JsonIndex, ValidationError> {
+    return build_index_with_options(document, &ValidateOptions::default());
+}
+
+pub fn build_index_with_options(
+    document: &UTF8Reader,
+    options: &ValidateOptions,
+) -> Result<JsonIndex, ValidationError> {
+    validate_with_options(document, options)?;
+
+    let mut cursor = Cursor::new(document, options);
+    let origin = cursor.position();
+    let token = cursor.next_token();
+
+    let mut entries = vec![];
+    return match index_value(&mut cursor, token, &mut entries) {
+        Ok(_) => Ok(JsonIndex { entries }),
+        Err((fault, index)) => Err(ValidationError::from_fault(document, index, origin, fault)),
+    };
+}
+
+/// Indexes the value that starts with `token`, leaving `cursor` past it. The
+/// document must already be known to be valid; only decoding an escaped key
+/// can fail.
+fn index_value(
+    cursor: &mut Cursor,
+    token: Token,
+    entries: &mut Vec<Entry>,
+) -> Result<usize, (Fault, usize)> {
+    let id = entries.len();
+    entries.push(Entry {
+        span: (token.start, token.end),
+        children: Children::Scalar,
+    });
+
+    let children = match token.kind {
+        TokenKind::BeginObject => {
+            let mut members = vec![];
+            loop {
+                let key = cursor.next_token();
+                match key.kind {
+                    TokenKind::Comma => {}
+                    TokenKind::Key => {
+                        let name = decode_key(
+                            cursor.document(),
+                            key.start,
+                            key.end - key.start,
+                            cursor.options(),
+                        )
+                        .map_err(|(fault, offset)| (fault, key.start + offset))?;
+
+                        // Past the ":"
+                        cursor.next_token();
+                        let value = cursor.next_token();
+                        members.push((name, index_value(cursor, value, entries)?));
+                    }
+                    _ => break,
+                }
+            }
+
+            // The sort is stable, so the first of each run of duplicates is
+            // the one that appeared first.
+            members.sort_by(|(a, _), (b, _)| a.cmp(b));
+            members.dedup_by(|(later, _), (earlier, _)| later == earlier);
+            Children::Object(members)
+        }
+        TokenKind::BeginArray => {
+            let mut elements = vec![];
+            loop {
+                let element = cursor.next_token();
+                match element.kind {
+                    TokenKind::Comma => {}
+                    TokenKind::EndArray | TokenKind::End => break,
+                    _ => elements.push(index_value(cursor, element, entries)?),
+                }
+            }
+            Children::Array(elements)
+        }
+        _ => Children::Scalar,
+    };
+
+    entries[id] = Entry {
+        span: (token.start, cursor.position()),
+        children,
+    };
+    return Ok(id);
+}
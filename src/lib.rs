@@ -0,0 +1,100 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::needless_return)]
+
+extern crate alloc;
+
+pub mod array_iter;
+#[cfg(feature = "async")]
+pub mod async_io;
+pub mod borrowed;
+pub mod builder;
+#[cfg(feature = "capi")]
+pub mod capi;
+pub mod comments;
+pub mod convert;
+mod cursor;
+pub mod decode;
+pub mod diff;
+pub mod document;
+pub mod error;
+pub mod events;
+#[cfg(feature = "gzip")]
+mod gzip;
+mod incremental;
+pub mod index;
+pub mod merge;
+pub mod parser;
+pub mod pointer;
+#[cfg(feature = "python")]
+mod python;
+pub mod repair;
+pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde_value;
+pub mod serialize;
+pub mod spanned;
+pub mod stats;
+pub mod tokenizer;
+pub mod utf16;
+pub mod utils;
+pub mod validator;
+pub mod value;
+pub mod walk;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use array_iter::{iter_top_level_array, ArrayIter};
+#[cfg(feature = "async")]
+pub use async_io::validate_async;
+pub use borrowed::{parse_borrowed, parse_borrowed_with_options, JsonValueRef};
+pub use builder::Validator;
+pub use comments::{parse_with_comments, Comment};
+pub use convert::ConversionError;
+pub use decode::{
+    decode_string, decode_string_with_options, unescape_cow, unescape_cow_with_options,
+};
+pub use diff::{diff, Change, ChangeKind};
+pub use document::{
+    parse_document, parse_document_with_options, write_preserving, Document, Element, Member, Node,
+};
+#[cfg(feature = "std")]
+pub use error::JsonFileError;
+pub use error::{error_to_json, ErrorKind, ValidationError};
+pub use events::{parse_events, parse_events_with_options, JsonEvent};
+pub use incremental::{IncrementalValidator, Progress};
+pub use index::{build_index, build_index_with_options, JsonIndex};
+pub use merge::{merge, MergeOptions};
+pub use parser::{parse, parse_with_options};
+pub use pointer::locate_pointer;
+pub use repair::repair;
+pub use schema::{validate_against, Schema, SchemaError};
+#[cfg(feature = "serde")]
+pub use serde_value::to_serde_value;
+pub use serialize::{
+    format_number_canonical, to_string, to_string_with_options, LineEnding, PrettyOptions,
+    SerializeError, SerializeOptions,
+};
+#[cfg(feature = "std")]
+pub use serialize::{to_writer_pretty, write_json, write_json_pretty, write_json_with_options};
+pub use spanned::{
+    parse_with_spans, parse_with_spans_with_options, SpannedKind, SpannedMember, SpannedValue,
+};
+pub use stats::{analyze, DocumentStats};
+pub use tokenizer::{Token, TokenKind, Tokenizer};
+pub use utf16::{validate_utf16, Endianness};
+#[cfg(feature = "std")]
+pub use utils::read_file_as_utf8;
+pub use utils::{UTF8Reader, UTF8ReaderResult};
+pub use validator::{
+    is_valid, looks_like_json, quick_reject, skip_value, validate, validate_at_offset,
+    validate_at_offset_with_options, validate_bytes, validate_bytes_with_options, validate_many,
+    validate_str, validate_str_with_options, validate_stream, validate_stream_with_options,
+    validate_with_options, validate_with_progress, validate_with_warnings, BomPolicy,
+    LoneSurrogatePolicy, SolidusPolicy, TopLevelType, ValidateOptions, ValidateResult,
+};
+#[cfg(feature = "std")]
+pub use validator::{validate_all_files, validate_file};
+pub use value::{classify_number, semantic_eq, JsonNumber, JsonValue, NumberClass, NumberKind};
+pub use walk::{walk, PathSegment};
+#[cfg(feature = "wasm")]
+pub use wasm::validate_wasm;
@@ -1,23 +1,78 @@
-use std::path::PathBuf;
+use std::env;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process;
 
-mod validator;
-use validator::validate;
+use rust_json::{validate_file, validate_str, JsonFileError};
 
 mod test;
 use test::run_suite;
 
-mod utils;
-use utils::{read_file_as_utf8, UTF8Reader};
+const USAGE: &str = "Usage: rust-json [--quiet] <file>...
+       rust-json --suite
+
+Validates each file as JSON. Use \"-\" to read from stdin.
+
+Options:
+    -q, --quiet    Only report invalid files
+    --suite        Run the bundled JSONTestSuite conformance suite";
 
 fn main() {
-    run_suite();
-
-    // let content = read_file_as_utf8(&PathBuf::from(
-    //     "JSONTestSuite/test_parsing/y_object_empty.json",
-    //     "test/playground.json",
-    // ))
-    // .unwrap();
-    // let reader = UTF8Reader::new(&content);
-    // println!("{:?}", validate(&reader));
-    // println!("{:?}", reader.get_tail());
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--suite") {
+        run_suite();
+        return;
+    }
+
+    let quiet = args.iter().any(|arg| arg == "-q" || arg == "--quiet");
+    let paths: Vec<&String> = args
+        .iter()
+        .filter(|arg| *arg == "-" || !arg.starts_with('-'))
+        .collect();
+
+    if paths.is_empty() || args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let mut all_valid = true;
+
+    for path in paths {
+        let result = if path == "-" {
+            let mut content = String::new();
+            io::stdin()
+                .read_to_string(&mut content)
+                .map_err(JsonFileError::from)
+                .and_then(|_| validate_str(&content).map_err(JsonFileError::from))
+        } else {
+            validate_file(Path::new(path))
+        };
+
+        match result {
+            Ok(_) => {
+                if !quiet {
+                    println!("{}: OK", path);
+                }
+            }
+            Err(JsonFileError::Invalid(error)) => {
+                println!(
+                    "{}:{}:{}: {}",
+                    path,
+                    error.line(),
+                    error.column(),
+                    error.reason()
+                );
+                all_valid = false;
+            }
+            Err(why) => {
+                println!("{}: FAIL ({})", path, why);
+                all_valid = false;
+            }
+        }
+    }
+
+    if !all_valid {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,37 @@
+use super::value::JsonValue;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MergeOptions {
+    /// Appends the override's elements to the base array instead of
+    /// replacing it.
+    pub concat_arrays: bool,
+}
+
+/// Deep-merges `over` onto `base`, as when layering configuration files.
+///
+/// Objects are merged key by key, recursively; keys only in `over` are
+/// appended after the base keys. Anything else, including a value whose type
+/// differs from the base, is taken from `over`, except that arrays are
+/// concatenated when `concat_arrays` is set.
+pub fn merge(base: JsonValue, over: JsonValue, options: MergeOptions) -> JsonValue {
+    return match (base, over) {
+        (JsonValue::Object(mut members), JsonValue::Object(overrides)) => {
+            for (key, value) in overrides {
+                match members.iter().position(|(name, _)| *name == key) {
+                    Some(position) => {
+                        let existing =
+                            core::mem::replace(&mut members[position].1, JsonValue::Null);
+                        members[position].1 = merge(existing, value, options);
+                    }
+                    None => members.push((key, value)),
+                }
+            }
+            JsonValue::Object(members)
+        }
+        (JsonValue::Array(mut elements), JsonValue::Array(extra)) if options.concat_arrays => {
+            elements.extend(extra);
+            JsonValue::Array(elements)
+        }
+        (_, over) => over,
+    };
+}
@@ -0,0 +1,58 @@
+use super::error::ValidationError;
+use super::events::{parse_events_with_options, JsonEvent};
+use super::utils::UTF8Reader;
+use super::validator::ValidateOptions;
+use super::value::JsonValue;
+use alloc::{string::String, vec, vec::Vec};
+
+enum Frame {
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>, String),
+}
+
+pub fn parse(document: &UTF8Reader) -> Result<JsonValue, ValidationError> {
+    return parse_with_options(document, &ValidateOptions::default());
+}
+
+pub fn parse_with_options(
+    document: &UTF8Reader,
+    options: &ValidateOptions,
+) -> Result<JsonValue, ValidationError> {
+    let mut stack: Vec<Frame> = vec![];
+    let mut root = JsonValue::Null;
+
+    parse_events_with_options(document, options, |event| {
+        let value = match event {
+            JsonEvent::BeginObject => {
+                stack.push(Frame::Object(vec![], String::new()));
+                return;
+            }
+            JsonEvent::BeginArray => {
+                stack.push(Frame::Array(vec![]));
+                return;
+            }
+            JsonEvent::Key(key) => {
+                if let Some(Frame::Object(_, pending)) = stack.last_mut() {
+                    *pending = key;
+                }
+                return;
+            }
+            JsonEvent::EndObject | JsonEvent::EndArray => match stack.pop() {
+                Some(Frame::Object(members, _)) => JsonValue::Object(members),
+                Some(Frame::Array(elements)) => JsonValue::Array(elements),
+                None => return,
+            },
+            JsonEvent::Value(value) => value,
+        };
+
+        match stack.last_mut() {
+            Some(Frame::Array(elements)) => elements.push(value),
+            Some(Frame::Object(members, pending)) => {
+                members.push((core::mem::take(pending), value))
+            }
+            None => root = value,
+        }
+    })?;
+
+    return Ok(root);
+}
@@ -0,0 +1,143 @@
+use alloc::{string::String, vec::Vec};
+
+use super::decode::scan_and_decode_string;
+use super::utils::{UTF8Reader, UTF8ReaderResult};
+use super::validator::{skip_whitespace, value_width, ValidateOptions};
+
+/// Finds the value addressed by an RFC 6901 JSON Pointer such as `/a/0/b`,
+/// returning its start and end indices in the document.
+///
+/// Only the path to the target is scanned, and siblings are validated just
+/// enough to be skipped over, so a document that is invalid elsewhere may
+/// still resolve. Returns `None` if the pointer is malformed or nothing is
+/// found at that path.
+pub fn locate_pointer(document: &UTF8Reader, pointer: &str) -> Option<(usize, usize)> {
+    let options = ValidateOptions::default();
+    let tokens = parse_pointer(pointer)?;
+
+    let mut index = skip_whitespace(document, 0, &options);
+    for token in tokens.iter() {
+        index = match document.look_ahead(index, 1) {
+            UTF8ReaderResult::Ok("{") => find_member(document, index, token, &options)?,
+            UTF8ReaderResult::Ok("[") => find_element(document, index, token, &options)?,
+            _ => return None,
+        };
+    }
+
+    let width = value_width(document, index, &options)?;
+    return Some((index, index + width));
+}
+
+/// Splits a pointer into its unescaped reference tokens.
+pub(crate) fn parse_pointer(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+
+    if !pointer.starts_with('/') {
+        return None;
+    }
+
+    let mut tokens = Vec::new();
+    for raw in pointer[1..].split('/') {
+        let mut token = String::new();
+        let mut chars = raw.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '~' => match chars.next() {
+                    Some('0') => token.push('~'),
+                    Some('1') => token.push('/'),
+                    _ => return None,
+                },
+                _ => token.push(c),
+            }
+        }
+        tokens.push(token);
+    }
+
+    return Some(tokens);
+}
+
+/// Index of the value stored under `key` in the object starting at `start`.
+fn find_member(
+    document: &UTF8Reader,
+    start: usize,
+    key: &str,
+    options: &ValidateOptions,
+) -> Option<usize> {
+    let mut ptr = skip_whitespace(document, start + 1, options);
+
+    if let UTF8ReaderResult::Ok("}") = document.look_ahead(ptr, 1) {
+        return None;
+    }
+
+    loop {
+        let (name, step) = scan_and_decode_string(document, ptr, options);
+        let name = name.ok()?;
+
+        ptr = skip_whitespace(document, ptr + step, options);
+        match document.look_ahead(ptr, 1) {
+            UTF8ReaderResult::Ok(":") => {}
+            _ => return None,
+        }
+
+        let value = skip_whitespace(document, ptr + 1, options);
+        if name == key {
+            return Some(value);
+        }
+
+        let end = value + value_width(document, value, options)?;
+        ptr = skip_whitespace(document, end, options);
+        match document.look_ahead(ptr, 1) {
+            UTF8ReaderResult::Ok(",") => ptr = skip_whitespace(document, ptr + 1, options),
+            _ => return None,
+        }
+    }
+}
+
+/// Index of the element at position `token` in the array starting at `start`.
+fn find_element(
+    document: &UTF8Reader,
+    start: usize,
+    token: &str,
+    options: &ValidateOptions,
+) -> Option<usize> {
+    let position = parse_array_index(token)?;
+    let mut ptr = skip_whitespace(document, start + 1, options);
+
+    if let UTF8ReaderResult::Ok("]") = document.look_ahead(ptr, 1) {
+        return None;
+    }
+
+    for _ in 0..position {
+        let end = ptr + value_width(document, ptr, options)?;
+        ptr = skip_whitespace(document, end, options);
+        match document.look_ahead(ptr, 1) {
+            UTF8ReaderResult::Ok(",") => ptr = skip_whitespace(document, ptr + 1, options),
+            _ => return None,
+        }
+    }
+
+    return Some(ptr);
+}
+
+/// Array indices are decimal without leading zeros; `-` names no element.
+pub(crate) fn parse_array_index(token: &str) -> Option<usize> {
+    let is_decimal = !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit());
+    if !is_decimal || (token.len() > 1 && token.starts_with('0')) {
+        return None;
+    }
+    return token.parse().ok();
+}
+
+/// Appends `/token`, escaping `~` and `/` as RFC 6901 requires.
+pub(crate) fn push_token(path: &mut String, token: &str) {
+    path.push('/');
+    for c in token.chars() {
+        match c {
+            '~' => path.push_str("~0"),
+            '/' => path.push_str("~1"),
+            _ => path.push(c),
+        }
+    }
+}
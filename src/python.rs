@@ -0,0 +1,70 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyInt, PyList};
+
+use super::error::ValidationError;
+use super::parser;
+use super::utils::UTF8Reader;
+use super::validator::validate_str;
+use super::value::{JsonValue, NumberKind};
+
+/// Returns `None` for a valid document, and raises `ValueError` otherwise.
+#[pyfunction]
+fn validate(s: &str) -> PyResult<()> {
+    return validate_str(s).map_err(value_error);
+}
+
+/// Parses a document into `dict`, `list`, `str`, `int`, `float`, `bool`
+/// and `None`, raising `ValueError` if it is not valid.
+#[pyfunction]
+fn parse(py: Python<'_>, s: &str) -> PyResult<Py<PyAny>> {
+    let value = parser::parse(&UTF8Reader::new(s)).map_err(value_error)?;
+    return to_python(py, &value);
+}
+
+fn value_error(error: ValidationError) -> PyErr {
+    return PyValueError::new_err(format!(
+        "{} (line {}, column {})",
+        error.reason(),
+        error.line(),
+        error.column()
+    ));
+}
+
+/// Integers become `int`, however large; anything with a fraction or an
+/// exponent becomes `float`.
+fn to_python(py: Python<'_>, value: &JsonValue) -> PyResult<Py<PyAny>> {
+    return Ok(match value {
+        JsonValue::Null => py.None(),
+        JsonValue::Bool(b) => b.into_pyobject(py)?.to_owned().into_any().unbind(),
+        JsonValue::Number(number) => match number.as_i64() {
+            Some(integer) => integer.into_pyobject(py)?.into_any().unbind(),
+            None if number.classify().kind == NumberKind::Integer => {
+                py.get_type::<PyInt>().call1((number.as_str(),))?.unbind()
+            }
+            None => number.as_f64().into_pyobject(py)?.into_any().unbind(),
+        },
+        JsonValue::String(s) => s.into_pyobject(py)?.into_any().unbind(),
+        JsonValue::Array(elements) => {
+            let list = PyList::empty(py);
+            for element in elements {
+                list.append(to_python(py, element)?)?;
+            }
+            list.into_any().unbind()
+        }
+        JsonValue::Object(members) => {
+            let dict = PyDict::new(py);
+            for (key, member) in members {
+                dict.set_item(key, to_python(py, member)?)?;
+            }
+            dict.into_any().unbind()
+        }
+    });
+}
+
+#[pymodule]
+fn rust_json(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(validate, m)?)?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    return Ok(());
+}
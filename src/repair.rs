@@ -0,0 +1,134 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use super::error::ValidationError;
+use super::parser::parse_with_options;
+use super::serialize::to_string;
+use super::utils::UTF8Reader;
+use super::validator::{validate_str, ValidateOptions};
+
+/// Turns a document with common mistakes into strict JSON: trailing commas,
+/// `'single quoted'` strings, unquoted keys and containers left open at the
+/// end of the input. A document that is already valid comes back unchanged;
+/// a repaired one comes back compact.
+///
+/// Anything else, such as a truncated string or a key with no value, is not
+/// guessed at, and the error for the original input is returned instead.
+pub fn repair(input: &str) -> Result<String, ValidationError> {
+    let original = match validate_str(input) {
+        Ok(_) => return Ok(String::from(input)),
+        Err(error) => error,
+    };
+
+    let closed = match close_containers(input) {
+        Some(closed) => closed,
+        None => return Err(original),
+    };
+
+    let options = ValidateOptions::default()
+        .allow_single_quotes(true)
+        .allow_unquoted_keys(true);
+    // The options let no `NaN` or `Infinity` through, so anything that
+    // parses can be written.
+    return match parse_with_options(&UTF8Reader::new(&closed), &options) {
+        Ok(value) => to_string(&value).map_err(|_| original),
+        Err(_) => Err(original),
+    };
+}
+
+/// Drops commas that follow a value before a closing bracket, and closes
+/// whatever is still open at the end. A comma with no value before it, as in
+/// `[,]`, marks a missing element rather than a trailing comma, so it is
+/// kept for parsing to reject. `None` if the input ends inside a string.
+fn close_containers(input: &str) -> Option<String> {
+    enum State {
+        Outside,
+        Quoted { quote: char, escaped: bool },
+    }
+
+    let mut state = State::Outside;
+    let mut output = String::with_capacity(input.len());
+    let mut open: Vec<char> = vec![];
+    // Where the last comma outside a string was written, if it follows a
+    // value, until anything else significant follows it.
+    let mut dangling_comma: Option<usize> = None;
+    // Whether the last significant character ended a value.
+    let mut after_value = false;
+
+    for chr in input.chars() {
+        match state {
+            State::Quoted { quote, escaped } => {
+                state = match chr {
+                    _ if escaped => State::Quoted {
+                        quote,
+                        escaped: false,
+                    },
+                    '\\' => State::Quoted {
+                        quote,
+                        escaped: true,
+                    },
+                    _ if chr == quote => {
+                        after_value = true;
+                        State::Outside
+                    }
+                    _ => State::Quoted { quote, escaped },
+                };
+            }
+            State::Outside => match chr {
+                '"' | '\'' => {
+                    dangling_comma = None;
+                    state = State::Quoted {
+                        quote: chr,
+                        escaped: false,
+                    };
+                }
+                '[' => {
+                    dangling_comma = None;
+                    after_value = false;
+                    open.push(']');
+                }
+                '{' => {
+                    dangling_comma = None;
+                    after_value = false;
+                    open.push('}');
+                }
+                ']' | '}' => {
+                    if let Some(position) = dangling_comma.take() {
+                        output.remove(position);
+                    }
+                    after_value = true;
+                    open.pop();
+                }
+                ',' => {
+                    dangling_comma = if after_value {
+                        Some(output.len())
+                    } else {
+                        None
+                    };
+                    after_value = false;
+                }
+                ':' => {
+                    dangling_comma = None;
+                    after_value = false;
+                }
+                ' ' | '\t' | '\n' | '\r' => {}
+                _ => {
+                    dangling_comma = None;
+                    after_value = true;
+                }
+            },
+        }
+        output.push(chr);
+    }
+
+    if let State::Quoted { .. } = state {
+        return None;
+    }
+
+    if !open.is_empty() {
+        if let Some(position) = dangling_comma {
+            output.remove(position);
+        }
+        output.extend(open.iter().rev());
+    }
+    return Some(output);
+}
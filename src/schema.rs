@@ -0,0 +1,144 @@
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+use core::fmt;
+
+use super::pointer::push_token;
+use super::value::JsonValue;
+
+/// A minimal description of the shape a value should have.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schema {
+    /// An object whose listed fields match their schemas. Fields that are
+    /// not listed are allowed, and listed fields are optional unless named
+    /// in `required`.
+    Object {
+        fields: Vec<(String, Schema)>,
+        required: Vec<String>,
+    },
+    /// An array whose every element matches the schema.
+    Array(Box<Schema>),
+    String,
+    Number,
+    Bool,
+    Null,
+    /// A value matching at least one of the schemas.
+    AnyOf(Vec<Schema>),
+}
+
+/// A schema violation, located by a JSON Pointer such as `/users/0/name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    pub path: String,
+    pub reason: String,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        write!(f, "{}: {}", path, self.reason)
+    }
+}
+
+/// Checks `value` against `schema`, collecting every violation rather than
+/// stopping at the first.
+pub fn validate_against(value: &JsonValue, schema: &Schema) -> Result<(), Vec<SchemaError>> {
+    let mut errors = vec![];
+    check(&mut String::new(), value, schema, &mut errors);
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+    return Err(errors);
+}
+
+fn check(path: &mut String, value: &JsonValue, schema: &Schema, errors: &mut Vec<SchemaError>) {
+    match (schema, value) {
+        (Schema::Object { fields, required }, JsonValue::Object(members)) => {
+            for name in required.iter() {
+                if !members.iter().any(|(key, _)| key == name) {
+                    errors.push(SchemaError {
+                        path: path.clone(),
+                        reason: format!("Missing required field {:?}", name),
+                    });
+                }
+            }
+
+            for (key, member) in members.iter() {
+                if let Some((_, field)) = fields.iter().find(|(name, _)| name == key) {
+                    let length = path.len();
+                    push_token(path, key);
+                    check(path, member, field, errors);
+                    path.truncate(length);
+                }
+            }
+        }
+        (Schema::Array(element), JsonValue::Array(elements)) => {
+            for (position, item) in elements.iter().enumerate() {
+                let length = path.len();
+                push_token(path, &format!("{}", position));
+                check(path, item, element, errors);
+                path.truncate(length);
+            }
+        }
+        (Schema::String, JsonValue::String(_))
+        | (Schema::Number, JsonValue::Number(_))
+        | (Schema::Bool, JsonValue::Bool(_))
+        | (Schema::Null, JsonValue::Null) => {}
+        (Schema::AnyOf(alternatives), _) => {
+            let matched = alternatives.iter().any(|alternative| {
+                let mut attempt = vec![];
+                check(&mut path.clone(), value, alternative, &mut attempt);
+                return attempt.is_empty();
+            });
+
+            if !matched {
+                errors.push(SchemaError {
+                    path: path.clone(),
+                    reason: format!(
+                        "Expected any of {}, found {}",
+                        alternatives
+                            .iter()
+                            .map(describe_schema)
+                            .collect::<Vec<&str>>()
+                            .join(", "),
+                        describe_value(value)
+                    ),
+                });
+            }
+        }
+        _ => errors.push(SchemaError {
+            path: path.clone(),
+            reason: format!(
+                "Expected {}, found {}",
+                describe_schema(schema),
+                describe_value(value)
+            ),
+        }),
+    }
+}
+
+fn describe_schema(schema: &Schema) -> &'static str {
+    return match schema {
+        Schema::Object { .. } => "object",
+        Schema::Array(_) => "array",
+        Schema::String => "string",
+        Schema::Number => "number",
+        Schema::Bool => "boolean",
+        Schema::Null => "null",
+        Schema::AnyOf(_) => "one of several types",
+    };
+}
+
+pub(crate) fn describe_value(value: &JsonValue) -> &'static str {
+    return match value {
+        JsonValue::Object(_) => "object",
+        JsonValue::Array(_) => "array",
+        JsonValue::String(_) => "string",
+        JsonValue::Number(_) => "number",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Null => "null",
+    };
+}
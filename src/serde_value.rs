@@ -0,0 +1,210 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Error, Serialize, Serializer};
+use serde_json::{Map, Number, Value};
+
+use super::convert::ConversionError;
+use super::error::{member_segment, ErrorKind, Fault, ValidationError};
+use super::spanned::{parse_with_spans, SpannedKind, SpannedValue};
+use super::utils::UTF8Reader;
+use super::value::{decimal_lexeme, round_trips_f64, JsonNumber, JsonValue};
+
+/// Numbers go through serde_json's own number parser, so integers keep
+/// their exact `u64`/`i64` value. A JSON5 spelling such as `0xFF`, `+1` or
+/// `.5` is read as its decimal value. A number serde_json can not hold
+/// exactly, such as `18446744073709551616`, `1e400` or `NaN`, is an error
+/// rather than a rounded float or `Null`.
+impl TryFrom<JsonValue> for Value {
+    type Error = ConversionError;
+
+    fn try_from(value: JsonValue) -> Result<Self, Self::Error> {
+        return match value {
+            JsonValue::Null => Ok(Value::Null),
+            JsonValue::Bool(b) => Ok(Value::Bool(b)),
+            JsonValue::Number(ref n) => match to_serde_number(n) {
+                Some(number) => Ok(Value::Number(number)),
+                None => Err(ConversionError::new(EXACT_NUMBER, &value)),
+            },
+            JsonValue::String(s) => Ok(Value::String(s)),
+            JsonValue::Array(elements) => elements
+                .into_iter()
+                .enumerate()
+                .map(|(position, element)| {
+                    Value::try_from(element)
+                        .map_err(|error| error.in_segment(format!("[{}]", position)))
+                })
+                .collect::<Result<Vec<Value>, ConversionError>>()
+                .map(Value::Array),
+            JsonValue::Object(members) => members
+                .into_iter()
+                .map(|(key, member)| match Value::try_from(member) {
+                    Ok(converted) => Ok((key, converted)),
+                    Err(error) => Err(error.in_segment(member_segment(&key))),
+                })
+                .collect::<Result<Map<String, Value>, ConversionError>>()
+                .map(Value::Object),
+        };
+    }
+}
+
+/// Numbers are written as `serde_json::Value` would write them, so integers
+/// stay integers and `1.0` stays a float; see `TryFrom<JsonValue> for Value`.
+/// Members are written in order, duplicates included.
+impl Serialize for JsonValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return match self {
+            JsonValue::Null => serializer.serialize_unit(),
+            JsonValue::Bool(b) => serializer.serialize_bool(*b),
+            JsonValue::Number(n) => match to_serde_number(n) {
+                Some(number) => number.serialize(serializer),
+                None => Err(S::Error::custom(inexact_number(n))),
+            },
+            JsonValue::String(s) => serializer.serialize_str(s),
+            JsonValue::Array(elements) => serializer.collect_seq(elements),
+            JsonValue::Object(members) => {
+                serializer.collect_map(members.iter().map(|(key, value)| (key, value)))
+            }
+        };
+    }
+}
+
+/// Accepts any self-describing input, such as a field of a struct read by
+/// serde_json. Floats keep a decimal point, so `1.0` is not read back as the
+/// integer `1`; non-finite floats become the JSON5 `NaN`, `Infinity` and
+/// `-Infinity`.
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        return deserializer.deserialize_any(JsonValueVisitor);
+    }
+}
+
+struct JsonValueVisitor;
+
+impl<'de> Visitor<'de> for JsonValueVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return f.write_str("any JSON value");
+    }
+
+    fn visit_unit<E>(self) -> Result<JsonValue, E> {
+        return Ok(JsonValue::Null);
+    }
+
+    fn visit_none<E>(self) -> Result<JsonValue, E> {
+        return Ok(JsonValue::Null);
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<JsonValue, D::Error> {
+        return JsonValue::deserialize(deserializer);
+    }
+
+    fn visit_bool<E>(self, b: bool) -> Result<JsonValue, E> {
+        return Ok(JsonValue::Bool(b));
+    }
+
+    fn visit_i64<E>(self, n: i64) -> Result<JsonValue, E> {
+        return Ok(JsonValue::Number(JsonNumber::new(&n.to_string())));
+    }
+
+    fn visit_u64<E>(self, n: u64) -> Result<JsonValue, E> {
+        return Ok(JsonValue::Number(JsonNumber::new(&n.to_string())));
+    }
+
+    fn visit_f64<E>(self, n: f64) -> Result<JsonValue, E> {
+        let lexeme = match Number::from_f64(n) {
+            Some(number) => number.to_string(),
+            None if n.is_nan() => String::from("NaN"),
+            None if n < 0.0 => String::from("-Infinity"),
+            None => String::from("Infinity"),
+        };
+        return Ok(JsonValue::Number(JsonNumber::new(&lexeme)));
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<JsonValue, E> {
+        return Ok(JsonValue::String(String::from(s)));
+    }
+
+    fn visit_string<E>(self, s: String) -> Result<JsonValue, E> {
+        return Ok(JsonValue::String(s));
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonValue, A::Error> {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        return Ok(JsonValue::Array(elements));
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonValue, A::Error> {
+        let mut members = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(member) = map.next_entry()? {
+            members.push(member);
+        }
+        return Ok(JsonValue::Object(members));
+    }
+}
+
+/// Converts like `TryFrom<JsonValue> for Value`, but reports a number
+/// serde_json can not hold exactly as a `PrecisionLoss` error at the number.
+pub fn to_serde_value(document: &UTF8Reader) -> Result<Value, ValidationError> {
+    let value = parse_with_spans(document)?;
+    return located_serde_value(value)
+        .map_err(|(fault, index)| ValidationError::from_fault(document, index, 0, fault));
+}
+
+fn located_serde_value(value: SpannedValue) -> Result<Value, (Fault, usize)> {
+    return match value.kind {
+        SpannedKind::Null => Ok(Value::Null),
+        SpannedKind::Bool(b) => Ok(Value::Bool(b)),
+        SpannedKind::Number(n) => match to_serde_number(&n) {
+            Some(number) => Ok(Value::Number(number)),
+            None => Err((
+                Fault::new(ErrorKind::PrecisionLoss, inexact_number(&n)),
+                value.span.0,
+            )),
+        },
+        SpannedKind::String(s) => Ok(Value::String(s)),
+        SpannedKind::Array(elements) => elements
+            .into_iter()
+            .enumerate()
+            .map(|(position, element)| {
+                located_serde_value(element)
+                    .map_err(|(fault, index)| (fault.in_element(position), index))
+            })
+            .collect::<Result<Vec<Value>, (Fault, usize)>>()
+            .map(Value::Array),
+        SpannedKind::Object(members) => members
+            .into_iter()
+            .map(|member| match located_serde_value(member.value) {
+                Ok(converted) => Ok((member.key, converted)),
+                Err((fault, index)) => Err((fault.in_member(&member.key), index)),
+            })
+            .collect::<Result<Map<String, Value>, (Fault, usize)>>()
+            .map(Value::Object),
+    };
+}
+
+/// What a conversion to serde_json wanted instead of an inexact number.
+const EXACT_NUMBER: &str = "i64, u64 or f64";
+
+fn inexact_number(number: &JsonNumber) -> String {
+    return format!(
+        "Number {} has no exact i64, u64 or f64 value",
+        number.as_str()
+    );
+}
+
+/// `None` for numbers serde_json can not hold exactly. A float is exact
+/// when it reads back as the digits that were written.
+fn to_serde_number(number: &JsonNumber) -> Option<Number> {
+    let decimal = decimal_lexeme(number.as_str())?;
+    let converted: Number = decimal.parse().ok()?;
+    if converted.is_f64() && !round_trips_f64(&decimal) {
+        return None;
+    }
+    return Some(converted);
+}
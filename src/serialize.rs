@@ -0,0 +1,405 @@
+use alloc::{format, string::String};
+use core::fmt::{self, Write};
+#[cfg(feature = "std")]
+use std::{error::Error, io};
+
+use super::value::{decimal_lexeme, JsonNumber, JsonValue};
+
+/// How lines end in pretty printed output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    Lf,
+    /// `\r\n`, for repositories that check JSON files out with CRLF.
+    CrLf,
+}
+
+impl Default for LineEnding {
+    fn default() -> Self {
+        return LineEnding::Lf;
+    }
+}
+
+impl LineEnding {
+    pub fn as_str(&self) -> &'static str {
+        return match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SerializeOptions {
+    /// Writes numbers the way `format_number_canonical` does, so `1.10`
+    /// becomes `1.1` and `1e2` becomes `100`. By default numbers are written
+    /// as they were parsed, except that JSON5 spellings such as `0xFF`, `+1`
+    /// or `.5` are rewritten in decimal.
+    pub canonical_numbers: bool,
+    /// Puts every array element and object member on its own line, indented
+    /// by this many spaces per level. By default the output is compact.
+    pub indent: Option<usize>,
+    /// Ends the lines of indented output, and the trailing newline.
+    pub line_ending: LineEnding,
+    /// Ends the output with a line ending, as most editors and POSIX tools
+    /// expect of a text file.
+    pub trailing_newline: bool,
+}
+
+/// Layout of pretty printed output, for `to_writer_pretty`.
+#[derive(Debug, Clone, Copy)]
+pub struct PrettyOptions {
+    /// Spaces per level of nesting.
+    pub indent: usize,
+    pub line_ending: LineEnding,
+    /// Ends the output with a line ending.
+    pub trailing_newline: bool,
+}
+
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        return PrettyOptions {
+            indent: 2,
+            line_ending: LineEnding::default(),
+            trailing_newline: false,
+        };
+    }
+}
+
+impl From<PrettyOptions> for SerializeOptions {
+    fn from(options: PrettyOptions) -> Self {
+        return SerializeOptions {
+            indent: Some(options.indent),
+            line_ending: options.line_ending,
+            trailing_newline: options.trailing_newline,
+            ..SerializeOptions::default()
+        };
+    }
+}
+
+/// A number that JSON has no spelling for, such as the JSON5 `NaN`, or one
+/// too large for an `f64` when writing canonical numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializeError {
+    lexeme: String,
+}
+
+impl SerializeError {
+    fn new(number: &JsonNumber) -> Self {
+        return SerializeError {
+            lexeme: String::from(number.as_str()),
+        };
+    }
+
+    /// The number as it was parsed.
+    pub fn lexeme(&self) -> &str {
+        return &self.lexeme;
+    }
+}
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is not a finite number", self.lexeme)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for SerializeError {}
+
+/// Why writing stopped: the output failed, or a number can not be written.
+enum WriteError {
+    Output,
+    Number(SerializeError),
+}
+
+impl From<fmt::Error> for WriteError {
+    fn from(_: fmt::Error) -> Self {
+        return WriteError::Output;
+    }
+}
+
+/// Writes `value` as compact RFC 8259 JSON, keeping every number's original
+/// text where JSON allows it. Fails on `Infinity` and `NaN`.
+pub fn to_string(value: &JsonValue) -> Result<String, SerializeError> {
+    return to_string_with_options(value, &SerializeOptions::default());
+}
+
+pub fn to_string_with_options(
+    value: &JsonValue,
+    options: &SerializeOptions,
+) -> Result<String, SerializeError> {
+    let mut output = String::new();
+    return match write_document(&mut output, value, options) {
+        Ok(_) => Ok(output),
+        Err(WriteError::Number(error)) => Err(error),
+        // Writing into a `String` can not fail.
+        Err(WriteError::Output) => Ok(output),
+    };
+}
+
+/// Writes `value` as compact JSON straight to `out`, without building the
+/// whole document in memory first.
+#[cfg(feature = "std")]
+pub fn write_json<W: io::Write>(value: &JsonValue, out: &mut W) -> io::Result<()> {
+    return write_json_with_options(value, out, &SerializeOptions::default());
+}
+
+/// Like `write_json`, but indents nested values by two spaces.
+#[cfg(feature = "std")]
+pub fn write_json_pretty<W: io::Write>(value: &JsonValue, out: &mut W) -> io::Result<()> {
+    let options = SerializeOptions {
+        indent: Some(2),
+        ..SerializeOptions::default()
+    };
+    return write_json_with_options(value, out, &options);
+}
+
+/// Like `write_json_pretty`, with the indent, line ending and trailing
+/// newline taken from `options`.
+#[cfg(feature = "std")]
+pub fn to_writer_pretty<W: io::Write>(
+    value: &JsonValue,
+    out: &mut W,
+    options: &PrettyOptions,
+) -> io::Result<()> {
+    return write_json_with_options(value, out, &SerializeOptions::from(*options));
+}
+
+#[cfg(feature = "std")]
+pub fn write_json_with_options<W: io::Write>(
+    value: &JsonValue,
+    out: &mut W,
+    options: &SerializeOptions,
+) -> io::Result<()> {
+    let mut adapter = IoAdapter {
+        inner: out,
+        error: None,
+    };
+
+    return match write_document(&mut adapter, value, options) {
+        Ok(_) => Ok(()),
+        Err(WriteError::Number(error)) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+        Err(WriteError::Output) => Err(adapter
+            .error
+            .unwrap_or_else(|| io::Error::other("formatter error"))),
+    };
+}
+
+/// Lets the serializer, which writes to a `fmt::Write`, target an
+/// `io::Write`, keeping the underlying error that `fmt::Error` can not carry.
+#[cfg(feature = "std")]
+struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, W: io::Write> Write for IoAdapter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        return self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            return fmt::Error;
+        });
+    }
+}
+
+fn write_document<W: Write>(
+    output: &mut W,
+    value: &JsonValue,
+    options: &SerializeOptions,
+) -> Result<(), WriteError> {
+    write_value(output, value, options, 0)?;
+    if options.trailing_newline {
+        output.write_str(options.line_ending.as_str())?;
+    }
+    return Ok(());
+}
+
+fn write_value<W: Write>(
+    output: &mut W,
+    value: &JsonValue,
+    options: &SerializeOptions,
+    depth: usize,
+) -> Result<(), WriteError> {
+    match value {
+        JsonValue::Null => output.write_str("null")?,
+        JsonValue::Bool(true) => output.write_str("true")?,
+        JsonValue::Bool(false) => output.write_str("false")?,
+        JsonValue::Number(number) if options.canonical_numbers => {
+            let canonical = format_number_canonical(number).map_err(WriteError::Number)?;
+            output.write_str(&canonical)?;
+        }
+        JsonValue::Number(number) => match decimal_lexeme(number.as_str()) {
+            Some(decimal) => output.write_str(&decimal)?,
+            None => return Err(WriteError::Number(SerializeError::new(number))),
+        },
+        JsonValue::String(s) => write_string(output, s)?,
+        JsonValue::Array(elements) => {
+            output.write_char('[')?;
+            for (position, element) in elements.iter().enumerate() {
+                if position > 0 {
+                    output.write_char(',')?;
+                }
+                write_indent(output, options, depth + 1)?;
+                write_value(output, element, options, depth + 1)?;
+            }
+            if !elements.is_empty() {
+                write_indent(output, options, depth)?;
+            }
+            output.write_char(']')?;
+        }
+        JsonValue::Object(members) => {
+            output.write_char('{')?;
+            for (position, (key, member)) in members.iter().enumerate() {
+                if position > 0 {
+                    output.write_char(',')?;
+                }
+                write_indent(output, options, depth + 1)?;
+                write_string(output, key)?;
+                output.write_char(':')?;
+                if options.indent.is_some() {
+                    output.write_char(' ')?;
+                }
+                write_value(output, member, options, depth + 1)?;
+            }
+            if !members.is_empty() {
+                write_indent(output, options, depth)?;
+            }
+            output.write_char('}')?;
+        }
+    }
+
+    return Ok(());
+}
+
+/// Starts a new line at `depth` when pretty printing; does nothing otherwise.
+fn write_indent<W: Write>(output: &mut W, options: &SerializeOptions, depth: usize) -> fmt::Result {
+    if let Some(width) = options.indent {
+        output.write_str(options.line_ending.as_str())?;
+        for _ in 0..width * depth {
+            output.write_char(' ')?;
+        }
+    }
+    return Ok(());
+}
+
+/// Quotes `s`, escaping what RFC 8259 requires and nothing more.
+pub(crate) fn write_string<W: Write>(output: &mut W, s: &str) -> fmt::Result {
+    output.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => output.write_str("\\\"")?,
+            '\\' => output.write_str("\\\\")?,
+            '\u{0008}' => output.write_str("\\b")?,
+            '\u{000C}' => output.write_str("\\f")?,
+            '\n' => output.write_str("\\n")?,
+            '\r' => output.write_str("\\r")?,
+            '\t' => output.write_str("\\t")?,
+            '\u{0000}'..='\u{001F}' => write!(output, "\\u{:04x}", c as u32)?,
+            _ => output.write_char(c)?,
+        }
+    }
+    return output.write_char('"');
+}
+
+/// Formats `number` the way ECMAScript's `Number.prototype.toString` does,
+/// which is what JSON Canonicalization (RFC 8785) requires: the shortest
+/// digits that read back as the same `f64`, positional notation for
+/// magnitudes from `1e-6` up to `1e21`, and an exponent such as `1e+21`
+/// outside that range. Integers beyond 2^53 lose precision, as in JavaScript.
+///
+/// Fails on `Infinity`, `NaN` and numbers too large for an `f64`, which
+/// RFC 8785 can not represent.
+pub fn format_number_canonical(number: &JsonNumber) -> Result<String, SerializeError> {
+    let value = number.as_f64();
+    if !value.is_finite() {
+        return Err(SerializeError::new(number));
+    }
+    if value == 0.0 {
+        return Ok(String::from("0"));
+    }
+
+    // `{:e}` gives the shortest round-tripping digits, e.g. `1.2345e-7`.
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    // The decimal point sits after the first `n` digits.
+    let n = exponent.parse::<i32>().unwrap_or(0) + 1;
+    let digits = closest_digits(value.abs(), digits, n);
+
+    let mut output = String::new();
+    if value < 0.0 {
+        output.push('-');
+    }
+
+    if k <= n && n <= 21 {
+        output.push_str(&digits);
+        output.push_str(&"0".repeat((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        output.push_str(&digits[..n as usize]);
+        output.push('.');
+        output.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        output.push_str("0.");
+        output.push_str(&"0".repeat(-n as usize));
+        output.push_str(&digits);
+    } else {
+        output.push_str(&digits[..1]);
+        if k > 1 {
+            output.push('.');
+            output.push_str(&digits[1..]);
+        }
+        let sign = if n > 0 { '+' } else { '-' };
+        let _ = write!(output, "e{}{}", sign, (n - 1).abs());
+    }
+
+    return Ok(output);
+}
+
+/// Of the shortest digit strings that read back as `value`, picks the one
+/// closest to its exact decimal expansion, and the even one on a tie, as
+/// ECMAScript requires. Rust's shortest form may round the last digit the
+/// other way, e.g. `1424953923781206.3` instead of `1424953923781206.2`.
+fn closest_digits(value: f64, digits: String, n: i32) -> String {
+    let k = digits.len() as i32;
+    let shortest: u64 = match digits.parse() {
+        Ok(shortest) => shortest,
+        Err(_) => return digits,
+    };
+    let reads_back = |candidate: u64| {
+        let lexeme = format!("{}e{}", candidate, n - k);
+        return lexeme.parse::<f64>() == Ok(value)
+            && format!("{}", candidate).len() == digits.len();
+    };
+    if !reads_back(shortest.wrapping_sub(1)) && !reads_back(shortest + 1) {
+        return digits;
+    }
+
+    // Every `f64` has an exact decimal expansion of at most 767 digits.
+    let exact = format!("{:.800e}", value);
+    let (mantissa, exponent) = exact.split_once('e').unwrap_or((&exact, "0"));
+    if exponent.parse::<i32>().unwrap_or(0) + 1 != n {
+        return digits;
+    }
+    let exact_digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let (head, rest) = exact_digits.split_at(digits.len());
+    let floor: u64 = match head.parse() {
+        Ok(floor) => floor,
+        Err(_) => return digits,
+    };
+
+    let rest = rest.trim_end_matches('0');
+    let closest = match rest.as_bytes().first() {
+        Some(digit) if *digit > b'5' => floor + 1,
+        Some(b'5') if rest.len() > 1 => floor + 1,
+        // Exactly halfway between two candidates.
+        Some(b'5') if floor % 2 == 1 => floor + 1,
+        _ => floor,
+    };
+
+    if closest != shortest && reads_back(closest) {
+        return format!("{}", closest);
+    }
+    return digits;
+}
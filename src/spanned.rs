@@ -0,0 +1,140 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use super::cursor::{Cursor, Token, TokenKind};
+use super::decode::decode_validated_string;
+use super::error::{Fault, ValidationError};
+use super::utils::UTF8Reader;
+use super::validator::{validate_with_options, ValidateOptions};
+use super::value::{JsonNumber, JsonValue};
+
+/// A parsed value with the start and end indices of its source text,
+/// excluding surrounding whitespace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedValue {
+    pub span: (usize, usize),
+    pub kind: SpannedKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpannedKind {
+    Null,
+    Bool(bool),
+    Number(JsonNumber),
+    String(String),
+    Array(Vec<SpannedValue>),
+    Object(Vec<SpannedMember>),
+}
+
+/// An object member, with the span of its key as written, quotes included.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedMember {
+    pub key: String,
+    pub key_span: (usize, usize),
+    pub value: SpannedValue,
+}
+
+impl From<SpannedValue> for JsonValue {
+    fn from(value: SpannedValue) -> Self {
+        return match value.kind {
+            SpannedKind::Null => JsonValue::Null,
+            SpannedKind::Bool(b) => JsonValue::Bool(b),
+            SpannedKind::Number(n) => JsonValue::Number(n),
+            SpannedKind::String(s) => JsonValue::String(s),
+            SpannedKind::Array(elements) => {
+                JsonValue::Array(elements.into_iter().map(JsonValue::from).collect())
+            }
+            SpannedKind::Object(members) => JsonValue::Object(
+                members
+                    .into_iter()
+                    .map(|member| (member.key, JsonValue::from(member.value)))
+                    .collect(),
+            ),
+        };
+    }
+}
+
+/// Like `parse`, but every value and object key records where it was found.
+pub fn parse_with_spans(document: &UTF8Reader) -> Result<SpannedValue, ValidationError> {
+    return parse_with_spans_with_options(document, &ValidateOptions::default());
+}
+
+pub fn parse_with_spans_with_options(
+    document: &UTF8Reader,
+    options: &ValidateOptions,
+) -> Result<SpannedValue, ValidationError> {
+    validate_with_options(document, options)?;
+
+    let mut cursor = Cursor::new(document, options);
+    let origin = cursor.position();
+    let token = cursor.next_token();
+    return build_value(&mut cursor, token).map_err(|(fault, index)| {
+        return ValidationError::from_fault(document, index, origin, fault);
+    });
+}
+
+/// Builds the value that starts with `token`, leaving `cursor` past it. The
+/// document must already be known to be valid; only decoding an escaped
+/// string can fail.
+fn build_value(cursor: &mut Cursor, token: Token) -> Result<SpannedValue, (Fault, usize)> {
+    let kind = match token.kind {
+        TokenKind::BeginObject => {
+            let mut members = vec![];
+            loop {
+                let key = cursor.next_token();
+                match key.kind {
+                    TokenKind::Comma => {}
+                    TokenKind::Key => {
+                        // Past the ":"
+                        cursor.next_token();
+                        let value = cursor.next_token();
+                        members.push(SpannedMember {
+                            key: build_string(cursor, key)?,
+                            key_span: (key.start, key.end),
+                            value: build_value(cursor, value)?,
+                        });
+                    }
+                    _ => break,
+                }
+            }
+            SpannedKind::Object(members)
+        }
+        TokenKind::BeginArray => {
+            let mut elements = vec![];
+            loop {
+                let element = cursor.next_token();
+                match element.kind {
+                    TokenKind::Comma => {}
+                    TokenKind::EndArray | TokenKind::End => break,
+                    _ => elements.push(build_value(cursor, element)?),
+                }
+            }
+            SpannedKind::Array(elements)
+        }
+        _ => match token.lexeme {
+            "true" => SpannedKind::Bool(true),
+            "false" => SpannedKind::Bool(false),
+            "null" => SpannedKind::Null,
+            lexeme if lexeme.starts_with('"') || lexeme.starts_with('\'') => {
+                SpannedKind::String(build_string(cursor, token)?)
+            }
+            lexeme => SpannedKind::Number(JsonNumber::new(lexeme)),
+        },
+    };
+
+    return Ok(SpannedValue {
+        span: (token.start, cursor.position()),
+        kind,
+    });
+}
+
+/// The content of a string with its escapes decoded, or an identifier key
+/// as written.
+fn build_string(cursor: &Cursor, token: Token) -> Result<String, (Fault, usize)> {
+    if !token.lexeme.starts_with('"') && !token.lexeme.starts_with('\'') {
+        return Ok(String::from(token.lexeme));
+    }
+
+    let width = token.end - token.start;
+    return decode_validated_string(cursor.document(), token.start, width, cursor.options())
+        .map_err(|(fault, offset)| (fault, token.start + offset));
+}
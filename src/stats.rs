@@ -0,0 +1,114 @@
+use alloc::{format, string::String, vec::Vec};
+
+use super::error::ValidationError;
+use super::events::{parse_events, JsonEvent};
+use super::pointer::push_token;
+use super::utils::UTF8Reader;
+use super::value::JsonValue;
+
+/// Shape of a document, gathered while it is validated.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentStats {
+    /// Deepest nesting of objects and arrays; 0 for a lone scalar.
+    pub max_depth: usize,
+    pub objects: usize,
+    pub arrays: usize,
+    /// String values, not counting object keys.
+    pub strings: usize,
+    pub numbers: usize,
+    pub bools: usize,
+    pub nulls: usize,
+    pub total_bytes: usize,
+    /// Length of the longest string value in decoded characters.
+    pub longest_string: usize,
+    /// How many values sit at each depth: the top-level value alone at 0,
+    /// its elements or member values at 1, and so on.
+    pub nodes_per_depth: Vec<usize>,
+    /// A JSON Pointer to the first of the most deeply nested values, such
+    /// as `/users/3/tags/0`; empty for a lone scalar.
+    pub deepest_path: String,
+}
+
+/// Validates the document and counts what it contains, in a single pass and
+/// without building a tree.
+pub fn analyze(document: &UTF8Reader) -> Result<DocumentStats, ValidationError> {
+    let mut stats = DocumentStats {
+        total_bytes: document.byte_offset_of(document.len()),
+        ..DocumentStats::default()
+    };
+    // The open containers, each with the key or position of the value
+    // currently being read in it.
+    let mut path: Vec<Step> = Vec::new();
+
+    parse_events(document, |event| match event {
+        JsonEvent::BeginObject | JsonEvent::BeginArray => {
+            count_node(&mut stats, &mut path);
+            match event {
+                JsonEvent::BeginObject => {
+                    stats.objects += 1;
+                    path.push(Step::Key(String::new()));
+                }
+                _ => {
+                    stats.arrays += 1;
+                    path.push(Step::Element(None));
+                }
+            }
+            stats.max_depth = stats.max_depth.max(path.len());
+        }
+        JsonEvent::EndObject | JsonEvent::EndArray => {
+            path.pop();
+        }
+        JsonEvent::Key(key) => {
+            if let Some(step) = path.last_mut() {
+                *step = Step::Key(key);
+            }
+        }
+        JsonEvent::Value(value) => {
+            count_node(&mut stats, &mut path);
+            match value {
+                JsonValue::String(text) => {
+                    stats.strings += 1;
+                    stats.longest_string = stats.longest_string.max(text.chars().count());
+                }
+                JsonValue::Number(_) => stats.numbers += 1,
+                JsonValue::Bool(_) => stats.bools += 1,
+                JsonValue::Null => stats.nulls += 1,
+                JsonValue::Array(_) | JsonValue::Object(_) => {}
+            }
+        }
+    })?;
+
+    return Ok(stats);
+}
+
+enum Step {
+    Key(String),
+    /// The position of the current element; `None` before the first.
+    Element(Option<usize>),
+}
+
+/// Counts a value starting under `path`, noting the path when the value is
+/// deeper than any before it.
+fn count_node(stats: &mut DocumentStats, path: &mut [Step]) {
+    if let Some(Step::Element(position)) = path.last_mut() {
+        *position = Some(position.map_or(0, |p| p + 1));
+    }
+
+    let depth = path.len();
+    if depth < stats.nodes_per_depth.len() {
+        stats.nodes_per_depth[depth] += 1;
+        return;
+    }
+
+    stats.nodes_per_depth.push(1);
+    stats.deepest_path.clear();
+    for step in path.iter() {
+        match step {
+            Step::Key(key) => push_token(&mut stats.deepest_path, key),
+            Step::Element(position) => {
+                let token = format!("{}", position.unwrap_or_default());
+                push_token(&mut stats.deepest_path, &token);
+            }
+        }
+    }
+}
@@ -2,8 +2,8 @@ use colored::*;
 use std::fs;
 use std::path::PathBuf;
 
-use super::utils::{read_file_as_utf8, UTF8Reader};
-use super::validator::validate;
+use rust_json::utils::{read_file_as_utf8, UTF8Reader};
+use rust_json::validator::validate;
 
 pub fn run_suite() {
     let entries: Vec<PathBuf> = fs::read_dir("JSONTestSuite/test_parsing")
@@ -13,15 +13,14 @@ pub fn run_suite() {
         .collect();
 
     let total = entries.len();
-    let mut index = 1;
 
-    for entry in entries {
+    for (index, entry) in entries.iter().enumerate() {
         let filename = entry.file_name().unwrap();
-        println!("({}/{}) {:?}", index, total, filename);
+        println!("({}/{}) {:?}", index + 1, total, filename);
 
-        match read_file_as_utf8(&entry) {
+        match read_file_as_utf8(entry) {
             Err(reason) => {
-                println!("{:?}", reason);
+                println!("{}", reason);
                 println!("------------------------");
             }
             Ok(document) => {
@@ -42,13 +41,11 @@ pub fn run_suite() {
                     "{}\n\n{}\n------------------------",
                     document.bright_yellow(),
                     match result {
-                        Err(reason) => reason,
+                        Err(reason) => reason.to_string(),
                         _ => String::default(),
                     }
                 );
             }
         }
-
-        index += 1;
     }
 }
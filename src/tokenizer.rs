@@ -0,0 +1,174 @@
+use super::error::ValidationError;
+use super::utils::{UTF8Reader, UTF8ReaderResult};
+use super::validator::{scan_value, skip_whitespace, validate_key, BomPolicy, ValidateOptions};
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    BeginObject,
+    EndObject,
+    BeginArray,
+    EndArray,
+    Colon,
+    Comma,
+    String,
+    Number,
+    True,
+    False,
+    Null,
+    /// An unquoted object key, only lexed under `allow_unquoted_keys`.
+    Identifier,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Splits a document into tokens, skipping insignificant whitespace.
+///
+/// Only the lexical grammar is checked here; whether the tokens form a valid
+/// document is left to the validator. Iteration stops after the first error.
+pub struct Tokenizer<'a> {
+    document: &'a UTF8Reader<'a>,
+    options: ValidateOptions,
+    ptr: usize,
+    /// Index of the first scalar after a skipped byte order mark.
+    origin: usize,
+    /// Whether each open container is an object, innermost last.
+    objects: Vec<bool>,
+    expects_key: bool,
+    done: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(document: &'a UTF8Reader<'a>) -> Self {
+        return Tokenizer::with_options(document, ValidateOptions::default());
+    }
+
+    /// Like `new`, but lexes what `options` relax, such as single quoted
+    /// strings, JSON5 numbers, unquoted keys and Unicode whitespace, and
+    /// skips a byte order mark the options let through.
+    pub fn with_options(document: &'a UTF8Reader<'a>, options: ValidateOptions) -> Self {
+        let origin = match document.look_ahead(0, 1) {
+            UTF8ReaderResult::Ok("\u{FEFF}") if options.bom_policy != BomPolicy::Forbid => 1,
+            _ => 0,
+        };
+
+        return Tokenizer {
+            document,
+            options,
+            ptr: origin,
+            origin,
+            objects: Vec::new(),
+            expects_key: false,
+            done: false,
+        };
+    }
+
+    /// The index just past the last token; before the first, past the
+    /// byte order mark. Once the tokens run out, the end of the document.
+    pub fn position(&self) -> usize {
+        return self.ptr;
+    }
+
+    /// Whether the next token stands where an object key belongs.
+    pub(crate) fn expects_key(&self) -> bool {
+        return self.expects_key;
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Token, ValidationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let start = skip_whitespace(self.document, self.ptr, &self.options);
+        self.ptr = start;
+        let chr = match self.document.look_ahead(start, 1) {
+            UTF8ReaderResult::Ok(chr) => chr,
+            UTF8ReaderResult::OutOfBoundError(_) => {
+                self.done = true;
+                return None;
+            }
+        };
+
+        let (kind, (result, step)) = match chr {
+            "{" => {
+                self.objects.push(true);
+                self.expects_key = true;
+                (TokenKind::BeginObject, (Ok(()), 1))
+            }
+            "[" => {
+                self.objects.push(false);
+                (TokenKind::BeginArray, (Ok(()), 1))
+            }
+            "}" => {
+                // Also ends `{}`, where a key was expected.
+                self.objects.pop();
+                self.expects_key = false;
+                (TokenKind::EndObject, (Ok(()), 1))
+            }
+            "]" => {
+                self.objects.pop();
+                (TokenKind::EndArray, (Ok(()), 1))
+            }
+            ":" => (TokenKind::Colon, (Ok(()), 1)),
+            "," => {
+                self.expects_key = self.objects.last() == Some(&true);
+                (TokenKind::Comma, (Ok(()), 1))
+            }
+            "\"" | "'" => {
+                self.expects_key = false;
+                (
+                    TokenKind::String,
+                    scan_value(self.document, start, 0, &self.options),
+                )
+            }
+            // Identifier keys are not values, so they are lexed on their own.
+            _ if self.expects_key && self.options.allow_unquoted_keys => {
+                self.expects_key = false;
+                (
+                    TokenKind::Identifier,
+                    validate_key(self.document, start, &self.options),
+                )
+            }
+            _ => {
+                self.expects_key = false;
+                let kind = match chr {
+                    "t" => TokenKind::True,
+                    "f" => TokenKind::False,
+                    "n" => TokenKind::Null,
+                    _ => TokenKind::Number,
+                };
+                (kind, scan_value(self.document, start, 0, &self.options))
+            }
+        };
+
+        return match result {
+            Ok(_) => {
+                self.ptr += step;
+                Some(Ok(Token {
+                    kind,
+                    start,
+                    length: step,
+                }))
+            }
+            Err(fault) => {
+                self.done = true;
+                let index = start + step;
+                Some(Err(ValidationError::from_fault(
+                    self.document,
+                    index,
+                    self.origin,
+                    fault,
+                )))
+            }
+        };
+    }
+}
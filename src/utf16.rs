@@ -0,0 +1,74 @@
+use alloc::{format, string::String};
+use core::char;
+
+use super::error::ValidationError;
+use super::validator::{encoding_error, validate_str, ValidateResult};
+
+/// Byte order of UTF-16 input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+/// Decodes UTF-16 input, as some Windows and Java tools emit, and validates
+/// the result. A leading byte order mark is skipped.
+///
+/// Input that is not well-formed UTF-16 fails with `ErrorKind::Encoding`.
+/// Every error's `byte_offset` counts bytes of the UTF-16 input.
+pub fn validate_utf16(input: &[u8], endianness: Endianness) -> ValidateResult {
+    let units = input.chunks_exact(2).map(|pair| match endianness {
+        Endianness::Little => u16::from_le_bytes([pair[0], pair[1]]),
+        Endianness::Big => u16::from_be_bytes([pair[0], pair[1]]),
+    });
+
+    let mut decoded = String::with_capacity(input.len() / 2);
+    let mut origin = 0;
+    let mut offset = 0;
+
+    for result in char::decode_utf16(units) {
+        match result {
+            Ok('\u{FEFF}') if offset == 0 => origin = 2,
+            Ok('\u{FFFE}') if offset == 0 => {
+                let (expected, actual) = match endianness {
+                    Endianness::Little => ("little", "big"),
+                    Endianness::Big => ("big", "little"),
+                };
+                let reason = format!(
+                    "Byte order mark shows the input is {}-endian, not {}-endian",
+                    actual, expected
+                );
+                return Err(encoding_error(&decoded, offset, reason));
+            }
+            Ok(c) => decoded.push(c),
+            Err(why) => {
+                let reason = format!(
+                    "Unpaired surrogate 0x{:04X} in UTF-16 input",
+                    why.unpaired_surrogate()
+                );
+                return Err(encoding_error(&decoded, offset, reason));
+            }
+        }
+        offset += match result {
+            Ok(c) => c.len_utf16() * 2,
+            Err(_) => 2,
+        };
+    }
+
+    if !input.chunks_exact(2).remainder().is_empty() {
+        let reason = String::from("UTF-16 input ends with a lone byte");
+        return Err(encoding_error(&decoded, offset, reason));
+    }
+
+    return validate_str(&decoded).map_err(|error| relocate(&decoded, origin, error));
+}
+
+/// Points an error found in the decoded text back at the UTF-16 input.
+fn relocate(decoded: &str, origin: usize, error: ValidationError) -> ValidationError {
+    let width: usize = decoded
+        .chars()
+        .take(error.index())
+        .map(|c| c.len_utf16() * 2)
+        .sum();
+    return error.with_byte_offset(origin + width);
+}
@@ -1,29 +1,33 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::PathBuf;
+#[cfg(any(feature = "async", feature = "gzip"))]
+use alloc::format;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::cell::OnceCell as LazyLines;
+use core::str::{self, Utf8Error};
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::path::Path;
+// Keeps the reader `Sync` wherever threads exist.
+#[cfg(feature = "std")]
+use std::sync::OnceLock as LazyLines;
 use unicode_segmentation::UnicodeSegmentation;
 
-pub fn read_file_as_utf8(entry: &PathBuf) -> Result<String, ()> {
-    let path = entry.to_str().unwrap();
+#[cfg(feature = "std")]
+use super::error::JsonFileError;
+#[cfg(any(feature = "async", feature = "gzip"))]
+use super::error::ValidationError;
+#[cfg(any(feature = "async", feature = "gzip"))]
+use super::incremental::IncrementalValidator;
 
-    let mut file = match File::open(path) {
-        Err(why) => {
-            println!("couldn't open. {}", why);
-            return Err(());
-        }
-        Ok(file) => file,
+/// Reads a whole file, which must be valid UTF-8.
+#[cfg(feature = "std")]
+pub fn read_file_as_utf8(entry: &Path) -> Result<String, JsonFileError> {
+    let bytes = fs::read(entry)?;
+    return match String::from_utf8(bytes) {
+        Ok(content) => Ok(content),
+        Err(why) => Err(JsonFileError::Utf8(why.utf8_error())),
     };
-
-    let mut content = String::new();
-    match file.read_to_string(&mut content) {
-        Err(why) => {
-            println!("couldn't read. {}", why);
-            return Err(());
-        }
-        Ok(_) => {}
-    }
-
-    return Ok(content);
 }
 
 pub enum UTF8ReaderResult<'a> {
@@ -34,16 +38,20 @@ pub enum UTF8ReaderResult<'a> {
 pub struct UTF8Reader<'a> {
     document: &'a str,
     begin_index_map: Vec<usize>,
+    graphemes: bool,
+    /// Index of the first unit of every line but the first, built on the
+    /// first call to `line_col`.
+    line_starts: LazyLines<Vec<usize>>,
 }
 
 impl<'a> UTF8Reader<'a> {
-    pub fn look_ahead(&self, begin_index: usize, width: usize) -> UTF8ReaderResult {
+    pub fn look_ahead(&self, begin_index: usize, width: usize) -> UTF8ReaderResult<'a> {
         let l = self.len();
 
-        let end_index = begin_index + width;
-        if end_index > l {
-            return UTF8ReaderResult::OutOfBoundError(l - begin_index);
-        }
+        let end_index = match begin_index.checked_add(width) {
+            Some(end_index) if end_index <= l => end_index,
+            _ => return UTF8ReaderResult::OutOfBoundError(l.saturating_sub(begin_index)),
+        };
 
         let begin = self.begin_index_map[begin_index];
         let end = self.begin_index_map[end_index];
@@ -51,27 +59,211 @@ impl<'a> UTF8Reader<'a> {
         return UTF8ReaderResult::Ok(&self.document[begin..end]);
     }
 
+    /// Byte offset of the unit at `index`, or the document length past the end.
+    pub fn byte_offset_of(&self, index: usize) -> usize {
+        return self.begin_index_map[index.min(self.len())];
+    }
+
+    /// Index of the unit starting at `byte_offset`, the inverse of
+    /// `byte_offset_of`. The document length maps to `len()`. Returns `None`
+    /// for offsets past the end or inside a unit.
+    pub fn scalar_index_of_byte(&self, byte_offset: usize) -> Option<usize> {
+        return self.begin_index_map.binary_search(&byte_offset).ok();
+    }
+
+    /// True if the document is plain ASCII, so every unit is a single byte.
+    pub fn is_ascii(&self) -> bool {
+        return self.document.is_ascii();
+    }
+
+    /// How many scalars of the document are outside ASCII, whichever unit
+    /// the reader counts in.
+    pub fn non_ascii_count(&self) -> usize {
+        // Every non-ASCII scalar starts with exactly one byte of the form 11xxxxxx.
+        return self.document.bytes().filter(|b| *b >= 0xC0).count();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
     pub fn len(&self) -> usize {
         return self.begin_index_map.len() - 1;
     }
 
+    /// Builds a reader whose units are Unicode scalar values, as RFC 8259
+    /// counts them.
     pub fn new(document: &'a str) -> Self {
-        let graphemes = UnicodeSegmentation::graphemes(document, true).collect::<Vec<&str>>();
-
-        let mut sum = 0;
-        let mut begin_index_map = graphemes
-            .iter()
-            .map(|g| {
-                let s = sum;
-                sum += g.len();
-                return s;
-            })
-            .collect::<Vec<usize>>();
-        begin_index_map.push(sum);
-
-        return UTF8Reader {
+        let mut reader = UTF8Reader {
+            document,
+            begin_index_map: Vec::new(),
+            graphemes: false,
+            line_starts: LazyLines::new(),
+        };
+        reader.map_units();
+        return reader;
+    }
+
+    /// Builds a reader whose units are extended grapheme clusters, which is
+    /// how this crate counted positions before. It is kept for callers that
+    /// rely on those indices, but it is not spec compliant: a combining mark
+    /// after a quote, digit or bracket merges with it into one unit, so such
+    /// documents may be rejected or misread. A `\r\n` pair is two units, as
+    /// in `new`, so a pure ASCII document has the same `len()` and indices
+    /// either way.
+    pub fn new_grapheme(document: &'a str) -> Self {
+        let mut reader = UTF8Reader {
             document,
-            begin_index_map,
+            begin_index_map: Vec::new(),
+            graphemes: true,
+            line_starts: LazyLines::new(),
+        };
+        reader.map_units();
+        return reader;
+    }
+
+    /// Points the reader at another document, reusing the memory of its
+    /// offset map, so a pooled reader can serve many documents without
+    /// allocating for each. The kind of unit, scalar or grapheme, is kept.
+    ///
+    /// Indices and errors obtained before the reset refer to the previous
+    /// document and must not be used with this reader afterwards.
+    pub fn reset(&mut self, document: &'a str) {
+        self.document = document;
+        self.line_starts = LazyLines::new();
+        self.map_units();
+    }
+
+    /// The 1-based line and column of the unit at `index`, counting `\n`,
+    /// `\r` and `\r\n` as one line break each, the way editors do. Indices
+    /// past the end keep counting columns on the last line.
+    ///
+    /// The first call indexes the line breaks of the whole document, so
+    /// later lookups take logarithmic time.
+    pub fn line_col(&self, index: usize) -> (usize, usize) {
+        let line_starts = self.line_starts.get_or_init(|| self.find_line_starts());
+
+        // The `\n` of a `\r\n` pair already sits on the line the pair opens.
+        let index = match self.look_ahead(index, 1) {
+            UTF8ReaderResult::Ok("\n") if self.ends_with_carriage_return(index) => index + 1,
+            _ => index,
         };
+
+        let line = line_starts.partition_point(|start| *start <= index);
+        let start = if line == 0 { 0 } else { line_starts[line - 1] };
+        return (line + 1, (index - start).saturating_add(1));
+    }
+
+    fn find_line_starts(&self) -> Vec<usize> {
+        let bytes = self.document.as_bytes();
+        let mut line_starts = Vec::new();
+
+        for i in 0..self.len() {
+            match bytes[self.begin_index_map[i]] {
+                b'\r' => line_starts.push(i + 1),
+                b'\n' if self.ends_with_carriage_return(i) => {
+                    if let Some(start) = line_starts.last_mut() {
+                        *start = i + 1;
+                    }
+                }
+                b'\n' => line_starts.push(i + 1),
+                _ => {}
+            }
+        }
+
+        return line_starts;
+    }
+
+    /// True if the unit just before `index` is a lone `\r`.
+    fn ends_with_carriage_return(&self, index: usize) -> bool {
+        return index > 0 && matches!(self.look_ahead(index - 1, 1), UTF8ReaderResult::Ok("\r"));
+    }
+
+    /// A reader over another document that counts the same kind of unit.
+    pub(crate) fn with_same_units<'b>(&self, document: &'b str) -> UTF8Reader<'b> {
+        if self.graphemes {
+            return UTF8Reader::new_grapheme(document);
+        }
+        return UTF8Reader::new(document);
+    }
+
+    fn map_units(&mut self) {
+        let document = self.document;
+        self.begin_index_map.clear();
+
+        if self.graphemes {
+            for (i, grapheme) in UnicodeSegmentation::grapheme_indices(document, true) {
+                self.begin_index_map.push(i);
+                // Only a `\r\n` pair makes a cluster of two ASCII characters;
+                // splitting it keeps ASCII indices the same in both modes.
+                if grapheme == "\r\n" {
+                    self.begin_index_map.push(i + 1);
+                }
+            }
+        } else {
+            let offsets = document.char_indices().map(|(i, _)| i);
+            self.begin_index_map.extend(offsets);
+        }
+
+        self.begin_index_map.push(document.len());
+    }
+
+    /// Builds a reader over a byte buffer, which must be valid UTF-8.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, Utf8Error> {
+        let document = core::str::from_utf8(bytes)?;
+        return Ok(UTF8Reader::new(document));
+    }
+}
+
+impl<'a> From<&'a str> for UTF8Reader<'a> {
+    fn from(document: &'a str) -> Self {
+        return UTF8Reader::new(document);
+    }
+}
+
+/// Feeds a document that arrives as raw bytes, in reads of any size, to an
+/// `IncrementalValidator`. A multi-byte character split between two reads
+/// is held back until the rest of it arrives.
+#[cfg(any(feature = "async", feature = "gzip"))]
+pub(crate) struct ChunkedUtf8Validator {
+    validator: IncrementalValidator,
+    pending: Vec<u8>,
+}
+
+#[cfg(any(feature = "async", feature = "gzip"))]
+impl ChunkedUtf8Validator {
+    pub(crate) fn new() -> Self {
+        return ChunkedUtf8Validator {
+            validator: IncrementalValidator::new(),
+            pending: Vec::new(),
+        };
+    }
+
+    pub(crate) fn feed(&mut self, bytes: &[u8]) -> Result<(), ValidationError> {
+        self.pending.extend_from_slice(bytes);
+
+        let (valid, invalid) = match str::from_utf8(&self.pending) {
+            Ok(text) => (text.len(), false),
+            // The tail may be the start of a character that continues in the next read.
+            Err(why) if why.error_len().is_none() => (why.valid_up_to(), false),
+            Err(why) => (why.valid_up_to(), true),
+        };
+
+        let text = str::from_utf8(&self.pending[..valid]).unwrap_or_default();
+        self.validator.feed(text)?;
+        if invalid {
+            let reason = format!("Invalid UTF-8 byte 0x{:02X}", self.pending[valid]);
+            return Err(self.validator.reject(reason));
+        }
+        self.pending.drain(..valid);
+        return Ok(());
+    }
+
+    pub(crate) fn finish(mut self) -> Result<(), ValidationError> {
+        if !self.pending.is_empty() {
+            let reason = String::from("Input ends inside a UTF-8 sequence");
+            return Err(self.validator.reject(reason));
+        }
+        return self.validator.finish();
     }
 }
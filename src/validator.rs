@@ -1,6 +1,30 @@
+use super::decode::decode_validated_string;
+#[cfg(feature = "std")]
+use super::error::JsonFileError;
+use super::error::{ErrorKind, Fault, ValidationError};
+use super::events::JsonEvent;
+#[cfg(feature = "gzip")]
+use super::gzip::{is_gzip, validate_gzip_file};
+#[cfg(feature = "std")]
+use super::utils::read_file_as_utf8;
 use super::utils::{UTF8Reader, UTF8ReaderResult};
+use super::value::{
+    classify_number, exceeds_safe_integer, is_negative_zero, round_trips_f64, JsonNumber,
+    JsonValue, NumberKind,
+};
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "std")]
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
 
-const MAX_DEPTH: usize = 100;
+pub(crate) const MAX_DEPTH: usize = 100;
+
+/// How many scalars `validate_with_progress` lets pass between reports.
+const PROGRESS_INTERVAL: usize = 1 << 16;
 
 // Structural Tokens
 const ST_LSBRACKET: &str = "[";
@@ -14,11 +38,15 @@ const ST_COMMA: &str = ",";
 const LN_TRUE: &str = "true";
 const LN_FALSE: &str = "false";
 const LN_NULL: &str = "null";
+const LN_INFINITY: &str = "Infinity";
+const LN_NAN: &str = "NaN";
 
 // Leading Tokens
 const LT_TRUE: &str = "t";
 const LT_FALSE: &str = "f";
 const LT_NULL: &str = "n";
+const LT_INFINITY: &str = "I";
+const LT_NAN: &str = "N";
 
 // Insignificant Whitespace
 const WS_CHARACTER_TABULATION: &str = "\u{0009}";
@@ -26,7 +54,12 @@ const WS_LINE_FEED: &str = "\u{000A}";
 const WS_CARRIAGE_RETURN: &str = "\u{000D}";
 const WS_SPACE: &str = "\u{0020}";
 
+// Byte Order Mark
+const BOM: &str = "\u{FEFF}";
+const NUL: &str = "\u{0}";
+
 const SP_QUOTE: &str = "\"";
+const SP_APOSTROPHE: &str = "'";
 const SP_REVERSE_SOLIDUS: &str = "\\";
 const SP_SOLIDUS: &str = "/";
 const SP_BACKSPACE: &str = "b";
@@ -36,36 +69,660 @@ const SP_CARRIAGE_RETURN: &str = "r";
 const SP_CHARACTER_TABULATION: &str = "t";
 const SP_UNICODE: &str = "u";
 const SP_MINUS: &str = "-";
+const SP_PLUS: &str = "+";
 const SP_DECIMAL_POINT: &str = ".";
 
-pub fn validate(document: &UTF8Reader) -> Result<(), String> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BomPolicy {
+    /// A leading BOM is an error, like any other unexpected character.
+    Forbid,
+    /// A leading BOM is skipped.
+    Allow,
+    /// The document must begin with a BOM.
+    Require,
+}
+
+impl Default for BomPolicy {
+    fn default() -> Self {
+        return BomPolicy::Forbid;
+    }
+}
+
+/// How `/` may be written inside strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolidusPolicy {
+    /// Both `/` and `\/` are accepted, as RFC 8259 allows.
+    Allow,
+    /// Every `/` must be written `\/`, so `</script>` can not appear.
+    RequireEscaped,
+    /// `\/` is rejected; `/` must be written as is.
+    ForbidEscaped,
+}
+
+impl Default for SolidusPolicy {
+    fn default() -> Self {
+        return SolidusPolicy::Allow;
+    }
+}
+
+/// What decoding does with a `\uXXXX` escape for a surrogate that is not
+/// half of a pair, such as `"\uD800"`. RFC 8259 lets such strings through
+/// validation either way. There is no way to keep the surrogate itself,
+/// since a Rust `String` can not hold one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoneSurrogatePolicy {
+    /// Decoding the string fails.
+    Error,
+    /// The surrogate is decoded as U+FFFD REPLACEMENT CHARACTER.
+    Replace,
+}
+
+impl Default for LoneSurrogatePolicy {
+    fn default() -> Self {
+        return LoneSurrogatePolicy::Error;
+    }
+}
+
+/// Which values may stand at the top level of a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopLevelType {
+    /// Any value, as RFC 8259 allows.
+    Any,
+    Object,
+    Array,
+    /// An object or an array, as RFC 4627 required.
+    ObjectOrArray,
+}
+
+impl Default for TopLevelType {
+    fn default() -> Self {
+        return TopLevelType::Any;
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ValidateOptions {
+    pub bom_policy: BomPolicy,
+    /// Rejects documents whose value is not of this type, for APIs that
+    /// never take a bare scalar.
+    pub require_top_level: TopLevelType,
+    /// Inputs longer than this many bytes are rejected before any scanning.
+    pub max_bytes: Option<usize>,
+    /// Caps how deeply arrays and objects nest; `None` keeps the default of
+    /// 100 levels. The parsers recurse once per level, so a much higher cap
+    /// needs a correspondingly larger stack.
+    pub max_depth: Option<usize>,
+    /// Measured in source scalars between the quotes, escapes as written.
+    pub max_string_length: Option<usize>,
+    /// Caps the escape sequences in a single string, since a string of
+    /// nothing but `\uXXXX` escapes is costly to decode.
+    pub max_escapes_per_string: Option<usize>,
+    /// Caps the UTF-8 bytes of a string once decoded, which `max_string_length`
+    /// does not bound: it counts scalars, and a scalar takes up to 4 bytes.
+    /// Only affects decoding, like `lone_surrogate_policy`.
+    pub max_decoded_string_bytes: Option<usize>,
+    pub max_array_elements: Option<usize>,
+    pub max_object_members: Option<usize>,
+    /// Caps the values in the whole document, containers and scalars alike,
+    /// so a shallow document can not be made arbitrarily wide.
+    pub max_total_nodes: Option<usize>,
+    /// Caps the digits of an exponent, not counting leading zeros, so that
+    /// numbers such as `1e99999999999999` are turned away before they reach
+    /// a parser that would try to scale by them.
+    pub max_exponent_digits: Option<usize>,
+    /// Warns when containers nest deeper than this, while still accepting
+    /// anything up to `max_depth`.
+    pub warn_depth: Option<usize>,
+    /// Accepts JSON5 `'single quoted'` strings, in which `"` needs no escape.
+    pub allow_single_quotes: bool,
+    /// Accepts ECMAScript identifiers such as `{ foo: 1 }` as object keys.
+    pub allow_unquoted_keys: bool,
+    /// Warns about `-0`, which many parsers silently read as `0`.
+    pub warn_negative_zero: bool,
+    /// Warns about integers beyond 2^53 and other numbers that an `f64` can
+    /// not hold without losing digits, which RFC 8259 flags as a risk for
+    /// interoperability.
+    pub warn_precision_loss: bool,
+    /// Warns about `\u` escapes, surrogate pairs included, that stand for
+    /// Unicode noncharacters such as U+FFFF, which are valid JSON but often
+    /// betray an encoding bug upstream.
+    pub warn_noncharacters: bool,
+    /// Accepts `Infinity`, `-Infinity` and `NaN` as numbers, as emitted by
+    /// some JavaScript and Python serializers.
+    pub allow_non_finite: bool,
+    /// Accepts JSON5 numbers with an explicit `+` sign, such as `+1`.
+    pub allow_leading_plus: bool,
+    /// Accepts JSON5 hexadecimal integers such as `0xFF`.
+    pub allow_hex_numbers: bool,
+    /// Accepts integer parts with leading zeros, such as `007`, from legacy
+    /// producers. They are read as decimal, not octal.
+    pub allow_leading_zeros: bool,
+    /// Accepts JSON5 numbers with nothing before or after the decimal point,
+    /// such as `.5` and `5.`. A lone `.` is still invalid.
+    pub allow_bare_decimal: bool,
+    pub solidus_policy: SolidusPolicy,
+    /// Only affects decoding: `parse` and the other builders, not `validate`.
+    pub lone_surrogate_policy: LoneSurrogatePolicy,
+    /// Rejects raw U+2028 and U+2029 in strings. They are valid JSON but end
+    /// a string literal in older JavaScript, so documents embedded in a
+    /// script need them escaped.
+    pub forbid_js_unsafe_separators: bool,
+    /// Treats Unicode spaces such as U+00A0 and U+2028 as insignificant
+    /// whitespace. Otherwise they are rejected with an error naming them.
+    pub allow_unicode_whitespace: bool,
+}
+
+/// A chainable setter for each option, such as `max_bytes(1024)`, is
+/// defined in `builder.rs` alongside the same one on `Validator`.
+impl ValidateOptions {
+    /// Plain RFC 8259 JSON with no limits but the default nesting depth; the
+    /// same as `default()`.
+    pub fn strict() -> Self {
+        return ValidateOptions::default();
+    }
+
+    /// Every JSON5 relaxation this validator supports: single quoted
+    /// strings, unquoted keys, `Infinity` and `NaN`, a leading `+`,
+    /// hexadecimal integers, bare decimal points and Unicode whitespace.
+    pub fn json5() -> Self {
+        return ValidateOptions {
+            allow_single_quotes: true,
+            allow_unquoted_keys: true,
+            allow_non_finite: true,
+            allow_leading_plus: true,
+            allow_hex_numbers: true,
+            allow_bare_decimal: true,
+            allow_unicode_whitespace: true,
+            ..ValidateOptions::default()
+        };
+    }
+
+    /// How deeply containers may nest: `max_depth`, or 100 levels.
+    pub(crate) fn depth_limit(&self) -> usize {
+        return self.max_depth.unwrap_or(MAX_DEPTH);
+    }
+}
+
+/// State shared across one traversal of a document.
+pub(crate) struct Context<'a> {
+    options: &'a ValidateOptions,
+    events: Option<&'a mut dyn FnMut(JsonEvent)>,
+    /// Non-fatal findings, with the index they were found at.
+    warnings: Vec<(usize, Fault)>,
+    /// Index of the first scalar after a skipped byte order mark.
+    origin: usize,
+    /// Values begun so far, for `max_total_nodes`.
+    nodes: usize,
+    progress: Option<&'a mut dyn FnMut(usize, usize)>,
+    /// The index past which progress is next reported; never reached when
+    /// nobody listens, so the check costs one comparison.
+    next_progress: usize,
+}
+
+impl<'a> Context<'a> {
+    pub(crate) fn new(
+        options: &'a ValidateOptions,
+        events: Option<&'a mut dyn FnMut(JsonEvent)>,
+    ) -> Self {
+        return Context {
+            options,
+            events,
+            warnings: vec![],
+            origin: 0,
+            nodes: 0,
+            progress: None,
+            next_progress: usize::MAX,
+        };
+    }
+
+    fn with_progress(mut self, progress: &'a mut dyn FnMut(usize, usize)) -> Self {
+        self.progress = Some(progress);
+        self.next_progress = 0;
+        return self;
+    }
+
+    fn report_progress(&mut self, index: usize, total: usize) {
+        if let Some(callback) = self.progress.as_mut() {
+            callback(index, total);
+        }
+        self.next_progress = index.saturating_add(PROGRESS_INTERVAL);
+    }
+
+    fn wants_events(&self) -> bool {
+        return self.events.is_some();
+    }
+
+    fn emit<F: FnOnce() -> JsonEvent>(&mut self, event: F) {
+        if let Some(callback) = self.events.as_mut() {
+            callback(event());
+        }
+    }
+}
+
+pub type ValidateResult = Result<(), ValidationError>;
+
+pub fn validate(document: &UTF8Reader) -> ValidateResult {
+    return validate_with_options(document, &ValidateOptions::default());
+}
+
+pub fn validate_with_options(document: &UTF8Reader, options: &ValidateOptions) -> ValidateResult {
+    return validate_document(document, &mut Context::new(options, None));
+}
+
+/// Like `validate`, for a `document` sliced out of a larger text such as a
+/// log line or an HTTP body. The slice starts `base_offset` bytes into that
+/// text, at the 1-based `base_line` and `base_column`, and the error's byte
+/// offset, line and column are reported as positions in the larger text.
+/// Its index stays an index into `document`.
+pub fn validate_at_offset(
+    document: &UTF8Reader,
+    base_offset: usize,
+    base_line: usize,
+    base_column: usize,
+) -> ValidateResult {
+    let options = ValidateOptions::default();
+    return validate_at_offset_with_options(
+        document,
+        &options,
+        base_offset,
+        base_line,
+        base_column,
+    );
+}
+
+/// Like `validate_at_offset`, with `options`. A byte order mark the options
+/// let through is not at the start of the larger text, so unlike with
+/// `validate_with_options` it takes up a column there.
+pub fn validate_at_offset_with_options(
+    document: &UTF8Reader,
+    options: &ValidateOptions,
+    base_offset: usize,
+    base_line: usize,
+    base_column: usize,
+) -> ValidateResult {
+    let mut ctx = Context::new(options, None);
+    let result = validate_document(document, &mut ctx);
+
+    let origin = ctx.origin;
+    let base = (base_offset, base_line, base_column.saturating_add(origin));
+    return result.map_err(|error| error.moved_into(document, origin, base));
+}
+
+/// Like `validate`, but calls `progress` with the number of scalars scanned
+/// so far and the total, every few tens of thousands of scalars, and once
+/// more with both equal when a valid document is done. Progress is noted
+/// between tokens, so a single long string is one step.
+pub fn validate_with_progress(
+    document: &UTF8Reader,
+    progress: &mut dyn FnMut(usize, usize),
+) -> ValidateResult {
+    let options = ValidateOptions::default();
+    let mut ctx = Context::new(&options, None).with_progress(progress);
+    validate_document(document, &mut ctx)?;
+
+    ctx.report_progress(document.len(), document.len());
+    return Ok(());
+}
+
+/// Like `validate_with_options`, but also returns the non-fatal warnings
+/// enabled by options such as `warn_negative_zero`, `warn_precision_loss`
+/// and `warn_depth`, in document order.
+pub fn validate_with_warnings(
+    document: &UTF8Reader,
+    options: &ValidateOptions,
+) -> (ValidateResult, Vec<ValidationError>) {
+    let mut ctx = Context::new(options, None);
+    let result = validate_document(document, &mut ctx);
+
+    let origin = ctx.origin;
+    let warnings = ctx
+        .warnings
+        .into_iter()
+        .map(|(index, fault)| ValidationError::from_fault(document, index, origin, fault))
+        .collect();
+
+    return (result, warnings);
+}
+
+pub fn is_valid(input: &str) -> bool {
+    return validate_str(input).is_ok();
+}
+
+/// A cheap guess at whether `input` is JSON, for telling it apart from YAML,
+/// CSV or XML before paying for validation. Only the start of the first
+/// value is looked at, past any whitespace and byte order mark, so `input`
+/// may be just the first few bytes of a document; a `true` here does not
+/// mean the whole of it is valid.
+pub fn looks_like_json(input: &str) -> bool {
+    let rest = input
+        .trim_start_matches('\u{FEFF}')
+        .trim_start_matches([' ', '\t', '\n', '\r']);
+
+    let mut chars = rest.chars();
+    return match chars.next() {
+        Some('{' | '[' | '"' | '0'..='9') => true,
+        // A number, unlike a YAML list item or document marker
+        Some('-') => matches!(chars.next(), Some('0'..='9') | None),
+        // A literal, possibly cut short by the end of the input
+        Some('t' | 'f' | 'n') => ["true", "false", "null"].iter().any(|literal| {
+            rest.chars().zip(literal.chars()).all(|(a, b)| a == b)
+                && !matches!(
+                    rest.chars().nth(literal.len()),
+                    Some(c) if c.is_alphanumeric() || c == '_' || c == ':'
+                )
+        }),
+        _ => false,
+    };
+}
+
+/// A single cheap pass over the bytes of `input` that spots documents
+/// `validate` is sure to reject: blank ones, ones whose brackets do not pair
+/// up or nest too deeply outside strings, and ones that end inside a string.
+/// `true` means the document is invalid; `false` only means it still needs
+/// validating. Nothing is allocated, which makes it cheap enough to run
+/// ahead of validation on a stream of mostly valid documents.
+///
+/// Only strict JSON is accounted for: under relaxed options, a bracket in a
+/// single quoted string could make a valid document look unbalanced.
+pub fn quick_reject(input: &str) -> bool {
+    let mut open = [0u8; MAX_DEPTH];
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaping = false;
+    let mut blank = true;
+
+    // Every byte of a multi-byte UTF-8 sequence is above 0x7F, so none of
+    // them can be mistaken for the ASCII looked for here.
+    for byte in input.bytes() {
+        if in_string {
+            match byte {
+                _ if escaping => escaping = false,
+                b'\\' => escaping = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match byte {
+            b' ' | b'\t' | b'\n' | b'\r' => continue,
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                if depth == MAX_DEPTH {
+                    return true;
+                }
+                open[depth] = byte;
+                depth += 1;
+            }
+            b'}' | b']' => {
+                let opening = if byte == b'}' { b'{' } else { b'[' };
+                if depth == 0 || open[depth - 1] != opening {
+                    return true;
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+        blank = false;
+    }
+
+    return blank || in_string || depth > 0;
+}
+
+pub fn validate_str(input: &str) -> ValidateResult {
+    return validate_str_with_options(input, &ValidateOptions::default());
+}
+
+/// Like `validate_with_options`, but checks `max_bytes` before the reader is
+/// built, so oversized inputs are rejected without being scanned.
+pub fn validate_str_with_options(input: &str, options: &ValidateOptions) -> ValidateResult {
+    check_document_size(input.len(), options)?;
+
+    let document = UTF8Reader::new(input);
+    return validate_document(&document, &mut Context::new(options, None));
+}
+
+/// Validates raw bytes, reporting invalid UTF-8 at the position of the
+/// first offending byte rather than failing before the scan.
+pub fn validate_bytes(input: &[u8]) -> ValidateResult {
+    return validate_bytes_with_options(input, &ValidateOptions::default());
+}
+
+pub fn validate_bytes_with_options(input: &[u8], options: &ValidateOptions) -> ValidateResult {
+    check_document_size(input.len(), options)?;
+
+    let (valid, reason) = match core::str::from_utf8(input) {
+        Ok(text) => return validate_str_with_options(text, options),
+        Err(why) if why.error_len().is_none() => (
+            why.valid_up_to(),
+            String::from("Input ends inside a UTF-8 sequence"),
+        ),
+        Err(why) => (
+            why.valid_up_to(),
+            format!("Invalid UTF-8 byte 0x{:02X}", input[why.valid_up_to()]),
+        ),
+    };
+
+    let prefix = core::str::from_utf8(&input[..valid]).unwrap_or_default();
+    return Err(encoding_error(prefix, valid, reason));
+}
+
+/// An error for input that could not be decoded, placed after the text that
+/// could be.
+pub(crate) fn encoding_error(prefix: &str, byte_offset: usize, reason: String) -> ValidationError {
+    let document = UTF8Reader::new(prefix);
+    let fault = Fault::new(ErrorKind::Encoding, reason);
+    return ValidationError::from_fault(&document, document.len(), 0, fault)
+        .with_byte_offset(byte_offset);
+}
+
+/// Reads the file at `path` and validates it with default options.
+///
+/// With the `gzip` feature, a file named `*.gz` or starting with the gzip
+/// magic bytes is inflated as it is validated.
+#[cfg(feature = "std")]
+pub fn validate_file(path: &Path) -> Result<(), JsonFileError> {
+    #[cfg(feature = "gzip")]
+    if is_gzip(path)? {
+        return validate_gzip_file(path);
+    }
+
+    let content = read_file_as_utf8(path)?;
+    validate_str(&content)?;
+    return Ok(());
+}
+
+/// Validates every `.json` file directly inside `dir`, in path order. Other
+/// files and subdirectories are skipped. Only a failure to read the directory
+/// or a file is returned as `Err`; a file that is not UTF-8 is reported as an
+/// `Encoding` error in its own result.
+#[cfg(feature = "std")]
+pub fn validate_all_files(dir: &Path) -> io::Result<Vec<(PathBuf, ValidateResult)>> {
+    let mut paths: Vec<PathBuf> = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension() == Some("json".as_ref()) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut results = Vec::with_capacity(paths.len());
+    for path in paths {
+        let content = fs::read(&path)?;
+        let result = validate_bytes(&content);
+        results.push((path, result));
+    }
+    return Ok(results);
+}
+
+/// Validates each document on its own, as `validate_str` would, keeping
+/// their order. With the `rayon` feature the documents are shared out
+/// across threads.
+pub fn validate_many(documents: &[&str]) -> Vec<ValidateResult> {
+    #[cfg(feature = "rayon")]
+    return documents
+        .par_iter()
+        .map(|document| validate_str(document))
+        .collect();
+
+    #[cfg(not(feature = "rayon"))]
+    return documents
+        .iter()
+        .map(|document| validate_str(document))
+        .collect();
+}
+
+/// Validates a sequence of concatenated top-level values, such as
+/// `{"a":1}{"b":2}` or `1 2 3`, pairing each value's start index with its
+/// result. A value that fails to validate ends the stream, since there is no
+/// telling where it was meant to stop.
+///
+/// A number ends at a bracket or quote, so `1"a"` and `1[2]` are two values
+/// each. Two literal names or a name and a number need whitespace between
+/// them: `truenull` and `1true` are errors, as `tru` or `1x` would be.
+pub fn validate_stream(document: &UTF8Reader) -> Vec<(usize, ValidateResult)> {
+    return validate_stream_with_options(document, &ValidateOptions::default());
+}
+
+/// Like `validate_stream`, under the given options. The size limit and the
+/// byte order mark policy apply to the stream as a whole; everything else
+/// applies to each value.
+pub fn validate_stream_with_options(
+    document: &UTF8Reader,
+    options: &ValidateOptions,
+) -> Vec<(usize, ValidateResult)> {
+    if let Err(error) = check_document_size(document.byte_offset_of(document.len()), options) {
+        return vec![(0, Err(error))];
+    }
+
+    let has_bom = matches!(document.look_ahead(0, 1), UTF8ReaderResult::Ok(BOM));
+    let origin = match options.bom_policy {
+        BomPolicy::Allow | BomPolicy::Require if has_bom => 1,
+        BomPolicy::Require => {
+            let fault = Fault::new(
+                ErrorKind::MissingByteOrderMark,
+                String::from("JSON document should start with a byte order mark"),
+            );
+            return vec![(0, Err(ValidationError::from_fault(document, 0, 0, fault)))];
+        }
+        _ => 0,
+    };
+
+    let mut ctx = Context::new(options, None);
+    ctx.origin = origin;
+    let mut results = vec![];
+    let mut ptr = origin;
+
+    loop {
+        match document.look_ahead(ptr, 1) {
+            UTF8ReaderResult::OutOfBoundError(_) => break,
+            UTF8ReaderResult::Ok(chr) if is_whitespace(chr, options) => {
+                ptr += 1;
+                continue;
+            }
+            UTF8ReaderResult::Ok(_) => {}
+        }
+
+        let start = ptr;
+        let (result, step) = validate_json_value(document, start, 0, &mut ctx);
+        ptr += step;
+
+        match result {
+            Ok(_) => results.push((start, Ok(()))),
+            Err(fault) => {
+                let error = ValidationError::from_fault(document, ptr, origin, fault);
+                results.push((start, Err(error)));
+                break;
+            }
+        }
+    }
+
+    return results;
+}
+
+/// Scans past the one value starting at `index`, of whatever type, and
+/// returns how many units it spans without building or decoding anything.
+/// The value must start right at `index`; whitespace around it is left to
+/// the caller. On failure the step is where scanning stopped.
+pub fn skip_value(document: &UTF8Reader, index: usize) -> (ValidateResult, usize) {
+    let (result, step) = scan_value(document, index, 0, &ValidateOptions::default());
+
+    return match result {
+        Ok(_) => (Ok(()), step),
+        Err(fault) => {
+            let error = ValidationError::from_fault(document, index + step, 0, fault);
+            (Err(error), step)
+        }
+    };
+}
+
+pub(crate) fn check_document_size(actual: usize, options: &ValidateOptions) -> ValidateResult {
+    return match options.max_bytes {
+        Some(limit) if actual > limit => Err(ValidationError::document_too_large(limit, actual)),
+        _ => Ok(()),
+    };
+}
+
+pub(crate) fn validate_document(document: &UTF8Reader, ctx: &mut Context) -> ValidateResult {
+    let options = ctx.options;
+
     enum State {
         PreDocument,
         PostDocument,
     }
 
-    fn error(index: usize, reason: &str) -> Result<(), String> {
-        return Err(format!(
-            "Validation Error @ 1:{}\nReason: {}",
-            index + 1,
-            reason
-        ));
-    }
+    let error = |index: usize, origin: usize, kind: ErrorKind, reason: &str| -> ValidateResult {
+        let fault = Fault::new(kind, String::from(reason));
+        return Err(ValidationError::from_fault(document, index, origin, fault));
+    };
+
+    check_document_size(document.byte_offset_of(document.len()), options)?;
 
     let length = document.len();
     if length == 0 {
-        return error(0, "JSON document can not be empty");
+        return error(
+            0,
+            0,
+            ErrorKind::EmptyDocument,
+            "JSON document can not be empty",
+        );
     }
 
+    let has_bom = match document.look_ahead(0, 1) {
+        UTF8ReaderResult::Ok(chr) => chr == BOM,
+        UTF8ReaderResult::OutOfBoundError(_) => false,
+    };
+    let origin = match options.bom_policy {
+        BomPolicy::Forbid => 0,
+        BomPolicy::Allow if has_bom => 1,
+        BomPolicy::Allow => 0,
+        BomPolicy::Require if has_bom => 1,
+        BomPolicy::Require => {
+            return error(
+                0,
+                0,
+                ErrorKind::MissingByteOrderMark,
+                "JSON document should start with a byte order mark",
+            )
+        }
+    };
+    ctx.origin = origin;
+
     let mut state = State::PreDocument;
-    let mut ptr = 0;
+    let mut ptr = origin;
 
     loop {
         let chr = match document.look_ahead(ptr, 1) {
             UTF8ReaderResult::Ok(s) => s,
             UTF8ReaderResult::OutOfBoundError(_) => {
                 if let State::PreDocument = state {
-                    return error(ptr, "No valid JSON value found");
+                    return error(
+                        ptr,
+                        origin,
+                        ErrorKind::EmptyDocument,
+                        "No valid JSON value found",
+                    );
                 }
                 break;
             }
@@ -73,20 +730,44 @@ pub fn validate(document: &UTF8Reader) -> Result<(), String> {
 
         match state {
             State::PreDocument => match chr {
-                _ if is_insignificant_whitespace(chr) => ptr += 1,
+                _ if is_whitespace(chr, options) => ptr += 1,
+                _ if is_stray_character(chr) => {
+                    // A forbidden byte order mark in front of the document
+                    // is a policy matter rather than a stray character.
+                    let fault = if ptr == 0 && chr == BOM {
+                        unicode_whitespace_fault(chr)
+                    } else {
+                        stray_character_fault(chr)
+                    };
+                    return Err(ValidationError::from_fault(document, ptr, origin, fault));
+                }
                 _ => {
-                    let (result, step) = validate_json_value(document, ptr, 0);
+                    let (result, step) = validate_json_value(document, ptr, 0, ctx);
                     ptr += step;
 
                     match result {
                         Ok(_) => state = State::PostDocument,
-                        Err(reason) => return error(ptr, &reason),
+                        Err(fault) => {
+                            return Err(ValidationError::from_fault(document, ptr, origin, fault))
+                        }
+                    }
+
+                    if let Err(fault) = check_top_level(chr, options.require_top_level) {
+                        let index = ptr - step;
+                        return Err(ValidationError::from_fault(document, index, origin, fault));
                     }
                 }
             },
             State::PostDocument => match chr {
-                _ if is_insignificant_whitespace(chr) => ptr += 1,
-                _ => return error(ptr, &format!("Expect EOF, but found \"{}\"", chr)),
+                _ if is_whitespace(chr, options) => ptr += 1,
+                _ if is_stray_character(chr) => {
+                    let fault = stray_character_fault(chr);
+                    return Err(ValidationError::from_fault(document, ptr, origin, fault));
+                }
+                _ => {
+                    let reason = trailing_data_reason(document, ptr, options);
+                    return error(ptr, origin, ErrorKind::TrailingData, &reason);
+                }
             },
         }
     }
@@ -94,255 +775,754 @@ pub fn validate(document: &UTF8Reader) -> Result<(), String> {
     return Ok(());
 }
 
-fn validate_json_value(
+/// Tells a second, complete value (often two documents pasted together)
+/// apart from plain garbage after the document.
+pub(crate) fn trailing_data_reason(
     document: &UTF8Reader,
     index: usize,
-    depth: usize,
-) -> (Result<(), String>, usize) {
-    return match document.look_ahead(index, 1) {
-        UTF8ReaderResult::OutOfBoundError(_) => {
-            return (Err(format!("Look ahead out of bound")), 1);
-        }
-        UTF8ReaderResult::Ok(chr) => match chr {
-            ST_LCBRACKET => validate_object(document, index, depth + 1),
-            ST_LSBRACKET => validate_array(document, index, depth + 1),
-            "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" | SP_MINUS => {
-                validate_number(document, index)
-            }
-            SP_QUOTE => validate_string(document, index),
-            LT_TRUE => validate_true(document, index),
-            LT_FALSE => validate_false(document, index),
-            LT_NULL => validate_null(document, index),
-            _ => {
-                return (Err(format!("Unknown character: \"{}\"", chr)), 1);
-            }
-        },
+    options: &ValidateOptions,
+) -> String {
+    let chr = match document.look_ahead(index, 1) {
+        UTF8ReaderResult::Ok(chr) => chr,
+        UTF8ReaderResult::OutOfBoundError(_) => return String::from("Expect EOF"),
+    };
+
+    if value_width(document, index, options).is_none() {
+        return format!("Expect EOF, but found \"{}\"", chr);
+    }
+
+    return format!(
+        "Expect EOF, but found a second JSON value ({}); use validate_stream for concatenated values",
+        value_kind(chr)
+    );
+}
+
+/// The kind of the valid value starting with `chr`.
+fn value_kind(chr: &str) -> &'static str {
+    return match chr {
+        ST_LCBRACKET => "object",
+        ST_LSBRACKET => "array",
+        LT_TRUE | LT_FALSE => "boolean",
+        LT_NULL => "null",
+        SP_QUOTE | SP_APOSTROPHE => "string",
+        _ => "number",
+    };
+}
+
+/// Rejects a top-level value starting with `chr` that is not of the
+/// required type.
+pub(crate) fn check_top_level(chr: &str, top_level: TopLevelType) -> Result<(), Fault> {
+    if is_allowed_top_level(chr, top_level) {
+        return Ok(());
+    }
+
+    let reason = format!(
+        "Top-level value should be {}, but found {}",
+        top_level_name(top_level),
+        value_kind(chr)
+    );
+    return Err(Fault::new(ErrorKind::UnexpectedTopLevel, reason));
+}
+
+fn is_allowed_top_level(chr: &str, top_level: TopLevelType) -> bool {
+    return match top_level {
+        TopLevelType::Any => true,
+        TopLevelType::Object => chr == ST_LCBRACKET,
+        TopLevelType::Array => chr == ST_LSBRACKET,
+        TopLevelType::ObjectOrArray => chr == ST_LCBRACKET || chr == ST_LSBRACKET,
     };
 }
 
-fn validate_object(
+fn top_level_name(top_level: TopLevelType) -> &'static str {
+    return match top_level {
+        TopLevelType::Any => "a value",
+        TopLevelType::Object => "an object",
+        TopLevelType::Array => "an array",
+        TopLevelType::ObjectOrArray => "an object or an array",
+    };
+}
+
+/// Validates the value starting at `index`, nested `depth` levels deep.
+///
+/// Open containers are kept on a heap-allocated stack instead of the call
+/// stack, so deeply nested input can not overflow the native stack.
+fn validate_json_value(
     document: &UTF8Reader,
-    start: usize,
+    index: usize,
     depth: usize,
-) -> (Result<(), String>, usize) {
-    enum State {
-        Begin,
-        PreKey,
-        Key,
+    ctx: &mut Context,
+) -> (Result<(), Fault>, usize) {
+    enum ObjectState {
+        // A "}" may only close the object before its first key, not after a ","
+        Key { closing_allowed: bool },
         PreValue,
         Value,
         PostValue,
     }
 
-    if depth > MAX_DEPTH {
-        return (Err(format!("Nested JSON value is too deep")), 0);
+    enum ArrayState {
+        PreValue,
+        Value,
+        PostValue,
     }
 
-    let mut state: State = State::Begin;
-    let mut ptr = 0;
+    enum Frame {
+        Object {
+            state: ObjectState,
+            members: usize,
+            key_start: usize,
+            key_width: usize,
+        },
+        Array {
+            state: ArrayState,
+            elements: usize,
+        },
+    }
 
-    loop {
-        let index = start + ptr;
+    let options = ctx.options;
+    let mut frames: Vec<Frame> = vec![];
+    let mut ptr = index;
+    // Whether a value starts at `ptr`, rather than more of the innermost container.
+    let mut at_value = true;
 
-        let chr = match document.look_ahead(index, 1) {
-            UTF8ReaderResult::Ok(s) => s,
-            UTF8ReaderResult::OutOfBoundError(i) => {
-                return (Err(format!("Incomplete number value")), i)
+    // On failure: the fault, where it happened, and whether it was the current
+    // value of the innermost container that failed rather than the container.
+    let (fault, position, in_value): (Fault, usize, bool) = loop {
+        if ptr >= ctx.next_progress {
+            ctx.report_progress(ptr, document.len());
+        }
+
+        if at_value {
+            at_value = false;
+
+            let chr = match document.look_ahead(ptr, 1) {
+                UTF8ReaderResult::Ok(chr) => chr,
+                UTF8ReaderResult::OutOfBoundError(_) => {
+                    break (
+                        String::from("Look ahead out of bound").into(),
+                        ptr + 1,
+                        true,
+                    );
+                }
+            };
+
+            if is_limit_reached(options.max_total_nodes, ctx.nodes) {
+                break (too_many_nodes(options), ptr, true);
             }
-        };
+            ctx.nodes += 1;
 
-        match state {
-            State::Begin => {
-                if chr != ST_LCBRACKET {
-                    return (Err(String::from("Object should start with \"{\"")), ptr);
+            if chr == ST_LCBRACKET || chr == ST_LSBRACKET {
+                if let Err(fault) = check_depth(ptr, depth + frames.len() + 1, ctx) {
+                    break (fault, ptr, true);
+                }
+
+                if chr == ST_LCBRACKET {
+                    ctx.emit(|| JsonEvent::BeginObject);
+                    frames.push(Frame::Object {
+                        state: ObjectState::Key {
+                            closing_allowed: true,
+                        },
+                        members: 0,
+                        key_start: 0,
+                        key_width: 0,
+                    });
+                } else {
+                    ctx.emit(|| JsonEvent::BeginArray);
+                    frames.push(Frame::Array {
+                        state: ArrayState::PreValue,
+                        elements: 0,
+                    });
                 }
-                state = State::PreKey;
+                ptr += 1;
+                continue;
             }
-            State::PreKey => match chr {
-                ST_RCBRACKET => return (Ok(()), ptr + 1),
-                _ if is_insignificant_whitespace(chr) => {}
-                _ => {
-                    let (result, step) = validate_string(document, index);
-                    ptr += step;
 
-                    if let Ok(_) = result {
-                        state = State::PreValue;
-                        continue;
-                    } else {
-                        return (
-                            Err(String::from("Object key should be a valid string")),
-                            ptr,
-                        );
-                    }
+            let (result, step) = validate_scalar(document, ptr, ctx);
+            ptr += step;
+            if let Err(fault) = result {
+                break (fault, ptr, true);
+            }
+        } else {
+            let chr = match document.look_ahead(ptr, 1) {
+                UTF8ReaderResult::Ok(s) => s,
+                UTF8ReaderResult::OutOfBoundError(_) => {
+                    let expected = match frames.last() {
+                        Some(Frame::Object { state, .. }) => match state {
+                            ObjectState::Key {
+                                closing_allowed: true,
+                            } => "an object key or \"}\"",
+                            ObjectState::Key { .. } => "an object key",
+                            ObjectState::PreValue => "\":\"",
+                            ObjectState::Value => "a value",
+                            ObjectState::PostValue => "\",\" or \"}\"",
+                        },
+                        Some(Frame::Array { state, .. }) => match state {
+                            ArrayState::PreValue => "a value or \"]\"",
+                            ArrayState::Value => "the next element",
+                            ArrayState::PostValue => "\",\" or \"]\"",
+                        },
+                        None => "a value",
+                    };
+                    break (end_of_input_fault(expected), ptr, false);
                 }
+            };
+
+            let closed = match frames.last_mut() {
+                Some(Frame::Object {
+                    state,
+                    members,
+                    key_start,
+                    key_width,
+                }) => match state {
+                    ObjectState::Key { closing_allowed } => match chr {
+                        ST_RCBRACKET if *closing_allowed => true,
+                        ST_RCBRACKET => break (trailing_comma_fault(chr), ptr, false),
+                        _ if is_whitespace(chr, options) => false,
+                        _ if is_stray_character(chr) => {
+                            break (stray_character_fault(chr), ptr, false)
+                        }
+                        _ if is_limit_reached(options.max_object_members, *members) => {
+                            break (too_many_object_members(options), ptr, false);
+                        }
+                        _ => {
+                            *members += 1;
+                            let (result, step) = validate_key(document, ptr, options);
+
+                            if let Err(fault) = result {
+                                // A limit, or a fault inside a quoted key, says more
+                                // than a generic key error.
+                                let is_quoted = chr == SP_QUOTE
+                                    || (chr == SP_APOSTROPHE && options.allow_single_quotes);
+                                let fault = if fault.kind != ErrorKind::Syntax || is_quoted {
+                                    fault
+                                } else {
+                                    invalid_key(options)
+                                };
+                                break (fault, ptr + step, false);
+                            }
+
+                            if options.warn_noncharacters {
+                                check_noncharacter_escapes(document, ptr, step, ctx);
+                            }
+
+                            if ctx.wants_events() {
+                                match decode_key(document, ptr, step, ctx.options) {
+                                    Ok(key) => ctx.emit(|| JsonEvent::Key(key)),
+                                    Err((fault, offset)) => break (fault, ptr + offset, false),
+                                }
+                            }
+                            *key_start = ptr;
+                            *key_width = step;
+                            *state = ObjectState::PreValue;
+                            ptr += step;
+                            continue;
+                        }
+                    },
+                    ObjectState::PreValue => match chr {
+                        ST_COLON => {
+                            *state = ObjectState::Value;
+                            false
+                        }
+                        _ if is_whitespace(chr, options) => false,
+                        _ if is_stray_character(chr) => {
+                            break (stray_character_fault(chr), ptr, false)
+                        }
+                        _ => {
+                            let reason = format!("Invalid character after object key: \"{}\"", chr);
+                            break (reason.into(), ptr, false);
+                        }
+                    },
+                    ObjectState::Value => match chr {
+                        _ if is_whitespace(chr, options) => false,
+                        _ if is_stray_character(chr) => {
+                            break (stray_character_fault(chr), ptr, false)
+                        }
+                        _ => {
+                            at_value = true;
+                            continue;
+                        }
+                    },
+                    ObjectState::PostValue => match chr {
+                        ST_RCBRACKET => true,
+                        ST_COMMA => {
+                            *state = ObjectState::Key {
+                                closing_allowed: false,
+                            };
+                            false
+                        }
+                        _ if is_whitespace(chr, options) => false,
+                        _ if is_stray_character(chr) => {
+                            break (stray_character_fault(chr), ptr, false)
+                        }
+                        _ => {
+                            let reason =
+                                format!("Invalid character after object value: \"{}\"", chr);
+                            break (reason.into(), ptr, false);
+                        }
+                    },
+                },
+                Some(Frame::Array { state, elements }) => match state {
+                    ArrayState::PreValue | ArrayState::Value => match chr {
+                        // A "]" may only close the array before its first element, not after a ","
+                        ST_RSBRACKET if matches!(state, ArrayState::PreValue) => true,
+                        // Reported at the element the "," promised
+                        ST_RSBRACKET => {
+                            *elements += 1;
+                            break (trailing_comma_fault(chr), ptr, true);
+                        }
+                        _ if is_whitespace(chr, options) => false,
+                        _ if is_stray_character(chr) => {
+                            break (stray_character_fault(chr), ptr, false)
+                        }
+                        _ if is_limit_reached(options.max_array_elements, *elements) => {
+                            break (too_many_array_elements(options), ptr, false);
+                        }
+                        _ => {
+                            *elements += 1;
+                            at_value = true;
+                            continue;
+                        }
+                    },
+                    ArrayState::PostValue => match chr {
+                        ST_RSBRACKET => true,
+                        ST_COMMA => {
+                            *state = ArrayState::Value;
+                            false
+                        }
+                        _ if is_whitespace(chr, options) => false,
+                        _ if is_stray_character(chr) => {
+                            break (stray_character_fault(chr), ptr, false)
+                        }
+                        _ => break (format!("Invalid character: \"{}\"", chr).into(), ptr, false),
+                    },
+                },
+                None => return (Ok(()), ptr - index),
+            };
+
+            ptr += 1;
+            if !closed {
+                continue;
+            }
+
+            match frames.pop() {
+                Some(Frame::Object { .. }) => ctx.emit(|| JsonEvent::EndObject),
+                _ => ctx.emit(|| JsonEvent::EndArray),
+            }
+        }
+
+        // A value has just ended before `ptr`.
+        match frames.last_mut() {
+            None => return (Ok(()), ptr - index),
+            Some(Frame::Object { state, .. }) => *state = ObjectState::PostValue,
+            Some(Frame::Array { state, .. }) => *state = ArrayState::PostValue,
+        }
+    };
+
+    // Each container around the failure names the member or element it
+    // failed in, innermost first.
+    let owners = if in_value {
+        frames.len()
+    } else {
+        frames.len() - 1
+    };
+    let mut fault = fault;
+    for frame in frames[..owners].iter().rev() {
+        fault = match frame {
+            Frame::Object {
+                key_start,
+                key_width,
+                ..
+            } => match decode_key(document, *key_start, *key_width, ctx.options) {
+                Ok(key) => fault.in_member(&key),
+                Err(_) => fault.in_member("?"),
             },
-            State::Key => match chr {
-                _ if is_insignificant_whitespace(chr) => {}
-                _ => {
-                    let (result, step) = validate_string(document, index);
-                    ptr += step;
+            Frame::Array { elements, .. } => fault.in_element(elements - 1),
+        };
+    }
+
+    return (Err(fault), position - index);
+}
+
+/// Validates a value that is not a container.
+fn validate_scalar(
+    document: &UTF8Reader,
+    index: usize,
+    ctx: &mut Context,
+) -> (Result<(), Fault>, usize) {
+    let chr = match document.look_ahead(index, 1) {
+        UTF8ReaderResult::OutOfBoundError(_) => {
+            return (Err(String::from("Look ahead out of bound").into()), 1);
+        }
+        UTF8ReaderResult::Ok(chr) => chr,
+    };
+
+    let (result, step) = match chr {
+        SP_MINUS
+            if ctx.options.allow_non_finite
+                && matches!(
+                    document.look_ahead(index + 1, 1),
+                    UTF8ReaderResult::Ok(LT_INFINITY)
+                ) =>
+        {
+            validate_infinity(document, index)
+        }
+        "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" | SP_MINUS => {
+            validate_number(document, index, ctx.options)
+        }
+        SP_PLUS if ctx.options.allow_leading_plus => validate_number(document, index, ctx.options),
+        SP_DECIMAL_POINT if ctx.options.allow_bare_decimal => {
+            validate_number(document, index, ctx.options)
+        }
+        SP_QUOTE => validate_string(document, index, ctx.options),
+        SP_APOSTROPHE if ctx.options.allow_single_quotes => {
+            validate_string(document, index, ctx.options)
+        }
+        LT_TRUE => validate_true(document, index),
+        LT_FALSE => validate_false(document, index),
+        LT_NULL => validate_null(document, index),
+        LT_INFINITY if ctx.options.allow_non_finite => validate_infinity(document, index),
+        LT_NAN if ctx.options.allow_non_finite => validate_nan(document, index),
+        SP_DECIMAL_POINT | SP_PLUS | "e" | "E" => {
+            return (
+                Err(format!("A value may not begin with \"{}\"", chr).into()),
+                0,
+            );
+        }
+        _ if is_miscased_literal(word_at(document, index)) => {
+            let word = word_at(document, index);
+            let reason = format!(
+                "Expected \"{}\", but found \"{}\"; literal names are lowercase",
+                word.to_lowercase(),
+                word
+            );
+            return (Err(Fault::new(ErrorKind::InvalidLiteral, reason)), 0);
+        }
+        _ => {
+            return (Err(format!("Unknown character: \"{}\"", chr).into()), 0);
+        }
+    };
+
+    if result.is_ok() && ctx.options.warn_negative_zero {
+        if let UTF8ReaderResult::Ok(lexeme) = document.look_ahead(index, step) {
+            if is_negative_zero(lexeme) {
+                let reason = format!("Negative zero \"{}\" may be read as 0 elsewhere", lexeme);
+                ctx.warnings
+                    .push((index, Fault::new(ErrorKind::NegativeZero, reason)));
+            }
+        }
+    }
+
+    if result.is_ok() && ctx.options.warn_precision_loss {
+        if let UTF8ReaderResult::Ok(lexeme) = document.look_ahead(index, step) {
+            if let Some(reason) = precision_loss(lexeme) {
+                ctx.warnings
+                    .push((index, Fault::new(ErrorKind::PrecisionLoss, reason)));
+            }
+        }
+    }
+
+    if result.is_ok() && ctx.options.warn_noncharacters {
+        check_noncharacter_escapes(document, index, step, ctx);
+    }
+
+    if result.is_ok() && ctx.wants_events() {
+        match scalar_value(document, index, step, ctx.options) {
+            Ok(value) => ctx.emit(|| JsonEvent::Value(value)),
+            Err((fault, offset)) => return (Err(fault), offset),
+        }
+    }
+
+    return (result, step);
+}
+
+/// Warns about every `\u` escape, or pair of them, in the valid string of
+/// `width` units at `start` that stands for a noncharacter. Does nothing for
+/// anything but a quoted string.
+fn check_noncharacter_escapes(
+    document: &UTF8Reader,
+    start: usize,
+    width: usize,
+    ctx: &mut Context,
+) {
+    let unit = |offset: usize| match document.look_ahead(start + offset, 1) {
+        UTF8ReaderResult::Ok(chr) => chr,
+        UTF8ReaderResult::OutOfBoundError(_) => "",
+    };
+    // The code of the `\uXXXX` escape at `offset`, if there is one.
+    let escape_at = |offset: usize| -> Option<u32> {
+        if unit(offset) != SP_REVERSE_SOLIDUS || unit(offset + 1) != SP_UNICODE {
+            return None;
+        }
+        return (2..6).try_fold(0, |code, i| {
+            Some(code * 16 + single_char(unit(i + offset))?.to_digit(16)?)
+        });
+    };
+
+    if !matches!(unit(0), SP_QUOTE | SP_APOSTROPHE) {
+        return;
+    }
 
-                    if let Ok(_) = result {
-                        state = State::PreValue;
-                        continue;
-                    } else {
-                        return (
-                            Err(String::from("Object key should be a valid string")),
-                            ptr,
-                        );
-                    }
-                }
-            },
-            State::PreValue => match chr {
-                ST_COLON => state = State::Value,
-                _ if is_insignificant_whitespace(chr) => {}
-                _ => {
-                    return (
-                        Err(format!("Invalid character after object key: \"{}\"", chr)),
-                        ptr,
-                    )
-                }
-            },
-            State::Value => match chr {
-                _ if is_insignificant_whitespace(chr) => {}
-                _ => {
-                    let (result, step) = validate_json_value(document, index, depth);
-                    ptr += step;
+    let mut offset = 1;
+    while offset + 1 < width {
+        if unit(offset) != SP_REVERSE_SOLIDUS {
+            offset += 1;
+            continue;
+        }
+        let high = match escape_at(offset) {
+            Some(code) => code,
+            None => {
+                // Past any other escape, so `\\u` is not mistaken for one.
+                offset += 2;
+                continue;
+            }
+        };
 
-                    if let Ok(_) = result {
-                        state = State::PostValue;
-                        continue;
-                    } else {
-                        return (result, ptr);
-                    }
-                }
-            },
-            State::PostValue => match chr {
-                ST_RCBRACKET => return (Ok(()), ptr + 1),
-                ST_COMMA => state = State::Key,
-                _ if is_insignificant_whitespace(chr) => {}
-                _ => {
-                    return (
-                        Err(format!("Invalid character after object value: \"{}\"", chr)),
-                        ptr,
-                    )
-                }
-            },
+        let (scalar, escape_width) = match escape_at(offset + 6) {
+            Some(low) if (0xD800..=0xDBFF).contains(&high) && (0xDC00..=0xDFFF).contains(&low) => {
+                (0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00), 12)
+            }
+            _ => (high, 6),
+        };
+
+        if is_noncharacter(scalar) {
+            let reason = format!("Escape decodes to the noncharacter U+{:04X}", scalar);
+            ctx.warnings
+                .push((start + offset, Fault::new(ErrorKind::Noncharacter, reason)));
         }
+        offset += escape_width;
+    }
+}
 
-        ptr += 1;
+/// U+FDD0 to U+FDEF, and the last two code points of every plane.
+fn is_noncharacter(code: u32) -> bool {
+    return (0xFDD0..=0xFDEF).contains(&code) || code & 0xFFFE == 0xFFFE;
+}
+
+/// Why a number may be read differently by a parser that uses `f64`, if it
+/// may. Anything that is not a number, such as `Infinity`, is left alone.
+fn precision_loss(lexeme: &str) -> Option<String> {
+    let is_number = lexeme.starts_with(|c: char| c.is_ascii_digit() || "-+.".contains(c))
+        && lexeme.bytes().any(|b| b.is_ascii_digit());
+    if !is_number {
+        return None;
+    }
+
+    if exceeds_safe_integer(lexeme) {
+        return Some(format!(
+            "Integer \"{}\" is beyond 2^53 and may lose precision elsewhere",
+            lexeme
+        ));
+    }
+
+    let is_integer = classify_number(lexeme).kind == NumberKind::Integer;
+    if !is_integer && !round_trips_f64(lexeme) {
+        return Some(format!(
+            "Number \"{}\" does not survive a round trip through a double",
+            lexeme
+        ));
     }
+    return None;
 }
 
-fn validate_array(
+/// Width of the value starting at `index`, or `None` if it is not valid.
+pub(crate) fn value_width(
     document: &UTF8Reader,
-    start: usize,
+    index: usize,
+    options: &ValidateOptions,
+) -> Option<usize> {
+    let (result, step) = scan_value(document, index, 0, options);
+    return result.ok().map(|_| step);
+}
+
+/// Validates the single value starting at `index`, nested `depth` levels deep.
+pub(crate) fn scan_value(
+    document: &UTF8Reader,
+    index: usize,
     depth: usize,
-) -> (Result<(), String>, usize) {
-    enum State {
-        Begin,
-        PreValue,
-        Value,
-        PostValue,
+    options: &ValidateOptions,
+) -> (Result<(), Fault>, usize) {
+    return validate_json_value(document, index, depth, &mut Context::new(options, None));
+}
+
+pub(crate) fn too_deep_fault() -> Fault {
+    let reason = String::from("Nested JSON value is too deep");
+    return Fault::new(ErrorKind::TooDeep, reason);
+}
+
+/// Rejects a container nested past `max_depth`, and warns once for each
+/// container that first crosses `warn_depth`, not again for those inside it.
+fn check_depth(start: usize, depth: usize, ctx: &mut Context) -> Result<(), Fault> {
+    if depth > ctx.options.depth_limit() {
+        return Err(too_deep_fault());
     }
 
-    if depth > MAX_DEPTH {
-        return (Err(format!("Nested JSON value is too deep")), 0);
+    if let Some(limit) = ctx.options.warn_depth {
+        if depth == limit + 1 {
+            let reason = format!(
+                "Nesting depth exceeds the warning threshold of {} levels",
+                limit
+            );
+            ctx.warnings
+                .push((start, Fault::new(ErrorKind::DeepNesting { limit }, reason)));
+        }
     }
+    return Ok(());
+}
 
-    let mut state: State = State::Begin;
-    let mut ptr = 0;
+pub(crate) fn validate_key(
+    document: &UTF8Reader,
+    start: usize,
+    options: &ValidateOptions,
+) -> (Result<(), Fault>, usize) {
+    return match document.look_ahead(start, 1) {
+        UTF8ReaderResult::Ok(SP_QUOTE) | UTF8ReaderResult::Ok(SP_APOSTROPHE) => {
+            validate_string(document, start, options)
+        }
+        _ if options.allow_unquoted_keys => validate_identifier(document, start),
+        _ => validate_string(document, start, options),
+    };
+}
 
-    loop {
-        let index = start + ptr;
+pub(crate) fn invalid_key(options: &ValidateOptions) -> Fault {
+    let reason = if options.allow_unquoted_keys {
+        "Object key should be a valid string or identifier"
+    } else {
+        "Object key should be a valid string"
+    };
+    return Fault::new(ErrorKind::InvalidKey, String::from(reason));
+}
 
-        let chr = match document.look_ahead(index, 1) {
-            UTF8ReaderResult::Ok(s) => s,
-            UTF8ReaderResult::OutOfBoundError(i) => {
-                return (Err(format!("Incomplete number value")), i)
-            }
+/// Scans an ECMAScript-style identifier: a letter, `_` or `$`, followed by
+/// letters, digits, `_` or `$`.
+fn validate_identifier(document: &UTF8Reader, start: usize) -> (Result<(), Fault>, usize) {
+    let mut ptr = 0;
+
+    while let UTF8ReaderResult::Ok(chr) = document.look_ahead(start + ptr, 1) {
+        let c = match chr.chars().next() {
+            Some(c) => c,
+            None => break,
+        };
+        let is_identifier = match c {
+            '_' | '$' => true,
+            _ if ptr == 0 => c.is_alphabetic(),
+            _ => c.is_alphanumeric(),
         };
+        if !is_identifier {
+            break;
+        }
+        ptr += 1;
+    }
 
-        match state {
-            State::Begin => {
-                if chr != ST_LSBRACKET {
-                    return (Err(String::from("Array should start with \"[\"")), ptr);
-                }
-                state = State::PreValue;
-            }
-            State::PreValue => match chr {
-                ST_RSBRACKET => return (Ok(()), ptr + 1),
-                _ if is_insignificant_whitespace(chr) => {}
-                _ => {
-                    let (result, step) = validate_json_value(document, index, depth);
-                    ptr += step;
+    if ptr == 0 {
+        return (
+            Err(Fault::new(
+                ErrorKind::InvalidKey,
+                String::from("Identifier should start with a letter, \"_\" or \"$\""),
+            )),
+            0,
+        );
+    }
 
-                    if let Ok(_) = result {
-                        state = State::PostValue;
-                        continue;
-                    } else {
-                        return (result, ptr);
-                    }
-                }
-            },
-            State::Value => match chr {
-                _ if is_insignificant_whitespace(chr) => {}
-                _ => {
-                    let (result, step) = validate_json_value(document, index, depth);
-                    ptr += step;
+    return (Ok(()), ptr);
+}
 
-                    if let Ok(_) = result {
-                        state = State::PostValue;
-                        continue;
-                    } else {
-                        return (result, ptr);
-                    }
-                }
-            },
-            State::PostValue => match chr {
-                ST_RSBRACKET => return (Ok(()), ptr + 1),
-                ST_COMMA => state = State::Value,
-                _ if is_insignificant_whitespace(chr) => {}
-                _ => return (Err(format!("Invalid character: \"{}\"", chr)), ptr),
-            },
+pub(crate) fn decode_key(
+    document: &UTF8Reader,
+    start: usize,
+    width: usize,
+    options: &ValidateOptions,
+) -> Result<String, (Fault, usize)> {
+    return match document.look_ahead(start, width) {
+        UTF8ReaderResult::Ok(lexeme)
+            if !lexeme.starts_with(SP_QUOTE) && !lexeme.starts_with(SP_APOSTROPHE) =>
+        {
+            Ok(String::from(lexeme))
         }
+        _ => decode_validated_string(document, start, width, options),
+    };
+}
 
-        ptr += 1;
-    }
+pub(crate) fn is_limit_reached(limit: Option<usize>, count: usize) -> bool {
+    return matches!(limit, Some(limit) if count >= limit);
+}
+
+pub(crate) fn too_many_object_members(options: &ValidateOptions) -> Fault {
+    let limit = options.max_object_members.unwrap_or_default();
+    return Fault::new(
+        ErrorKind::TooManyObjectMembers { limit },
+        format!("Object has more than {} members", limit),
+    );
+}
+
+pub(crate) fn too_many_nodes(options: &ValidateOptions) -> Fault {
+    let limit = options.max_total_nodes.unwrap_or_default();
+    return Fault::new(
+        ErrorKind::TooManyNodes { limit },
+        format!("Document has more than {} values", limit),
+    );
+}
+
+pub(crate) fn too_many_array_elements(options: &ValidateOptions) -> Fault {
+    let limit = options.max_array_elements.unwrap_or_default();
+    return Fault::new(
+        ErrorKind::TooManyArrayElements { limit },
+        format!("Array has more than {} elements", limit),
+    );
+}
+
+pub(crate) fn validate_number(
+    document: &UTF8Reader,
+    start: usize,
+    options: &ValidateOptions,
+) -> (Result<(), Fault>, usize) {
+    let (result, step) = scan_number(document, start, options);
+    return (
+        result.map_err(|fault| fault.classified(ErrorKind::InvalidNumber)),
+        step,
+    );
 }
 
-fn validate_number(document: &UTF8Reader, start: usize) -> (Result<(), String>, usize) {
+fn scan_number(
+    document: &UTF8Reader,
+    start: usize,
+    options: &ValidateOptions,
+) -> (Result<(), Fault>, usize) {
     enum State {
         Begin,
-        LeadingMinus,
+        LeadingSign,
         LeadingZero,
         Integer,
         PendingFraction,
+        // A decimal point with no integer part before it, as in `.5`
+        BareFraction,
         Fraction,
         ExponentSign, // + or -
         PendingExponent,
         Exponent,
+        PendingHexadecimal,
+        Hexadecimal,
     }
 
     fn is_valid_demical_number(chr: &str, non_zero: bool) -> bool {
-        let c = chr.chars().nth(0).unwrap();
-        match c {
-            '1'..='9' => true,
-            '0' => !non_zero,
+        match single_char(chr) {
+            Some('1'..='9') => true,
+            Some('0') => !non_zero,
             _ => false,
         }
     }
 
-    fn is_end_of_number(chr: &str) -> bool {
-        match chr {
-            ST_COMMA | ST_RCBRACKET | ST_RSBRACKET => true,
-            _ if is_insignificant_whitespace(chr) => true,
-            _ => false,
-        }
+    fn is_hex_digit(chr: &str) -> bool {
+        matches!(single_char(chr), Some(c) if c.is_ascii_hexdigit())
     }
 
+    let sign = match document.look_ahead(start, 1) {
+        UTF8ReaderResult::Ok(SP_PLUS) => "plus",
+        _ => "minus",
+    };
+
     let mut state: State = State::Begin;
     let mut ptr = 0;
+    let mut exponent_digits = 0;
 
     loop {
         let index = start + ptr;
@@ -350,40 +1530,76 @@ fn validate_number(document: &UTF8Reader, start: usize) -> (Result<(), String>,
         let chr = match document.look_ahead(index, 1) {
             UTF8ReaderResult::Ok(s) => s,
             UTF8ReaderResult::OutOfBoundError(tail_offset) => match state {
-                State::LeadingZero | State::Integer | State::Fraction | State::Exponent => {
-                    return (Ok(()), ptr)
+                State::LeadingZero
+                | State::Integer
+                | State::Fraction
+                | State::Exponent
+                | State::Hexadecimal => return (Ok(()), ptr),
+                State::PendingFraction if options.allow_bare_decimal => return (Ok(()), ptr),
+                State::BareFraction => {
+                    return (Err(String::from("Number has no digits").into()), ptr)
+                }
+                State::LeadingSign => {
+                    let reason = format!("Number has no digits after leading {}", sign);
+                    return (Err(reason.into()), ptr);
+                }
+                _ => {
+                    return (
+                        Err(String::from("Incomplete number value").into()),
+                        tail_offset,
+                    )
                 }
-                _ => return (Err(format!("Incomplete number value")), tail_offset),
             },
         };
 
+        if chr == NUL || (is_unicode_whitespace(chr) && !options.allow_unicode_whitespace) {
+            return (Err(stray_character_fault(chr)), ptr);
+        }
+
         match state {
             State::Begin => match chr {
-                SP_MINUS => state = State::LeadingMinus,
+                SP_MINUS => state = State::LeadingSign,
+                SP_PLUS if options.allow_leading_plus => state = State::LeadingSign,
+                SP_DECIMAL_POINT if options.allow_bare_decimal => state = State::BareFraction,
                 "0" => state = State::LeadingZero,
                 _ if is_valid_demical_number(chr, true) => state = State::Integer,
-                _ => return (Err(format!("Invalid number leading: {:?}", chr)), ptr),
+                _ => {
+                    return (
+                        Err(format!("Invalid number leading: {:?}", chr).into()),
+                        ptr,
+                    )
+                }
             },
-            State::LeadingMinus => match chr {
+            State::LeadingSign => match chr {
                 "0" => state = State::LeadingZero,
+                SP_DECIMAL_POINT if options.allow_bare_decimal => state = State::BareFraction,
                 _ if is_valid_demical_number(chr, true) => state = State::Integer,
+                _ if is_end_of_number(chr, options) => {
+                    let reason = format!("Number has no digits after leading {}", sign);
+                    return (Err(reason.into()), ptr);
+                }
                 _ => {
                     return (
-                        Err(format!("Invalid character after leading minus: {:?}", chr)),
+                        Err(format!("Invalid character after leading {}: {:?}", sign, chr).into()),
                         ptr,
-                    )
+                    );
                 }
             },
             State::LeadingZero => match chr {
                 SP_DECIMAL_POINT => state = State::PendingFraction,
                 "e" | "E" => state = State::ExponentSign,
+                "x" | "X" if options.allow_hex_numbers => state = State::PendingHexadecimal,
+                _ if options.allow_leading_zeros && is_valid_demical_number(chr, false) => {
+                    state = State::Integer
+                }
                 _ if is_valid_demical_number(chr, false) => {
-                    return (Err(format!("Leading zeros are not allowed")), ptr)
+                    let reason = String::from("Leading zeros are not allowed");
+                    return (Err(Fault::new(ErrorKind::LeadingZero, reason)), ptr);
                 }
-                _ if is_end_of_number(chr) => return (Ok(()), ptr),
+                _ if is_end_of_number(chr, options) => return (Ok(()), ptr),
                 _ => {
                     return (
-                        Err(format!("Invalid character after leading zero: {:?}", chr)),
+                        Err(format!("Invalid character after leading zero: {:?}", chr).into()),
                         ptr,
                     )
                 }
@@ -392,19 +1608,33 @@ fn validate_number(document: &UTF8Reader, start: usize) -> (Result<(), String>,
                 SP_DECIMAL_POINT => state = State::PendingFraction,
                 "e" | "E" => state = State::ExponentSign,
                 _ if is_valid_demical_number(chr, false) => {}
-                _ if is_end_of_number(chr) => return (Ok(()), ptr),
+                _ if is_end_of_number(chr, options) => return (Ok(()), ptr),
                 _ => {
                     return (
-                        Err(format!("Invalid character in interger part: {:?}", chr)),
+                        Err(format!("Invalid character in interger part: {:?}", chr).into()),
                         ptr,
                     )
                 }
             },
-            State::PendingFraction => match chr {
+            State::PendingFraction | State::BareFraction => match chr {
                 _ if is_valid_demical_number(chr, false) => state = State::Fraction,
+                "e" | "E"
+                    if matches!(state, State::PendingFraction) && options.allow_bare_decimal =>
+                {
+                    state = State::ExponentSign
+                }
+                _ if matches!(state, State::PendingFraction)
+                    && options.allow_bare_decimal
+                    && is_end_of_number(chr, options) =>
+                {
+                    return (Ok(()), ptr)
+                }
+                _ if matches!(state, State::BareFraction) && is_end_of_number(chr, options) => {
+                    return (Err(String::from("Number has no digits").into()), ptr)
+                }
                 _ => {
                     return (
-                        Err(format!("Invalid character after demical point: {:?}", chr)),
+                        Err(format!("Invalid character after demical point: {:?}", chr).into()),
                         ptr,
                     )
                 }
@@ -412,10 +1642,10 @@ fn validate_number(document: &UTF8Reader, start: usize) -> (Result<(), String>,
             State::Fraction => match chr {
                 "e" | "E" => state = State::ExponentSign,
                 _ if is_valid_demical_number(chr, false) => {}
-                _ if is_end_of_number(chr) => return (Ok(()), ptr),
+                _ if is_end_of_number(chr, options) => return (Ok(()), ptr),
                 _ => {
                     return (
-                        Err(format!("Invalid character in fraction part: {:?}", chr)),
+                        Err(format!("Invalid character in fraction part: {:?}", chr).into()),
                         ptr,
                     )
                 }
@@ -425,7 +1655,7 @@ fn validate_number(document: &UTF8Reader, start: usize) -> (Result<(), String>,
                 _ if is_valid_demical_number(chr, false) => state = State::Exponent,
                 _ => {
                     return (
-                        Err(format!("Invalid character in exponent part: {:?}", chr)),
+                        Err(format!("Invalid character in exponent part: {:?}", chr).into()),
                         ptr,
                     )
                 }
@@ -434,28 +1664,66 @@ fn validate_number(document: &UTF8Reader, start: usize) -> (Result<(), String>,
                 _ if is_valid_demical_number(chr, false) => state = State::Exponent,
                 _ => {
                     return (
-                        Err(format!("Invalid character in exponent part: {:?}", chr)),
+                        Err(format!("Invalid character in exponent part: {:?}", chr).into()),
                         ptr,
                     )
                 }
             },
             State::Exponent => match chr {
                 _ if is_valid_demical_number(chr, false) => {}
-                _ if is_end_of_number(chr) => return (Ok(()), ptr),
+                _ if is_end_of_number(chr, options) => return (Ok(()), ptr),
+                _ => {
+                    return (
+                        Err(format!("Invalid character in exponent part: {:?}", chr).into()),
+                        ptr,
+                    )
+                }
+            },
+            State::PendingHexadecimal => match chr {
+                _ if is_hex_digit(chr) => state = State::Hexadecimal,
+                _ => {
+                    return (
+                        Err(
+                            format!("Invalid character after hexadecimal prefix: {:?}", chr).into(),
+                        ),
+                        ptr,
+                    )
+                }
+            },
+            State::Hexadecimal => match chr {
+                _ if is_hex_digit(chr) => {}
+                _ if is_end_of_number(chr, options) => return (Ok(()), ptr),
                 _ => {
                     return (
-                        Err(format!("Invalid character in exponent part: {:?}", chr)),
+                        Err(format!("Invalid character in hexadecimal number: {:?}", chr).into()),
                         ptr,
                     )
                 }
             },
         }
 
+        // Only a digit leaves the scanner in `Exponent`.
+        if matches!(state, State::Exponent) && (exponent_digits > 0 || chr != "0") {
+            if is_limit_reached(options.max_exponent_digits, exponent_digits) {
+                let limit = options.max_exponent_digits.unwrap_or_default();
+                let reason = format!("Exponent has more than {} digits", limit);
+                return (
+                    Err(Fault::new(ErrorKind::ExponentTooLong { limit }, reason)),
+                    ptr,
+                );
+            }
+            exponent_digits += 1;
+        }
+
         ptr += 1;
     }
 }
 
-fn validate_string(document: &UTF8Reader, start: usize) -> (Result<(), String>, usize) {
+pub(crate) fn validate_string(
+    document: &UTF8Reader,
+    start: usize,
+    options: &ValidateOptions,
+) -> (Result<(), Fault>, usize) {
     enum State {
         Begin,
         PlainText,
@@ -464,55 +1732,112 @@ fn validate_string(document: &UTF8Reader, start: usize) -> (Result<(), String>,
     }
 
     fn is_control_character(chr: &str) -> bool {
-        let c = chr.chars().nth(0).unwrap();
-        match c {
-            '\u{0000}'..='\u{001F}' => true,
-            _ => false,
-        }
+        matches!(chr.chars().next(), Some('\u{0000}'..='\u{001F}'))
     }
 
     fn is_hex_digit(chr: &str) -> bool {
-        let c = chr.chars().nth(0).unwrap();
-        match c {
-            '0'..='9' | 'A'..='F' | 'a'..='f' => true,
-            _ => false,
-        }
+        matches!(single_char(chr), Some(c) if c.is_ascii_hexdigit())
     }
 
     let mut state: State = State::Begin;
     let mut ptr = 0;
     let mut unicode_len = 0;
+    let mut escapes = 0;
+    let mut quote = SP_QUOTE;
 
     loop {
         let index = start + ptr;
 
         let chr = match document.look_ahead(index, 1) {
             UTF8ReaderResult::Ok(s) => s,
-            UTF8ReaderResult::OutOfBoundError(i) => {
-                return (Err(format!("Incomplete string value")), i)
+            UTF8ReaderResult::OutOfBoundError(_) => {
+                let reason = String::from("Incomplete string value");
+                let fault = Fault::new(ErrorKind::UnterminatedString, reason);
+                return (Err(fault.opened_at(start)), ptr);
             }
         };
 
+        let is_content = match state {
+            State::Begin => false,
+            State::PlainText => chr != quote,
+            _ => true,
+        };
+        if is_content && is_limit_reached(options.max_string_length, ptr - 1) {
+            let limit = options.max_string_length.unwrap_or_default();
+            let reason = format!("String is longer than {} characters", limit);
+            return (
+                Err(Fault::new(ErrorKind::StringTooLong { limit }, reason)),
+                ptr,
+            );
+        }
+
         match state {
             State::Begin => {
-                if chr != SP_QUOTE {
-                    return (Err(String::from("String value should start with \"")), ptr);
+                match chr {
+                    SP_QUOTE => {}
+                    SP_APOSTROPHE if options.allow_single_quotes => quote = SP_APOSTROPHE,
+                    _ => {
+                        return (
+                            Err(String::from("String value should start with \"").into()),
+                            ptr,
+                        )
+                    }
                 }
 
                 state = State::PlainText;
             }
             State::PlainText => match chr {
-                SP_QUOTE => return (Ok(()), ptr + 1),
-                SP_REVERSE_SOLIDUS => state = State::Escaping,
+                _ if chr == quote => return (Ok(()), ptr + 1),
+                SP_REVERSE_SOLIDUS if is_limit_reached(options.max_escapes_per_string, escapes) => {
+                    let limit = options.max_escapes_per_string.unwrap_or_default();
+                    let reason = format!("String has more than {} escape sequences", limit);
+                    return (
+                        Err(Fault::new(ErrorKind::TooManyEscapes { limit }, reason)),
+                        ptr,
+                    );
+                }
+                SP_REVERSE_SOLIDUS => {
+                    escapes += 1;
+                    state = State::Escaping;
+                }
+                SP_SOLIDUS if options.solidus_policy == SolidusPolicy::RequireEscaped => {
+                    return (
+                        Err(Fault::new(
+                            ErrorKind::UnescapedCharacter,
+                            String::from("Solidus should be escaped as \"\\/\""),
+                        )),
+                        ptr,
+                    )
+                }
                 _ if is_control_character(chr) => {
                     return (
-                        Err(format!("Control character \"{}\" should be escaped", chr)),
+                        Err(Fault::new(
+                            ErrorKind::UnescapedCharacter,
+                            format!("Control character \"{}\" should be escaped", chr),
+                        )),
                         ptr,
                     )
                 }
+                "\u{2028}" | "\u{2029}" if options.forbid_js_unsafe_separators => {
+                    let reason = format!(
+                        "Raw U+{:04X} should be escaped as \"\\u{:04x}\" so the document can be embedded in JavaScript",
+                        single_char(chr).map_or(0, |c| c as u32),
+                        single_char(chr).map_or(0, |c| c as u32)
+                    );
+                    return (Err(Fault::new(ErrorKind::UnescapedCharacter, reason)), ptr);
+                }
                 _ => state = State::PlainText,
             },
             State::Escaping => match chr {
+                SP_SOLIDUS if options.solidus_policy == SolidusPolicy::ForbidEscaped => {
+                    return (
+                        Err(Fault::new(
+                            ErrorKind::InvalidEscape,
+                            String::from("Solidus should not be escaped"),
+                        )),
+                        ptr,
+                    )
+                }
                 SP_QUOTE
                 | SP_REVERSE_SOLIDUS
                 | SP_SOLIDUS
@@ -521,14 +1846,29 @@ fn validate_string(document: &UTF8Reader, start: usize) -> (Result<(), String>,
                 | SP_LINE_FEED
                 | SP_CARRIAGE_RETURN
                 | SP_CHARACTER_TABULATION => state = State::PlainText,
+                SP_APOSTROPHE if options.allow_single_quotes => state = State::PlainText,
                 SP_UNICODE => {
                     state = State::Unicode;
                 }
-                _ => return (Err(format!("Invalid escaping character: {:?}", chr)), ptr),
+                _ => {
+                    return (
+                        Err(Fault::new(
+                            ErrorKind::InvalidEscape,
+                            format!("Invalid escaping character: {:?}", chr),
+                        )),
+                        ptr,
+                    )
+                }
             },
             State::Unicode => {
                 if !is_hex_digit(chr) {
-                    return (Err(format!("Invalid unicode sequence: {:?}", chr)), ptr);
+                    return (
+                        Err(Fault::new(
+                            ErrorKind::InvalidEscape,
+                            format!("Invalid unicode sequence: {:?}", chr),
+                        )),
+                        ptr,
+                    );
                 }
 
                 unicode_len += 1;
@@ -543,75 +1883,223 @@ fn validate_string(document: &UTF8Reader, start: usize) -> (Result<(), String>,
     }
 }
 
-fn validate_true(document: &UTF8Reader, start: usize) -> (Result<(), String>, usize) {
-    let segment = document.look_ahead(start, 4);
-    match segment {
-        UTF8ReaderResult::OutOfBoundError(i) => {
-            return (Err(format!("Incomplete literal name \"true\"",)), i);
-        }
-        UTF8ReaderResult::Ok(name) => {
-            if name == LN_TRUE {
-                return (Ok(()), 4);
-            } else {
-                return (
-                    Err(format!(
-                        "It seems to be the plain value \"true\", but got \"{}\"",
-                        name
-                    )),
-                    4,
-                );
-            }
-        }
+pub(crate) fn validate_true(document: &UTF8Reader, start: usize) -> (Result<(), Fault>, usize) {
+    return validate_literal(document, start, LN_TRUE);
+}
+
+pub(crate) fn validate_false(document: &UTF8Reader, start: usize) -> (Result<(), Fault>, usize) {
+    return validate_literal(document, start, LN_FALSE);
+}
+
+pub(crate) fn validate_null(document: &UTF8Reader, start: usize) -> (Result<(), Fault>, usize) {
+    return validate_literal(document, start, LN_NULL);
+}
+
+/// Matches the whole run of letters and digits at `start` against `name`,
+/// so a mistyped literal is reported as written, e.g. `tru` or `truer`.
+fn validate_literal(document: &UTF8Reader, start: usize, name: &str) -> (Result<(), Fault>, usize) {
+    let token = word_at(document, start);
+    if token == name {
+        return (Ok(()), name.len());
     }
+
+    return (
+        Err(Fault::new(
+            ErrorKind::InvalidLiteral,
+            format!("Expected \"{}\", but found \"{}\"", name, token),
+        )),
+        0,
+    );
 }
 
-fn validate_false(document: &UTF8Reader, start: usize) -> (Result<(), String>, usize) {
-    let segment = document.look_ahead(start, 5);
-    match segment {
-        UTF8ReaderResult::OutOfBoundError(i) => {
-            return (Err(format!("Incomplete literal name \"false\"",)), i);
-        }
-        UTF8ReaderResult::Ok(name) => {
-            if name == LN_FALSE {
-                return (Ok(()), 5);
-            } else {
-                return (
-                    Err(format!(
-                        "It seems to be the plain value \"false\", but got \"{}\"",
-                        name
-                    )),
-                    5,
-                );
-            }
+/// The run of letters and digits starting at `start`.
+fn word_at<'a>(document: &UTF8Reader<'a>, start: usize) -> &'a str {
+    let mut width = 0;
+    while let UTF8ReaderResult::Ok(chr) = document.look_ahead(start + width, 1) {
+        if !matches!(single_char(chr), Some(c) if c.is_alphanumeric()) {
+            break;
         }
+        width += 1;
     }
+
+    return match document.look_ahead(start, width) {
+        UTF8ReaderResult::Ok(word) => word,
+        UTF8ReaderResult::OutOfBoundError(_) => "",
+    };
+}
+
+fn is_miscased_literal(word: &str) -> bool {
+    return [LN_TRUE, LN_FALSE, LN_NULL]
+        .iter()
+        .any(|name| word.eq_ignore_ascii_case(name));
 }
 
-fn validate_null(document: &UTF8Reader, start: usize) -> (Result<(), String>, usize) {
-    let segment = document.look_ahead(start, 4);
-    match segment {
-        UTF8ReaderResult::OutOfBoundError(i) => {
-            return (Err(format!("Incomplete literal name \"null\"",)), i);
+/// Scans `Infinity` or `-Infinity`, reporting a mistyped name as written
+/// the way `validate_literal` does.
+fn validate_infinity(document: &UTF8Reader, start: usize) -> (Result<(), Fault>, usize) {
+    return match document.look_ahead(start, 1) {
+        UTF8ReaderResult::Ok(SP_MINUS) => {
+            let (result, step) = validate_literal(document, start + 1, LN_INFINITY);
+            (result, step + 1)
         }
-        UTF8ReaderResult::Ok(name) => {
-            if name == LN_NULL {
-                return (Ok(()), 4);
-            } else {
-                return (
-                    Err(format!(
-                        "It seems to be the plain value \"null\", but got \"{}\"",
-                        name
-                    )),
-                    4,
-                );
-            }
+        _ => validate_literal(document, start, LN_INFINITY),
+    };
+}
+
+fn validate_nan(document: &UTF8Reader, start: usize) -> (Result<(), Fault>, usize) {
+    return validate_literal(document, start, LN_NAN);
+}
+
+/// Builds the value of a scalar that has already been validated.
+fn scalar_value(
+    document: &UTF8Reader,
+    start: usize,
+    width: usize,
+    options: &ValidateOptions,
+) -> Result<JsonValue, (Fault, usize)> {
+    let lexeme = match document.look_ahead(start, width) {
+        UTF8ReaderResult::Ok(s) => s,
+        UTF8ReaderResult::OutOfBoundError(_) => "",
+    };
+
+    return match lexeme {
+        LN_TRUE => Ok(JsonValue::Bool(true)),
+        LN_FALSE => Ok(JsonValue::Bool(false)),
+        LN_NULL => Ok(JsonValue::Null),
+        _ if lexeme.starts_with(SP_QUOTE) || lexeme.starts_with(SP_APOSTROPHE) => {
+            decode_validated_string(document, start, width, options).map(JsonValue::String)
+        }
+        _ => Ok(JsonValue::Number(JsonNumber::new(lexeme))),
+    };
+}
+
+pub(crate) fn is_insignificant_whitespace(chr: &str) -> bool {
+    matches!(
+        chr,
+        WS_CHARACTER_TABULATION | WS_LINE_FEED | WS_CARRIAGE_RETURN | WS_SPACE
+    )
+}
+
+/// Whether `chr` ends a number or literal name. A bracket or quote that opens
+/// the next value also ends this one, so `1"a"` and `1[2]` split into two
+/// values in a stream.
+pub(crate) fn is_end_of_number(chr: &str, options: &ValidateOptions) -> bool {
+    return matches!(
+        chr,
+        ST_COMMA | ST_RCBRACKET | ST_RSBRACKET | ST_LCBRACKET | ST_LSBRACKET | SP_QUOTE
+    ) || (chr == SP_APOSTROPHE && options.allow_single_quotes)
+        || is_whitespace(chr, options);
+}
+
+/// Whitespace allowed between tokens under the given options.
+pub(crate) fn is_whitespace(chr: &str, options: &ValidateOptions) -> bool {
+    return is_insignificant_whitespace(chr)
+        || (options.allow_unicode_whitespace && is_unicode_whitespace(chr));
+}
+
+/// The index of the first character at or after `start` that is not
+/// whitespace.
+pub(crate) fn skip_whitespace(
+    document: &UTF8Reader,
+    start: usize,
+    options: &ValidateOptions,
+) -> usize {
+    let mut ptr = start;
+    while let UTF8ReaderResult::Ok(chr) = document.look_ahead(ptr, 1) {
+        if !is_whitespace(chr, options) {
+            break;
         }
+        ptr += 1;
     }
+    return ptr;
 }
 
-fn is_insignificant_whitespace(chr: &str) -> bool {
-    match chr {
-        WS_CHARACTER_TABULATION | WS_LINE_FEED | WS_CARRIAGE_RETURN | WS_SPACE => true,
-        _ => false,
+/// Whitespace and invisible spacing that JSON does not allow between
+/// tokens, but that often slips into hand-edited or pasted documents.
+fn is_unicode_whitespace(chr: &str) -> bool {
+    return unicode_whitespace_name(chr).is_some();
+}
+
+/// The scalar making up `chr`, or `None` if a combining mark is attached.
+fn single_char(chr: &str) -> Option<char> {
+    let mut chars = chr.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() {
+        return None;
     }
+    return Some(c);
+}
+
+fn unicode_whitespace_name(chr: &str) -> Option<&'static str> {
+    return match single_char(chr)? {
+        '\u{000B}' => Some("line tabulation"),
+        '\u{000C}' => Some("form feed"),
+        '\u{0085}' => Some("next line"),
+        '\u{00A0}' => Some("no-break space"),
+        '\u{1680}' => Some("ogham space mark"),
+        '\u{2000}' => Some("en quad"),
+        '\u{2001}' => Some("em quad"),
+        '\u{2002}' => Some("en space"),
+        '\u{2003}' => Some("em space"),
+        '\u{2004}' => Some("three-per-em space"),
+        '\u{2005}' => Some("four-per-em space"),
+        '\u{2006}' => Some("six-per-em space"),
+        '\u{2007}' => Some("figure space"),
+        '\u{2008}' => Some("punctuation space"),
+        '\u{2009}' => Some("thin space"),
+        '\u{200A}' => Some("hair space"),
+        '\u{2028}' => Some("line separator"),
+        '\u{2029}' => Some("paragraph separator"),
+        '\u{202F}' => Some("narrow no-break space"),
+        '\u{205F}' => Some("medium mathematical space"),
+        '\u{3000}' => Some("ideographic space"),
+        '\u{FEFF}' => Some("byte order mark"),
+        _ => None,
+    };
+}
+
+pub(crate) fn unicode_whitespace_fault(chr: &str) -> Fault {
+    let c = chr.chars().next().unwrap_or_default();
+    let name = unicode_whitespace_name(chr).unwrap_or("whitespace");
+    let reason = format!(
+        "Unexpected U+{:04X} ({}); only space, tab, line feed and carriage return may separate tokens, so remove it",
+        c as u32, name
+    );
+    return Fault::new(ErrorKind::StrayCharacter, reason);
+}
+
+/// Anything between tokens that is not whitespace JSON allows, and would
+/// otherwise be reported as an unknown character.
+pub(crate) fn is_stray_character(chr: &str) -> bool {
+    return chr == NUL || is_unicode_whitespace(chr);
+}
+
+/// NULs and byte order marks in the middle of a document usually come from
+/// a broken transfer or concatenated files, so they are named as such.
+pub(crate) fn stray_character_fault(chr: &str) -> Fault {
+    return match single_char(chr).and_then(stray_character_reason) {
+        Some(reason) => Fault::new(ErrorKind::StrayCharacter, String::from(reason)),
+        None => unicode_whitespace_fault(chr),
+    };
+}
+
+/// The fault for a `,` right before the `closing` bracket of its container.
+pub(crate) fn trailing_comma_fault(closing: &str) -> Fault {
+    let reason = format!("Trailing comma before \"{}\"", closing);
+    return Fault::new(ErrorKind::TrailingComma, reason);
+}
+
+/// The fault for input that ends inside a container, naming what the
+/// container was waiting for.
+pub(crate) fn end_of_input_fault(expected: &str) -> Fault {
+    let reason = format!("Unexpected end of input, expected {}", expected);
+    return Fault::new(ErrorKind::UnexpectedEnd, reason);
+}
+
+pub(crate) fn stray_character_reason(c: char) -> Option<&'static str> {
+    return match c {
+        '\u{0000}' => Some("Unexpected NUL byte"),
+        '\u{FEFF}' => Some("Unexpected byte order mark"),
+        _ => None,
+    };
 }
@@ -0,0 +1,456 @@
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+use core::convert::TryFrom;
+use core::mem;
+
+/// `==` compares values exactly as parsed, so member order and number
+/// lexemes must match. See `semantic_eq` for a looser comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(JsonNumber),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub fn is_null(&self) -> bool {
+        return matches!(self, JsonValue::Null);
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        return match self {
+            JsonValue::Bool(b) => Some(*b),
+            _ => None,
+        };
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        return match self {
+            JsonValue::Number(n) => Some(n.as_f64()),
+            _ => None,
+        };
+    }
+
+    /// See `JsonNumber::as_i64`; also `None` for anything but a number.
+    pub fn as_i64(&self) -> Option<i64> {
+        return match self {
+            JsonValue::Number(n) => n.as_i64(),
+            _ => None,
+        };
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        return match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        };
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        return match self {
+            JsonValue::Array(elements) => Some(elements),
+            _ => None,
+        };
+    }
+
+    pub fn as_object(&self) -> Option<&Vec<(String, JsonValue)>> {
+        return match self {
+            JsonValue::Object(members) => Some(members),
+            _ => None,
+        };
+    }
+
+    /// The value of the first member named `key`, if this is an object.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        return self
+            .as_object()?
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value);
+    }
+
+    /// The element at `index`, if this is an array.
+    pub fn get_index(&self, index: usize) -> Option<&JsonValue> {
+        return self.as_array()?.get(index);
+    }
+
+    /// Moves the value out, leaving `Null` in its place.
+    pub fn take(&mut self) -> JsonValue {
+        return mem::replace(self, JsonValue::Null);
+    }
+
+    /// Replaces the value of the first member named `key` and returns the
+    /// old one, or appends a new member and returns `None`. Nothing is
+    /// inserted unless this is an object.
+    pub fn insert(&mut self, key: &str, value: JsonValue) -> Option<JsonValue> {
+        let members = match self {
+            JsonValue::Object(members) => members,
+            _ => return None,
+        };
+        return match members.iter_mut().find(|(name, _)| name == key) {
+            Some((_, member)) => Some(mem::replace(member, value)),
+            None => {
+                members.push((String::from(key), value));
+                None
+            }
+        };
+    }
+
+    /// Removes the first member named `key`, if this is an object. Later
+    /// members sharing the key stay.
+    pub fn remove(&mut self, key: &str) -> Option<JsonValue> {
+        let members = match self {
+            JsonValue::Object(members) => members,
+            _ => return None,
+        };
+        let position = members.iter().position(|(name, _)| name == key)?;
+        return Some(members.remove(position).1);
+    }
+
+    /// Appends `value` if this is an array, returning whether it did.
+    pub fn push(&mut self, value: JsonValue) -> bool {
+        return match self {
+            JsonValue::Array(elements) => {
+                elements.push(value);
+                true
+            }
+            _ => false,
+        };
+    }
+
+    /// Removes the element at `index`, if this is an array that long.
+    pub fn remove_index(&mut self, index: usize) -> Option<JsonValue> {
+        return match self {
+            JsonValue::Array(elements) if index < elements.len() => Some(elements.remove(index)),
+            _ => None,
+        };
+    }
+
+    /// Sorts the members of every object in the tree by key, comparing
+    /// Unicode scalar values. Members sharing a key keep their order, and
+    /// arrays keep theirs.
+    pub fn sort_keys(&mut self) {
+        match self {
+            JsonValue::Array(elements) => elements.iter_mut().for_each(JsonValue::sort_keys),
+            JsonValue::Object(members) => {
+                members.sort_by(|(x, _), (y, _)| x.cmp(y));
+                members
+                    .iter_mut()
+                    .for_each(|(_, member)| member.sort_keys());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Compares two values the way most consumers read them: object members in
+/// any order, array elements in order, and numbers by exact value, so `1.0`
+/// equals `1` and `0x10` equals `16`. Members sharing a key are matched in
+/// the order they appear.
+pub fn semantic_eq(a: &JsonValue, b: &JsonValue) -> bool {
+    return match (a, b) {
+        (JsonValue::Number(x), JsonValue::Number(y)) => same_number(x.as_str(), y.as_str()),
+        (JsonValue::Array(xs), JsonValue::Array(ys)) => {
+            xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| semantic_eq(x, y))
+        }
+        (JsonValue::Object(xs), JsonValue::Object(ys)) => {
+            if xs.len() != ys.len() {
+                return false;
+            }
+            let xs = sorted_members(xs);
+            let ys = sorted_members(ys);
+            xs.iter()
+                .zip(&ys)
+                .all(|((kx, x), (ky, y))| kx == ky && semantic_eq(x, y))
+        }
+        _ => a == b,
+    };
+}
+
+/// Compares the digits of two lexemes rather than their `f64`s, so that
+/// integers past 2^53 and long fractions keep every digit. `-0` equals `0`.
+/// `Infinity`, `NaN` and numbers whose exponent leaves the `i64` range only
+/// equal the same lexeme.
+fn same_number(x: &str, y: &str) -> bool {
+    return match (exact_value(x), exact_value(y)) {
+        (Some(x), Some(y)) => x == y,
+        _ => x == y,
+    };
+}
+
+/// Whether a lexeme is negative, and its significand; see `significand`.
+fn exact_value(lexeme: &str) -> Option<(bool, String, i64)> {
+    let decimal = decimal_lexeme(lexeme)?;
+    let (digits, exponent) = significand(&decimal)?;
+    let negative = decimal.starts_with('-') && !digits.is_empty();
+    return Some((negative, digits, exponent));
+}
+
+/// Members ordered by key, keeping document order among equal keys.
+fn sorted_members(members: &[(String, JsonValue)]) -> Vec<&(String, JsonValue)> {
+    let mut sorted: Vec<&(String, JsonValue)> = members.iter().collect();
+    sorted.sort_by(|(x, _), (y, _)| x.cmp(y));
+    return sorted;
+}
+
+/// A number kept as its source lexeme, so no precision is lost before the
+/// caller decides how to interpret it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonNumber {
+    lexeme: String,
+}
+
+impl JsonNumber {
+    pub fn new(lexeme: &str) -> Self {
+        return JsonNumber {
+            lexeme: String::from(lexeme),
+        };
+    }
+
+    pub fn as_str(&self) -> &str {
+        return &self.lexeme;
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        if let Some(value) = parse_hexadecimal(&self.lexeme) {
+            return value as f64;
+        }
+        return self.lexeme.parse().unwrap_or(f64::NAN);
+    }
+
+    /// The value of an integer lexeme, decimal or hexadecimal, if it fits in
+    /// an `i64`. Fractions and exponents give `None`. Leading zeros, which
+    /// `allow_leading_zeros` lets through, are ignored.
+    pub fn as_i64(&self) -> Option<i64> {
+        if let Some(value) = parse_hexadecimal(&self.lexeme) {
+            return i64::try_from(value).ok();
+        }
+        if self.classify().kind != NumberKind::Integer {
+            return None;
+        }
+        return self.lexeme.trim_start_matches('+').parse().ok();
+    }
+
+    /// True for `-0`, `-0.0`, `-0e5` and the like, which compare equal to `0`
+    /// as `f64` but may matter to downstream systems.
+    pub fn is_negative_zero(&self) -> bool {
+        return is_negative_zero(&self.lexeme);
+    }
+
+    pub fn classify(&self) -> NumberClass {
+        return classify_number(&self.lexeme);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberKind {
+    /// No fraction and no exponent, such as `42`, `-7` or `0xFF`.
+    Integer,
+    /// A fraction and no exponent, such as `1.5` or `5.`, and the JSON5
+    /// `Infinity` and `NaN`.
+    Float,
+    /// An exponent, with or without a fraction, such as `1e9` or `2.5E-3`.
+    Scientific,
+}
+
+/// The shape of a number, as far as it can be told from its lexeme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberClass {
+    pub kind: NumberKind,
+    pub negative: bool,
+    pub has_fraction: bool,
+    pub has_exponent: bool,
+}
+
+/// Tells integers from floats the same way validation and parsing do, so a
+/// caller can inspect a number without converting it. The lexeme should be
+/// one the validator accepted; a hexadecimal `0xE` has no exponent, and
+/// `-0` is negative.
+pub fn classify_number(lexeme: &str) -> NumberClass {
+    let negative = lexeme.starts_with('-');
+    let unsigned = lexeme.trim_start_matches(['-', '+']);
+    let is_hexadecimal = hexadecimal_digits(lexeme).is_some();
+    let is_decimal =
+        !is_hexadecimal && unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.');
+
+    let has_fraction = is_decimal && unsigned.contains('.');
+    let has_exponent = is_decimal && unsigned.contains(['e', 'E']);
+    let kind = if has_exponent {
+        NumberKind::Scientific
+    } else if has_fraction || !(is_decimal || is_hexadecimal) {
+        NumberKind::Float
+    } else {
+        NumberKind::Integer
+    };
+
+    return NumberClass {
+        kind,
+        negative,
+        has_fraction,
+        has_exponent,
+    };
+}
+
+pub(crate) fn is_negative_zero(lexeme: &str) -> bool {
+    let mantissa = lexeme.split(['e', 'E']).next().unwrap_or_default();
+    return lexeme.starts_with('-') && mantissa[1..].chars().all(|c| c == '0' || c == '.');
+}
+
+/// True for integers larger in magnitude than 2^53, beyond which not every
+/// integer has an exact `f64`.
+pub(crate) fn exceeds_safe_integer(lexeme: &str) -> bool {
+    const MAX_SAFE_INTEGER: u128 = 1 << 53;
+
+    if let Some(value) = parse_hexadecimal(lexeme) {
+        return value.unsigned_abs() > MAX_SAFE_INTEGER;
+    }
+    let digits = lexeme.trim_start_matches(['-', '+']);
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    return match digits.parse::<u128>() {
+        Ok(value) => value > MAX_SAFE_INTEGER,
+        Err(_) => !digits.is_empty(),
+    };
+}
+
+/// True if reading the decimal `lexeme` as an `f64` and printing it back gives
+/// the same number, i.e. no digits were lost and it did not overflow or
+/// underflow.
+pub(crate) fn round_trips_f64(lexeme: &str) -> bool {
+    let value: f64 = match lexeme.trim_start_matches('+').parse() {
+        Ok(value) => value,
+        Err(_) => return false,
+    };
+    if !value.is_finite() {
+        return false;
+    }
+    let shortest = format!("{:e}", value.abs());
+    return match (significand(lexeme), significand(&shortest)) {
+        (Some(written), Some(read)) => written == read,
+        _ => false,
+    };
+}
+
+/// The significant digits of a decimal lexeme and the power of ten that
+/// scales them, so `12.50` and `1.25e1` compare equal. Zero has no digits.
+/// `None` when the exponent of a nonzero number does not fit in an `i64`,
+/// before or after scaling.
+fn significand(lexeme: &str) -> Option<(String, i64)> {
+    let unsigned = lexeme.trim_start_matches(['-', '+']);
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(position) => (
+            &unsigned[..position],
+            unsigned[position + 1..].parse().ok()?,
+        ),
+        None => (unsigned, 0i64),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let digits = format!("{}{}", integer, fraction);
+    let significant = digits.trim_start_matches('0');
+    if significant.is_empty() {
+        return Some((String::new(), 0));
+    }
+
+    let leading_zeros = (digits.len() - significant.len()) as i64;
+    let exponent = exponent
+        .checked_add(integer.len() as i64)?
+        .checked_sub(leading_zeros)?;
+    return Some((String::from(significant.trim_end_matches('0')), exponent));
+}
+
+/// The lexeme spelled as RFC 8259 allows: JSON5 hexadecimal, a leading
+/// `+`, a bare or trailing decimal point and leading zeros are rewritten in
+/// decimal, and anything else is borrowed unchanged. `None` for `Infinity`
+/// and `NaN`, which JSON has no spelling for.
+pub(crate) fn decimal_lexeme(lexeme: &str) -> Option<Cow<'_, str>> {
+    if let Some((negative, digits)) = hexadecimal_digits(lexeme) {
+        let sign = if negative { "-" } else { "" };
+        return Some(Cow::Owned(format!(
+            "{}{}",
+            sign,
+            hexadecimal_to_decimal(digits)
+        )));
+    }
+
+    let (sign, unsigned) = match lexeme.as_bytes().first() {
+        Some(b'-') => ("-", &lexeme[1..]),
+        Some(b'+') => ("", &lexeme[1..]),
+        _ => ("", lexeme),
+    };
+    if !unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(position) => unsigned.split_at(position),
+        None => (unsigned, ""),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let integer = integer.trim_start_matches('0');
+
+    let mut decimal = String::from(sign);
+    decimal.push_str(if integer.is_empty() { "0" } else { integer });
+    if !fraction.is_empty() {
+        decimal.push('.');
+        decimal.push_str(fraction);
+    }
+    decimal.push_str(exponent);
+
+    if decimal == lexeme {
+        return Some(Cow::Borrowed(lexeme));
+    }
+    return Some(Cow::Owned(decimal));
+}
+
+/// Hexadecimal digits in decimal, however many there are.
+fn hexadecimal_to_decimal(digits: &str) -> String {
+    // Least significant first.
+    let mut decimal: Vec<u32> = Vec::new();
+    for c in digits.chars() {
+        let mut carry = c.to_digit(16).unwrap_or(0);
+        for digit in decimal.iter_mut() {
+            let value = *digit * 16 + carry;
+            *digit = value % 10;
+            carry = value / 10;
+        }
+        while carry > 0 {
+            decimal.push(carry % 10);
+            carry /= 10;
+        }
+    }
+
+    if decimal.is_empty() {
+        return String::from("0");
+    }
+    return decimal
+        .iter()
+        .rev()
+        .filter_map(|digit| char::from_digit(*digit, 10))
+        .collect();
+}
+
+/// Reads a JSON5 hexadecimal lexeme such as `-0xFF`, which `str::parse`
+/// does not understand.
+fn parse_hexadecimal(lexeme: &str) -> Option<i128> {
+    let (negative, digits) = hexadecimal_digits(lexeme)?;
+    let magnitude = i128::from_str_radix(digits, 16).ok()?;
+    return Some(if negative { -magnitude } else { magnitude });
+}
+
+/// Whether a JSON5 hexadecimal lexeme is negative, and its digits after
+/// the `0x`. `None` for anything that is not hexadecimal.
+fn hexadecimal_digits(lexeme: &str) -> Option<(bool, &str)> {
+    let (negative, unsigned) = match lexeme.as_bytes().first() {
+        Some(b'-') => (true, &lexeme[1..]),
+        Some(b'+') => (false, &lexeme[1..]),
+        _ => (false, lexeme),
+    };
+    let digits = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))?;
+    return Some((negative, digits));
+}
@@ -0,0 +1,46 @@
+use alloc::{vec, vec::Vec};
+
+use super::value::JsonValue;
+
+/// One step on the way from the root to a value in the tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+/// Calls `visitor` on every value in the tree, `root` included, along with
+/// the steps that lead to it from the root. A container is visited before
+/// its children, which are visited in document order.
+///
+/// This walks an already parsed value; see `parse_events` for a pass over
+/// the source text.
+pub fn walk<F: FnMut(&JsonValue, &[PathSegment])>(root: &JsonValue, mut visitor: F) {
+    walk_at(root, &mut vec![], &mut visitor);
+}
+
+fn walk_at<'a, F: FnMut(&JsonValue, &[PathSegment])>(
+    value: &'a JsonValue,
+    path: &mut Vec<PathSegment<'a>>,
+    visitor: &mut F,
+) {
+    visitor(value, path);
+
+    match value {
+        JsonValue::Array(elements) => {
+            for (index, element) in elements.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                walk_at(element, path, visitor);
+                path.pop();
+            }
+        }
+        JsonValue::Object(members) => {
+            for (key, member) in members.iter() {
+                path.push(PathSegment::Key(key));
+                walk_at(member, path, visitor);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
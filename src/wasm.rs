@@ -0,0 +1,33 @@
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use super::validator::validate_str;
+
+fn set(target: &Object, key: &str, value: JsValue) {
+    // Only fails for frozen objects and proxies, neither of which occur here.
+    let _ = Reflect::set(target, &JsValue::from_str(key), &value);
+}
+
+/// Returns `{ ok: boolean, error: { line, column, message } | null }`.
+#[wasm_bindgen]
+pub fn validate_wasm(input: &str) -> JsValue {
+    let result = Object::new();
+
+    match validate_str(input) {
+        Ok(_) => {
+            set(&result, "ok", JsValue::TRUE);
+            set(&result, "error", JsValue::NULL);
+        }
+        Err(reason) => {
+            let error = Object::new();
+            set(&error, "line", JsValue::from(reason.line() as u32));
+            set(&error, "column", JsValue::from(reason.column() as u32));
+            set(&error, "message", JsValue::from_str(reason.reason()));
+
+            set(&result, "ok", JsValue::FALSE);
+            set(&result, "error", error.into());
+        }
+    }
+
+    return result.into();
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::needless_return)]
+#![cfg(feature = "async")]
+
+use rust_json::{validate_async, ErrorKind, JsonFileError};
+use tokio::io::AsyncReadExt;
+
+fn invalid(result: Result<(), JsonFileError>) -> (ErrorKind, String, usize, usize) {
+    return match result {
+        Err(JsonFileError::Invalid(error)) => (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        ),
+        other => panic!("expected a validation error, got {:?}", other),
+    };
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn validates_a_document() {
+    assert!(validate_async(&b"{\"a\": [1, 2, {\"b\": null}]}"[..])
+        .await
+        .is_ok());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn validates_characters_split_between_reads() {
+    let bytes = "[\"é€😀\"]".as_bytes();
+    for split in 1..bytes.len() {
+        let reader = (&bytes[..split]).chain(&bytes[split..]);
+        assert!(validate_async(reader).await.is_ok(), "split at {}", split);
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn validates_documents_longer_than_one_read() {
+    let document = format!("[{}1]", "1, ".repeat(10_000));
+    assert!(validate_async(document.as_bytes()).await.is_ok());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn reports_json_errors_with_their_position() {
+    let reader = (&b"{\"a\":\n"[..]).chain(&b"  [1, 2,]}"[..]);
+    assert_eq!(
+        invalid(validate_async(reader).await),
+        (
+            ErrorKind::TrailingComma,
+            String::from("Invalid value at $.a[2]: Trailing comma before \"]\""),
+            2,
+            9
+        )
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn reports_an_invalid_utf8_byte() {
+    assert_eq!(
+        invalid(validate_async(&b"[\"caf\xff\"]"[..]).await),
+        (
+            ErrorKind::Encoding,
+            String::from("Invalid UTF-8 byte 0xFF"),
+            1,
+            6
+        )
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn reports_input_ending_inside_a_character() {
+    assert_eq!(
+        invalid(validate_async(&b"[\"\xe2\x82"[..]).await),
+        (
+            ErrorKind::Encoding,
+            String::from("Input ends inside a UTF-8 sequence"),
+            1,
+            3
+        )
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn reports_a_truncated_document() {
+    let (kind, _, _, _) = invalid(validate_async(&b"[1, 2"[..]).await);
+    assert_eq!(kind, ErrorKind::UnexpectedEnd);
+}
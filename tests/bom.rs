@@ -0,0 +1,123 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, BomPolicy, ErrorKind, JsonValue, UTF8Reader,
+    ValidateOptions,
+};
+
+const BOM: &str = "\u{FEFF}";
+
+fn check(input: &str, policy: BomPolicy) -> Result<(), (ErrorKind, String, usize, usize)> {
+    let options = ValidateOptions::default().bom_policy(policy);
+    return validate_with_options(&UTF8Reader::new(input), &options).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+#[test]
+fn a_leading_bom_is_forbidden_by_default() {
+    assert_eq!(ValidateOptions::default().bom_policy, BomPolicy::Forbid);
+    assert_eq!(
+        check(&format!("{}{{}}", BOM), BomPolicy::Forbid),
+        Err((
+            ErrorKind::StrayCharacter,
+            String::from(
+                "Unexpected U+FEFF (byte order mark); only space, tab, line feed and \
+                 carriage return may separate tokens, so remove it"
+            ),
+            1,
+            1
+        ))
+    );
+    assert_eq!(check("{}", BomPolicy::Forbid), Ok(()));
+}
+
+#[test]
+fn allow_skips_one_leading_bom() {
+    assert_eq!(check(&format!("{}{{}}", BOM), BomPolicy::Allow), Ok(()));
+    assert_eq!(check("{}", BomPolicy::Allow), Ok(()));
+    assert_eq!(
+        check(&format!("{}{}[]", BOM, BOM), BomPolicy::Allow),
+        Err((
+            ErrorKind::StrayCharacter,
+            String::from("Unexpected byte order mark"),
+            1,
+            1
+        ))
+    );
+}
+
+#[test]
+fn require_rejects_a_document_without_a_bom() {
+    assert_eq!(check(&format!("{}[1]", BOM), BomPolicy::Require), Ok(()));
+    assert_eq!(
+        check("[1]", BomPolicy::Require),
+        Err((
+            ErrorKind::MissingByteOrderMark,
+            String::from("JSON document should start with a byte order mark"),
+            1,
+            1
+        ))
+    );
+}
+
+#[test]
+fn a_bom_alone_is_an_empty_document() {
+    for policy in [BomPolicy::Allow, BomPolicy::Require] {
+        assert_eq!(
+            check(BOM, policy),
+            Err((
+                ErrorKind::EmptyDocument,
+                String::from("No valid JSON value found"),
+                1,
+                1
+            ))
+        );
+    }
+}
+
+#[test]
+fn the_bom_takes_no_column() {
+    assert_eq!(
+        check(&format!("{} \n [x]", BOM), BomPolicy::Allow),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Invalid value at $[0]: Unknown character: \"x\""),
+            2,
+            3
+        ))
+    );
+}
+
+#[test]
+fn a_bom_inside_the_document_is_never_skipped() {
+    for policy in [BomPolicy::Forbid, BomPolicy::Allow] {
+        assert_eq!(
+            check(&format!("[{}1]", BOM), policy),
+            Err((
+                ErrorKind::StrayCharacter,
+                String::from("Unexpected byte order mark"),
+                1,
+                2
+            ))
+        );
+    }
+
+    // Inside a string it is just a character.
+    assert_eq!(check(&format!("\"{}\"", BOM), BomPolicy::Forbid), Ok(()));
+}
+
+#[test]
+fn parse_skips_an_allowed_bom() {
+    let options = ValidateOptions::default().bom_policy(BomPolicy::Allow);
+    let input = format!("{}[true]", BOM);
+    assert_eq!(
+        parse_with_options(&UTF8Reader::new(&input), &options),
+        Ok(JsonValue::Array(vec![JsonValue::Bool(true)]))
+    );
+}
@@ -0,0 +1,114 @@
+#![allow(clippy::needless_return)]
+
+use proptest::prelude::*;
+use rust_json::{
+    classify_number, parse_with_options, validate_str, JsonValue, NumberClass, NumberKind,
+    UTF8Reader, ValidateOptions,
+};
+
+fn class(kind: NumberKind, negative: bool, has_fraction: bool, has_exponent: bool) -> NumberClass {
+    return NumberClass {
+        kind,
+        negative,
+        has_fraction,
+        has_exponent,
+    };
+}
+
+#[test]
+fn classifies_representative_lexemes() {
+    let cases = [
+        ("0", class(NumberKind::Integer, false, false, false)),
+        ("42", class(NumberKind::Integer, false, false, false)),
+        ("-7", class(NumberKind::Integer, true, false, false)),
+        ("-0", class(NumberKind::Integer, true, false, false)),
+        (
+            "9007199254740993",
+            class(NumberKind::Integer, false, false, false),
+        ),
+        ("1.5", class(NumberKind::Float, false, true, false)),
+        ("-0.0", class(NumberKind::Float, true, true, false)),
+        ("1e9", class(NumberKind::Scientific, false, false, true)),
+        ("2.5E-3", class(NumberKind::Scientific, false, true, true)),
+        ("-1.0e+10", class(NumberKind::Scientific, true, true, true)),
+    ];
+
+    for (lexeme, expected) in cases {
+        assert_eq!(classify_number(lexeme), expected, "{}", lexeme);
+    }
+}
+
+#[test]
+fn classifies_json5_lexemes() {
+    let cases = [
+        ("+1", class(NumberKind::Integer, false, false, false)),
+        (".5", class(NumberKind::Float, false, true, false)),
+        ("5.", class(NumberKind::Float, false, true, false)),
+        ("0xFF", class(NumberKind::Integer, false, false, false)),
+        // The "E" is a hexadecimal digit, not an exponent.
+        ("-0xE", class(NumberKind::Integer, true, false, false)),
+        ("Infinity", class(NumberKind::Float, false, false, false)),
+        ("-Infinity", class(NumberKind::Float, true, false, false)),
+        ("NaN", class(NumberKind::Float, false, false, false)),
+    ];
+
+    for (lexeme, expected) in cases {
+        assert_eq!(classify_number(lexeme), expected, "{}", lexeme);
+    }
+}
+
+#[test]
+fn parsed_numbers_agree() {
+    let document = "[1, -2.5, 3e2, 0x1E, 1E400]";
+    let value = parse_with_options(&UTF8Reader::new(document), &ValidateOptions::json5()).unwrap();
+    let kinds: Vec<NumberKind> = value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|element| match element {
+            JsonValue::Number(number) => number.classify().kind,
+            _ => unreachable!(),
+        })
+        .collect();
+
+    assert_eq!(
+        kinds,
+        vec![
+            NumberKind::Integer,
+            NumberKind::Float,
+            NumberKind::Scientific,
+            NumberKind::Integer,
+            NumberKind::Scientific,
+        ]
+    );
+}
+
+proptest! {
+    #[test]
+    fn only_integers_convert_to_i64(
+        negative in any::<bool>(),
+        integer in "(0|[1-9][0-9]{0,6})",
+        fraction in proptest::option::of("[0-9]{1,3}"),
+        exponent in proptest::option::of("[eE][+-]?[0-9]{1,2}"),
+    ) {
+        let lexeme = format!(
+            "{}{}{}{}",
+            if negative { "-" } else { "" },
+            integer,
+            fraction.as_ref().map(|f| format!(".{}", f)).unwrap_or_default(),
+            exponent.clone().unwrap_or_default(),
+        );
+        prop_assume!(validate_str(&lexeme).is_ok());
+
+        let number = match parse_with_options(&UTF8Reader::new(&lexeme), &ValidateOptions::default()) {
+            Ok(JsonValue::Number(number)) => number,
+            other => panic!("{:?}", other),
+        };
+        let class = number.classify();
+
+        prop_assert_eq!(class.negative, negative);
+        prop_assert_eq!(class.has_fraction, fraction.is_some());
+        prop_assert_eq!(class.has_exponent, exponent.is_some());
+        prop_assert_eq!(number.as_i64().is_some(), class.kind == NumberKind::Integer);
+    }
+}
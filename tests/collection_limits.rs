@@ -0,0 +1,99 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_with_options, ErrorKind, UTF8Reader, ValidateOptions};
+
+fn check(input: &str, options: &ValidateOptions) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate_with_options(&UTF8Reader::new(input), options).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+#[test]
+fn there_are_no_limits_by_default() {
+    let options = ValidateOptions::default();
+    assert_eq!(options.max_string_length, None);
+    assert_eq!(options.max_array_elements, None);
+    assert_eq!(options.max_object_members, None);
+
+    let long = format!(
+        "[\"{}\", {}]",
+        "a".repeat(10_000),
+        vec!["0"; 10_000].join(",")
+    );
+    assert_eq!(check(&long, &options), Ok(()));
+}
+
+#[test]
+fn string_length_counts_characters_as_written() {
+    let options = ValidateOptions::default().max_string_length(3);
+    assert_eq!(check("\"abc\"", &options), Ok(()));
+    assert_eq!(check("\"ééé\"", &options), Ok(()));
+
+    let too_long = |column| {
+        return Err((
+            ErrorKind::StringTooLong { limit: 3 },
+            String::from("String is longer than 3 characters"),
+            1,
+            column,
+        ));
+    };
+    assert_eq!(check("\"abcd\"", &options), too_long(5));
+    // `\n` is two characters as written.
+    assert_eq!(check("\"a\\nb\"", &options), too_long(5));
+    // Keys count too.
+    assert_eq!(check("{\"abcd\":1}", &options), too_long(6));
+}
+
+#[test]
+fn array_elements_are_capped_per_array() {
+    let options = ValidateOptions::default().max_array_elements(2);
+    assert_eq!(check("[1,2]", &options), Ok(()));
+    assert_eq!(check("[[1,2],[3,4]]", &options), Ok(()));
+    assert_eq!(
+        check("[1,2,3]", &options),
+        Err((
+            ErrorKind::TooManyArrayElements { limit: 2 },
+            String::from("Array has more than 2 elements"),
+            1,
+            6
+        ))
+    );
+    assert_eq!(
+        check("[[1,2],[3,4,5]]", &options),
+        Err((
+            ErrorKind::TooManyArrayElements { limit: 2 },
+            String::from("Invalid value at $[1]: Array has more than 2 elements"),
+            1,
+            13
+        ))
+    );
+}
+
+#[test]
+fn object_members_are_capped_per_object() {
+    let options = ValidateOptions::default().max_object_members(1);
+    assert_eq!(check("{\"a\":{\"b\":1}}", &options), Ok(()));
+    assert_eq!(
+        check("{\"a\":1,\"b\":2}", &options),
+        Err((
+            ErrorKind::TooManyObjectMembers { limit: 1 },
+            String::from("Object has more than 1 members"),
+            1,
+            8
+        ))
+    );
+    assert_eq!(
+        check("{\"a\":{\"b\":1,\"c\":2}}", &options),
+        Err((
+            ErrorKind::TooManyObjectMembers { limit: 1 },
+            String::from("Invalid value at $.a: Object has more than 1 members"),
+            1,
+            13
+        ))
+    );
+}
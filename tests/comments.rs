@@ -0,0 +1,76 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{parse, parse_with_comments, Comment, UTF8Reader};
+
+const CONFIG: &str = r#"// Service settings
+{
+  // Address to listen on
+  "host": "0.0.0.0",
+  /* Port, between
+     1024 and 65535 */
+  "port": 8080,
+  "paths": [
+    "/a", // not this one
+    "/b//c" /* keeps the slashes */
+  ],
+  "odd key": /* inline */ true
+}
+// trailing
+"#;
+
+fn comments(document: &str) -> Vec<Comment> {
+    return parse_with_comments(&UTF8Reader::new(document)).unwrap().1;
+}
+
+#[test]
+fn attaches_comments_to_what_follows() {
+    let found: Vec<(String, Option<String>)> = comments(CONFIG)
+        .into_iter()
+        .map(|comment| (comment.text, comment.path))
+        .collect();
+    let path = |p: &str| Some(String::from(p));
+
+    assert_eq!(
+        found,
+        [
+            (String::from(" Service settings"), path("$")),
+            (String::from(" Address to listen on"), path("$.host")),
+            (
+                String::from(" Port, between\n     1024 and 65535 "),
+                path("$.port")
+            ),
+            (String::from(" not this one"), path("$.paths[1]")),
+            (String::from(" keeps the slashes "), path("$[\"odd key\"]")),
+            (String::from(" inline "), path("$[\"odd key\"]")),
+            (String::from(" trailing"), None),
+        ]
+    );
+}
+
+#[test]
+fn value_is_the_same_without_comments() {
+    let (value, _) = parse_with_comments(&UTF8Reader::new(CONFIG)).unwrap();
+    let plain = r#"{"host": "0.0.0.0", "port": 8080, "paths": ["/a", "/b//c"], "odd key": true}"#;
+    assert_eq!(value, parse(&UTF8Reader::new(plain)).unwrap());
+}
+
+#[test]
+fn spans_cover_the_markers() {
+    let document = "[1, /* é */ 2]";
+    let found = comments(document);
+    assert_eq!(found[0].span, (4, 11));
+    let text: String = document.chars().skip(4).take(7).collect();
+    assert_eq!(text, "/* é */");
+}
+
+#[test]
+fn errors_point_into_the_original() {
+    let document = "{\n  /* é */ \"a\": 1,\n}";
+    let error = parse_with_comments(&UTF8Reader::new(document)).unwrap_err();
+    assert_eq!((error.line(), error.column()), (3, 1));
+    assert_eq!(error.byte_offset(), document.len() - 1);
+
+    let error = parse_with_comments(&UTF8Reader::new("[1 /* open")).unwrap_err();
+    assert_eq!(error.reason(), "Block comment is never closed");
+    assert_eq!(error.column(), 4);
+}
@@ -0,0 +1,55 @@
+#![allow(clippy::needless_return)]
+#![cfg(feature = "std")]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use rust_json::{parse, ConversionError, JsonValue, UTF8Reader};
+
+fn value(document: &str) -> JsonValue {
+    return parse(&UTF8Reader::new(document)).unwrap();
+}
+
+#[test]
+fn object_into_hash_map() {
+    let scores = value(r#"{"alice": 3, "bob": -7, "carol": 0}"#);
+    let scores = HashMap::<String, i64>::try_from(&scores).unwrap();
+    assert_eq!(scores.len(), 3);
+    assert_eq!(scores["alice"], 3);
+    assert_eq!(scores["bob"], -7);
+}
+
+#[test]
+fn mismatch_names_the_field() {
+    let scores = value(r#"{"alice": 3, "bob": "seven"}"#);
+    let error = HashMap::<String, i64>::try_from(&scores).unwrap_err();
+    assert_eq!(error.expected(), "i64");
+    assert_eq!(error.found(), "string");
+    assert_eq!(error.path(), "$.bob");
+    assert_eq!(error.to_string(), "Expected i64 at $.bob, found string");
+}
+
+#[test]
+fn nested_collections() {
+    let document = value(r#"{"a b": [[true], [false, true]]}"#);
+    let flags = HashMap::<String, Vec<Vec<bool>>>::try_from(&document).unwrap();
+    assert_eq!(flags["a b"], vec![vec![true], vec![false, true]]);
+
+    let document = value(r#"{"a b": [[true], [false, null]]}"#);
+    let error = HashMap::<String, Vec<Vec<bool>>>::try_from(&document).unwrap_err();
+    assert_eq!(error.path(), "$[\"a b\"][1][1]");
+    assert_eq!(error.found(), "null");
+}
+
+#[test]
+fn scalars() {
+    assert_eq!(String::try_from(&value("\"x\"")), Ok(String::from("x")));
+    assert_eq!(f64::try_from(&value("1.5e1")), Ok(15.0));
+    assert_eq!(i64::try_from(&value("-12")), Ok(-12));
+    assert_eq!(bool::try_from(&value("false")), Ok(false));
+
+    let error: ConversionError = i64::try_from(&value("1.5")).unwrap_err();
+    assert_eq!((error.expected(), error.found()), ("i64", "number"));
+    assert_eq!(error.path(), "$");
+    assert!(Vec::<i64>::try_from(&value("{}")).is_err());
+}
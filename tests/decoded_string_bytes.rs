@@ -0,0 +1,76 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    decode_string_with_options, parse_borrowed_with_options, unescape_cow_with_options,
+    validate_str_with_options, ErrorKind, UTF8Reader, ValidateOptions, Validator,
+};
+
+// Three scalars as the validator counts them, but twelve bytes decoded.
+const EMOJI: &str = "\"\u{1F600}\u{1F600}\u{1F600}\"";
+// Each surrogate pair escape decodes to four bytes.
+const PAIRS: &str = r#""\uD83D\uDE00\uD83D\uDE00""#;
+
+fn options(limit: usize) -> ValidateOptions {
+    return ValidateOptions::default()
+        .max_string_length(4)
+        .max_decoded_string_bytes(limit);
+}
+
+#[test]
+fn short_source_strings_can_decode_past_the_cap() {
+    let options = options(8);
+    assert!(validate_str_with_options(EMOJI, &options).is_ok());
+
+    let (result, step) = decode_string_with_options(&UTF8Reader::new(EMOJI), 0, &options);
+    let error = result.unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::DecodedStringTooLong { limit: 8 });
+    assert_eq!(error.kind().code(), "DECODED_STRING_TOO_LONG");
+    assert_eq!(error.reason(), "String decodes to more than 8 bytes");
+    // The third emoji is the one that crosses the cap.
+    assert_eq!(error.byte_offset(), 9);
+    assert_eq!(step, 3);
+}
+
+#[test]
+fn surrogate_pairs_count_as_decoded() {
+    let decode = |limit: usize| {
+        let options = ValidateOptions::default().max_decoded_string_bytes(limit);
+        let (result, _) = decode_string_with_options(&UTF8Reader::new(PAIRS), 0, &options);
+        return result.map_err(|error| error.kind().clone());
+    };
+
+    assert_eq!(decode(8), Ok(String::from("\u{1F600}\u{1F600}")));
+    assert_eq!(decode(7), Err(ErrorKind::DecodedStringTooLong { limit: 7 }));
+}
+
+#[test]
+fn borrowed_strings_are_capped_too() {
+    let reader = UTF8Reader::new(EMOJI);
+    let (result, _) = unescape_cow_with_options(&reader, 0, &options(12));
+    assert_eq!(result.unwrap(), "\u{1F600}\u{1F600}\u{1F600}");
+
+    let (result, _) = unescape_cow_with_options(&reader, 0, &options(11));
+    assert!(result.is_err());
+
+    let document = format!("{{\"key\": {}}}", EMOJI);
+    let reader = UTF8Reader::new(&document);
+    assert!(parse_borrowed_with_options(&reader, &options(12)).is_ok());
+    assert!(parse_borrowed_with_options(&reader, &options(11)).is_err());
+}
+
+#[test]
+fn applies_to_keys_when_parsing() {
+    let validator = Validator::new().max_decoded_string_bytes(8);
+    let document = format!("{{{}: 1}}", EMOJI);
+
+    assert!(validator.validate(&document).is_ok());
+    let error = validator.parse(&document).unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::DecodedStringTooLong { limit: 8 });
+}
+
+#[test]
+fn unset_by_default() {
+    let (result, _) =
+        decode_string_with_options(&UTF8Reader::new(EMOJI), 0, &ValidateOptions::default());
+    assert_eq!(result.unwrap().len(), 12);
+}
@@ -0,0 +1,114 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{diff, parse, Change, ChangeKind, JsonValue, UTF8Reader};
+
+fn value(input: &str) -> JsonValue {
+    return parse(&UTF8Reader::new(input)).unwrap();
+}
+
+fn changes(a: &str, b: &str) -> Vec<Change> {
+    return diff(&value(a), &value(b));
+}
+
+fn change(path: &str, kind: ChangeKind) -> Change {
+    return Change {
+        path: String::from(path),
+        kind,
+    };
+}
+
+#[test]
+fn finds_nothing_between_equal_values() {
+    assert_eq!(
+        changes(r#"{"a": [1, {"b": null}]}"#, r#"{"a": [1, {"b": null}]}"#),
+        vec![]
+    );
+}
+
+#[test]
+fn ignores_key_order() {
+    assert_eq!(
+        changes(r#"{"a": 1, "b": 2}"#, r#"{"b": 2, "a": 1}"#),
+        vec![]
+    );
+}
+
+#[test]
+fn reports_added_removed_and_replaced_members() {
+    assert_eq!(
+        changes(r#"{"a": 1, "b": 2}"#, r#"{"b": 3, "c": 4}"#),
+        vec![
+            change("/a", ChangeKind::Removed(value("1"))),
+            change(
+                "/b",
+                ChangeKind::Replaced {
+                    old: value("2"),
+                    new: value("3"),
+                }
+            ),
+            change("/c", ChangeKind::Added(value("4"))),
+        ]
+    );
+}
+
+#[test]
+fn matches_array_elements_by_position() {
+    assert_eq!(
+        changes("[1, 2]", "[0, 1, 2]"),
+        vec![
+            change(
+                "/0",
+                ChangeKind::Replaced {
+                    old: value("1"),
+                    new: value("0"),
+                }
+            ),
+            change(
+                "/1",
+                ChangeKind::Replaced {
+                    old: value("2"),
+                    new: value("1"),
+                }
+            ),
+            change("/2", ChangeKind::Added(value("2"))),
+        ]
+    );
+    assert_eq!(
+        changes("[1, 2]", "[1]"),
+        vec![change("/1", ChangeKind::Removed(value("2")))]
+    );
+}
+
+#[test]
+fn locates_nested_changes_with_escaped_pointers() {
+    assert_eq!(
+        changes(
+            r#"{"servers": [{"a/b": 1, "c~d": true}]}"#,
+            r#"{"servers": [{"a/b": 2}]}"#
+        ),
+        vec![
+            change(
+                "/servers/0/a~1b",
+                ChangeKind::Replaced {
+                    old: value("1"),
+                    new: value("2"),
+                }
+            ),
+            change("/servers/0/c~0d", ChangeKind::Removed(value("true"))),
+        ]
+    );
+}
+
+#[test]
+fn replaces_the_root_when_the_types_differ() {
+    assert_eq!(
+        changes("{}", "[]"),
+        vec![change(
+            "",
+            ChangeKind::Replaced {
+                old: value("{}"),
+                new: value("[]"),
+            }
+        )]
+    );
+}
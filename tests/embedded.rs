@@ -0,0 +1,140 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    validate, validate_at_offset, validate_at_offset_with_options, BomPolicy, ErrorKind,
+    UTF8Reader, ValidateOptions,
+};
+
+const REQUEST: &str =
+    "POST /users HTTP/1.1\r\nHost: example.com\r\n\r\n{\"user\": \"ada\",\n \"age\": 3x}";
+
+fn slice_start(text: &str, start: usize) -> (usize, usize) {
+    let before = &text[..start];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap().chars().count() + 1;
+    return (line, column);
+}
+
+#[test]
+fn errors_point_into_the_original_text() {
+    let line = r#"2024-05-01T12:00:00Z INFO request body={"user": "ada", "age": 3x}"#;
+    let start = line.find('{').unwrap();
+    let json = &line[start..];
+
+    let error = validate_at_offset(&UTF8Reader::new(json), start, 1, start + 1).unwrap_err();
+    assert_eq!(error.byte_offset(), line.find("x}").unwrap());
+    assert_eq!(&line[error.byte_offset()..], "x}");
+    assert_eq!(error.line(), 1);
+    assert_eq!(error.column(), line.find("x}").unwrap() + 1);
+
+    // The reason and the index are as `validate` reports them.
+    let plain = validate(&UTF8Reader::new(json)).unwrap_err();
+    assert_eq!(error.index(), plain.index());
+    assert_eq!(error.reason(), plain.reason());
+}
+
+#[test]
+fn later_lines_keep_their_columns() {
+    let start = REQUEST.find('{').unwrap();
+    let (line, column) = slice_start(REQUEST, start);
+    assert_eq!((line, column), (4, 1));
+
+    let json = &REQUEST[start..];
+    let error = validate_at_offset(&UTF8Reader::new(json), start, line, column).unwrap_err();
+    assert_eq!(&REQUEST[error.byte_offset()..], "x}");
+    assert_eq!(error.line(), 5);
+    assert_eq!(error.column(), 10);
+}
+
+#[test]
+fn counts_the_prefix_in_bytes() {
+    let body = "Überschrift: [1, 2,, 3]";
+    let start = body.find('[').unwrap();
+    assert_eq!(start, 14);
+
+    let (line, column) = slice_start(body, start);
+    let json = &body[start..];
+    let error = validate_at_offset(&UTF8Reader::new(json), start, line, column).unwrap_err();
+    assert_eq!(&body[error.byte_offset()..], ", 3]");
+    // Columns count scalars, so "Ü" is one of them.
+    assert_eq!(error.column(), 20);
+}
+
+#[test]
+fn moves_where_an_unclosed_string_opened() {
+    let text = "data: [1, \"abc";
+    let start = text.find('[').unwrap();
+    let json = &text[start..];
+
+    let error = validate_at_offset(&UTF8Reader::new(json), start, 1, start + 1).unwrap_err();
+    assert!(
+        error.reason().ends_with("opened at line 1, column 11"),
+        "{}",
+        error.reason()
+    );
+}
+
+#[test]
+fn valid_documents_pass() {
+    assert!(validate_at_offset(&UTF8Reader::new(r#"{"ok": true}"#), 42, 3, 7).is_ok());
+}
+
+#[test]
+fn huge_offsets_saturate() {
+    let error = validate_at_offset(&UTF8Reader::new("[1,]"), usize::MAX, usize::MAX, usize::MAX)
+        .unwrap_err();
+    assert_eq!(error.byte_offset(), usize::MAX);
+    assert_eq!(error.line(), usize::MAX);
+    assert_eq!(error.column(), usize::MAX);
+}
+
+#[test]
+fn a_bom_starting_the_slice_follows_the_options() {
+    let text = "body=\u{FEFF}[1,]";
+    let start = text.find('\u{FEFF}').unwrap();
+    let json = &text[start..];
+
+    let error = validate_at_offset(&UTF8Reader::new(json), start, 1, start + 1).unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::StrayCharacter);
+    assert_eq!(error.byte_offset(), start);
+    assert_eq!(error.column(), 6);
+
+    let options = ValidateOptions::default().bom_policy(BomPolicy::Allow);
+    let error =
+        validate_at_offset_with_options(&UTF8Reader::new(json), &options, start, 1, start + 1)
+            .unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::TrailingComma);
+    assert_eq!(&text[error.byte_offset()..], "]");
+    // The BOM is a character of the larger text, so it takes up a column.
+    assert_eq!(error.column(), 10);
+
+    assert!(validate_at_offset_with_options(
+        &UTF8Reader::new("\u{FEFF}[1]"),
+        &options,
+        start,
+        1,
+        start + 1
+    )
+    .is_ok());
+}
+
+#[test]
+fn a_bom_starting_the_slice_moves_where_a_string_opened() {
+    let text = "body=\u{FEFF}[\"ab";
+    let start = text.find('\u{FEFF}').unwrap();
+    let options = ValidateOptions::default().bom_policy(BomPolicy::Allow);
+
+    let error = validate_at_offset_with_options(
+        &UTF8Reader::new(&text[start..]),
+        &options,
+        start,
+        1,
+        start + 1,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.reason(),
+        "Invalid value at $[0]: Incomplete string value, opened at line 1, column 8"
+    );
+    assert_eq!(error.column(), 11);
+}
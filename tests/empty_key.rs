@@ -0,0 +1,85 @@
+#![allow(clippy::needless_return)]
+#![cfg(feature = "std")]
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use rust_json::{
+    decode_string, locate_pointer, parse, parse_borrowed, parse_document, parse_events,
+    parse_with_spans, validate_str, JsonEvent, JsonValue, JsonValueRef, UTF8Reader,
+};
+
+fn value(document: &str) -> JsonValue {
+    return parse(&UTF8Reader::new(document)).unwrap();
+}
+
+#[test]
+fn validates() {
+    for document in [
+        r#"{"":1}"#,
+        r#"{ "" : 1 , "a" : 2 }"#,
+        r#"{"":{"":[""]}}"#,
+        r#"{"":1,"":2}"#,
+    ] {
+        assert!(validate_str(document).is_ok(), "{}", document);
+    }
+    assert!(validate_str(r#"{:1}"#).is_err());
+    assert!(validate_str(r#"{"":}"#).is_err());
+}
+
+#[test]
+fn parses_in_every_flavour() {
+    let document = r#"{"": 1}"#;
+    let reader = UTF8Reader::new(document);
+
+    assert_eq!(value(document).get(""), Some(&value("1")));
+    assert!(matches!(
+        parse_borrowed(&reader).unwrap(),
+        JsonValueRef::Object(members) if members.len() == 1 && members[0].0.is_empty()
+    ));
+
+    let spanned = parse_with_spans(&reader).unwrap();
+    assert_eq!(JsonValue::from(spanned.clone()), value(document));
+    if let rust_json::SpannedKind::Object(members) = spanned.kind {
+        assert_eq!(members[0].key, "");
+        assert_eq!(members[0].key_span, (1, 3));
+    }
+
+    let mut keys = vec![];
+    parse_events(&reader, |event| {
+        if let JsonEvent::Key(key) = event {
+            keys.push(key);
+        }
+    })
+    .unwrap();
+    assert_eq!(keys, [""]);
+
+    let (key, step) = decode_string(&reader, 1);
+    assert_eq!((key.unwrap().as_str(), step), ("", 2));
+
+    assert_eq!(parse_document(&reader).unwrap().to_string(), document);
+}
+
+#[test]
+fn paths_and_pointers() {
+    let error = validate_str(r#"{"": [1, ]}"#).unwrap_err();
+    assert_eq!(error.path(), "$[\"\"][1]");
+
+    // The pointer "/" names the empty key, not the root.
+    let reader = UTF8Reader::new(r#"{"": 7, "a": 8}"#);
+    assert_eq!(locate_pointer(&reader, "/"), Some((5, 6)));
+}
+
+/// Two empty keys are the same key, however it is looked up.
+#[test]
+fn repeated_empty_keys() {
+    let mut object = value(r#"{"": 1, "": 2}"#);
+    assert_eq!(object.get(""), Some(&value("1")));
+    assert_eq!(object.insert("", value("3")), Some(value("1")));
+    assert_eq!(object.remove(""), Some(value("3")));
+    assert_eq!(object.get(""), Some(&value("2")));
+
+    let converted = HashMap::<String, i64>::try_from(&value(r#"{"": 1, "": 2}"#)).unwrap();
+    assert_eq!(converted.len(), 1);
+    assert_eq!(converted[""], 2);
+}
@@ -0,0 +1,122 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    error_to_json, parse, validate_str, validate_str_with_options, BomPolicy, ErrorKind,
+    TopLevelType, UTF8Reader, ValidateOptions,
+};
+
+// A document, the code of its error, and where the error is.
+const CASES: [(&str, &str, usize, usize); 20] = [
+    ("", "EMPTY_DOCUMENT", 1, 1),
+    ("  ", "EMPTY_DOCUMENT", 1, 3),
+    ("[01]", "LEADING_ZERO", 1, 3),
+    ("[1.]", "INVALID_NUMBER", 1, 4),
+    ("[-]", "INVALID_NUMBER", 1, 3),
+    ("[1,]", "TRAILING_COMMA", 1, 4),
+    ("{\"a\": 1,\n}", "TRAILING_COMMA", 2, 1),
+    ("[\"\\x\"]", "INVALID_ESCAPE", 1, 4),
+    ("[\"\\u12G4\"]", "INVALID_ESCAPE", 1, 7),
+    ("[\"abc", "UNTERMINATED_STRING", 1, 6),
+    ("[\"a\u{1}\"]", "UNESCAPED_CHARACTER", 1, 4),
+    ("{\"a\":", "UNEXPECTED_END", 1, 6),
+    ("[1", "UNEXPECTED_END", 1, 3),
+    ("[1] 2", "TRAILING_DATA", 1, 5),
+    ("tru", "INVALID_LITERAL", 1, 1),
+    ("[TRUE]", "INVALID_LITERAL", 1, 2),
+    ("{1: 2}", "INVALID_KEY", 1, 2),
+    ("[\u{0}]", "STRAY_CHARACTER", 1, 2),
+    ("[1 2]", "SYNTAX", 1, 4),
+    ("[#]", "SYNTAX", 1, 2),
+];
+
+#[test]
+fn each_fault_class_has_its_own_code() {
+    for (document, code, line, column) in CASES {
+        let error = validate_str(document).unwrap_err();
+        assert_eq!(error.kind().code(), code, "{:?}", document);
+        assert_eq!(
+            (error.line(), error.column()),
+            (line, column),
+            "{:?}",
+            document
+        );
+    }
+}
+
+#[test]
+fn reasons_name_the_fault() {
+    let reason = |document: &str| String::from(validate_str(document).unwrap_err().reason());
+
+    assert_eq!(
+        reason("[1,]"),
+        "Invalid value at $[1]: Trailing comma before \"]\""
+    );
+    assert_eq!(reason("{\"a\": 1,}"), "Trailing comma before \"}\"");
+    assert_eq!(
+        reason("[01]"),
+        "Invalid value at $[0]: Leading zeros are not allowed"
+    );
+}
+
+#[test]
+fn json_carries_the_code() {
+    let error = validate_str("[01]").unwrap_err();
+    assert_eq!(
+        error_to_json(&error),
+        r#"{"line":1,"column":3,"byte_offset":2,"code":"LEADING_ZERO","message":"Invalid value at $[0]: Leading zeros are not allowed"}"#
+    );
+
+    let error = validate_str("{\"a\": 1,}").unwrap_err();
+    assert_eq!(
+        error_to_json(&error),
+        r#"{"line":1,"column":9,"byte_offset":8,"code":"TRAILING_COMMA","message":"Trailing comma before \"}\""}"#
+    );
+}
+
+#[test]
+fn unpaired_surrogates_fail_only_when_decoded() {
+    let document = r#"["\uD800"]"#;
+    assert!(validate_str(document).is_ok());
+
+    let error = parse(&UTF8Reader::new(document)).unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::UnpairedSurrogate);
+    assert_eq!(
+        error_to_json(&error),
+        r#"{"line":1,"column":3,"byte_offset":2,"code":"UNPAIRED_SURROGATE","message":"Invalid value at $[0]: Unpaired surrogate \\uD800 can not be decoded"}"#
+    );
+}
+
+#[test]
+fn option_faults_have_codes() {
+    let code = |document: &str, options: ValidateOptions| {
+        let error = validate_str_with_options(document, &options).unwrap_err();
+        return error.kind().code();
+    };
+
+    assert_eq!(
+        code(
+            "[1]",
+            ValidateOptions::default().require_top_level(TopLevelType::Object)
+        ),
+        "UNEXPECTED_TOP_LEVEL"
+    );
+    assert_eq!(
+        code(
+            "[1]",
+            ValidateOptions::default().bom_policy(BomPolicy::Require)
+        ),
+        "MISSING_BYTE_ORDER_MARK"
+    );
+    assert_eq!(
+        code("[1]", ValidateOptions::default().max_bytes(2)),
+        "DOCUMENT_TOO_LARGE"
+    );
+}
+
+#[test]
+fn deep_nesting_is_too_deep() {
+    let document = format!("{}{}", "[".repeat(101), "]".repeat(101));
+    let error = validate_str(&document).unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::TooDeep);
+    assert_eq!(error.column(), 101);
+}
@@ -0,0 +1,24 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::validate_str;
+
+#[test]
+fn nested_failure_names_its_path() {
+    let document = r#"{"users": [0, 1, 2, {"profile": {"address": tru}}]}"#;
+    let error = validate_str(document).unwrap_err();
+    assert_eq!(error.path(), "$.users[3].profile.address");
+    assert!(error
+        .reason()
+        .starts_with("Invalid value at $.users[3].profile.address: "));
+}
+
+#[test]
+fn keys_that_are_not_identifiers_are_quoted() {
+    let error = validate_str(r#"{"a b": [1, {"x": 01}]}"#).unwrap_err();
+    assert_eq!(error.path(), r#"$["a b"][1].x"#);
+}
+
+#[test]
+fn errors_outside_any_value_are_at_the_root() {
+    assert_eq!(validate_str("[1, 2").unwrap_err().path(), "$");
+}
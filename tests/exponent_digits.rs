@@ -0,0 +1,41 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_str, validate_str_with_options, ErrorKind, ValidateOptions};
+
+fn validate(document: &str) -> Result<(), ErrorKind> {
+    let options = ValidateOptions::default().max_exponent_digits(3);
+    return validate_str_with_options(document, &options).map_err(|error| error.kind().clone());
+}
+
+#[test]
+fn at_the_limit() {
+    assert_eq!(validate("1e999"), Ok(()));
+    assert_eq!(validate("[1.5E+100, -2e-999]"), Ok(()));
+}
+
+#[test]
+fn just_over_the_limit() {
+    let too_long = Err(ErrorKind::ExponentTooLong { limit: 3 });
+    assert_eq!(validate("1e1000"), too_long);
+    assert_eq!(validate("[1.5E+1000]"), too_long);
+    assert_eq!(validate("{\"a\": -2e-9999}"), too_long);
+}
+
+#[test]
+fn leading_zeros_are_not_counted() {
+    assert_eq!(validate("1e000000999"), Ok(()));
+    assert_eq!(validate("1e-0000"), Ok(()));
+}
+
+#[test]
+fn points_at_the_first_digit_past_the_limit() {
+    let options = ValidateOptions::default().max_exponent_digits(3);
+    let error = validate_str_with_options("1e1234", &options).unwrap_err();
+    assert_eq!(error.column(), 6);
+    assert_eq!(error.reason(), "Exponent has more than 3 digits");
+}
+
+#[test]
+fn unlimited_by_default() {
+    assert!(validate_str("1e99999999999999").is_ok());
+}
@@ -0,0 +1,96 @@
+#![allow(clippy::needless_return)]
+#![cfg(feature = "std")]
+
+use rust_json::{read_file_as_utf8, validate_all_files, validate_file, ErrorKind, JsonFileError};
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    return std::env::temp_dir().join(format!("rust-json-files-{}-{}", std::process::id(), name));
+}
+
+fn temp_file(name: &str, content: &[u8]) -> PathBuf {
+    let path = temp_path(name);
+    fs::write(&path, content).unwrap();
+    return path;
+}
+
+#[test]
+fn validates_a_file() {
+    let path = temp_file("valid.json", b"{\"a\": [1, 2]}\n");
+    assert!(validate_file(&path).is_ok());
+}
+
+#[test]
+fn reports_json_errors_with_their_position() {
+    let path = temp_file("invalid.json", b"{\n  \"a\": tru\n}");
+    match validate_file(&path) {
+        Err(JsonFileError::Invalid(error)) => {
+            assert_eq!(error.kind(), &ErrorKind::InvalidLiteral);
+            assert_eq!((error.line(), error.column()), (2, 8));
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn reports_a_missing_file_as_io() {
+    let path = temp_path("missing.json");
+    assert!(matches!(validate_file(&path), Err(JsonFileError::Io(_))));
+    assert!(matches!(
+        read_file_as_utf8(&path),
+        Err(JsonFileError::Io(_))
+    ));
+}
+
+#[test]
+fn reports_invalid_utf8_as_utf8() {
+    let path = temp_file("latin1.json", b"[\"caf\xe9\"]");
+    match validate_file(&path) {
+        Err(JsonFileError::Utf8(error)) => assert_eq!(error.valid_up_to(), 5),
+        other => panic!("expected a UTF-8 error, got {:?}", other),
+    }
+    assert!(matches!(
+        read_file_as_utf8(&path),
+        Err(JsonFileError::Utf8(_))
+    ));
+}
+
+#[test]
+fn reads_a_file_as_utf8() {
+    let path = temp_file("read.json", "[\"é\"]".as_bytes());
+    assert_eq!(read_file_as_utf8(&path).unwrap(), "[\"é\"]");
+}
+
+#[test]
+fn validates_every_json_file_in_a_directory_in_order() {
+    let dir = temp_path("dir");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir(&dir).unwrap();
+    fs::write(dir.join("b.json"), b"[1,]").unwrap();
+    fs::write(dir.join("a.json"), b"{}").unwrap();
+    fs::write(dir.join("c.json"), b"\"\xff\"").unwrap();
+    fs::write(dir.join("notes.txt"), b"not json").unwrap();
+    fs::create_dir(dir.join("nested.json")).unwrap();
+
+    let results = validate_all_files(&dir).unwrap();
+    let names: Vec<&str> = results
+        .iter()
+        .map(|(path, _)| path.file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, ["a.json", "b.json", "c.json"]);
+
+    assert!(results[0].1.is_ok());
+    let error = results[1].1.as_ref().unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::TrailingComma);
+    assert_eq!((error.line(), error.column()), (1, 4));
+    let error = results[2].1.as_ref().unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::Encoding);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fails_when_the_directory_is_missing() {
+    assert!(validate_all_files(&temp_path("no-such-dir")).is_err());
+}
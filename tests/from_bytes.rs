@@ -0,0 +1,33 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate, UTF8Reader};
+
+#[test]
+fn reads_valid_utf8() {
+    let reader = UTF8Reader::from_bytes(b"[\"\xC3\xA9\"]").unwrap();
+    assert_eq!(reader.len(), 5);
+    assert_eq!(validate(&reader), Ok(()));
+}
+
+#[test]
+fn reports_where_invalid_utf8_starts() {
+    let error = UTF8Reader::from_bytes(b"[\"\xFF\"]").err().unwrap();
+    assert_eq!(error.valid_up_to(), 2);
+    assert_eq!(error.error_len(), Some(1));
+
+    // A sequence cut short at the end.
+    let error = UTF8Reader::from_bytes(b"[\"\xC3").err().unwrap();
+    assert_eq!(error.valid_up_to(), 2);
+    assert_eq!(error.error_len(), None);
+
+    let error = UTF8Reader::from_bytes(b"\xE2\x82").err().unwrap();
+    assert_eq!(error.valid_up_to(), 0);
+}
+
+#[test]
+fn converts_from_a_str() {
+    let reader: UTF8Reader = "{\"a\": [1]}".into();
+    assert_eq!(reader.len(), 10);
+    assert_eq!(validate(&reader), Ok(()));
+    assert!(validate(&UTF8Reader::from("[1,")).is_err());
+}
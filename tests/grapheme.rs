@@ -0,0 +1,117 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate, UTF8Reader};
+
+/// Reason, line, column and index of the error in each mode.
+fn failure(reader: &UTF8Reader) -> (String, usize, usize, usize) {
+    let error = validate(reader).unwrap_err();
+    return (
+        String::from(error.reason()),
+        error.line(),
+        error.column(),
+        error.index(),
+    );
+}
+
+#[test]
+fn ascii_has_the_same_units_in_both_modes() {
+    for document in [
+        "",
+        "{}",
+        "{\r\n  \na: [1, 2.5e3, true]\r\n}",
+        "[\r1\r,\n\r2]",
+        "\r\n\r\n",
+    ] {
+        let scalars = UTF8Reader::new(document);
+        let graphemes = UTF8Reader::new_grapheme(document);
+        assert_eq!(scalars.len(), document.len(), "{:?}", document);
+        assert_eq!(graphemes.len(), scalars.len(), "{:?}", document);
+    }
+}
+
+#[test]
+fn ascii_errors_are_at_the_same_position_in_both_modes() {
+    let document = "{\r\n\"a\":\r\n x}";
+    let expected = (
+        String::from("Invalid value at $.a: Unknown character: \"x\""),
+        3,
+        2,
+        10,
+    );
+    assert_eq!(failure(&UTF8Reader::new(document)), expected);
+    assert_eq!(failure(&UTF8Reader::new_grapheme(document)), expected);
+}
+
+#[test]
+fn a_combining_mark_inside_a_string_shifts_later_positions() {
+    let document = "[\"e\u{301}\", x]";
+    assert_eq!(UTF8Reader::new(document).len(), 9);
+    assert_eq!(UTF8Reader::new_grapheme(document).len(), 8);
+
+    let reason = String::from("Invalid value at $[1]: Unknown character: \"x\"");
+    assert_eq!(
+        failure(&UTF8Reader::new(document)),
+        (reason.clone(), 1, 8, 7)
+    );
+    assert_eq!(
+        failure(&UTF8Reader::new_grapheme(document)),
+        (reason, 1, 7, 6)
+    );
+}
+
+#[test]
+fn a_combining_mark_after_a_quote_merges_with_it() {
+    // Valid JSON: the string starts with a combining acute accent.
+    let document = "[\"\u{301}x\"]";
+    assert!(validate(&UTF8Reader::new(document)).is_ok());
+    assert_eq!(
+        failure(&UTF8Reader::new_grapheme(document)),
+        (
+            String::from("Invalid value at $[0]: Unknown character: \"\"\u{301}\""),
+            1,
+            2,
+            1
+        )
+    );
+
+    // Not JSON: the accent follows the closing quote.
+    let document = "[\"a\"\u{301}]";
+    assert_eq!(
+        failure(&UTF8Reader::new(document)),
+        (String::from("Invalid character: \"\u{301}\""), 1, 5, 4)
+    );
+    assert_eq!(
+        failure(&UTF8Reader::new_grapheme(document)),
+        (
+            String::from(
+                "Invalid value at $[0]: Incomplete string value, opened at line 1, column 2"
+            ),
+            1,
+            6,
+            5
+        )
+    );
+}
+
+#[test]
+fn a_combining_mark_after_a_digit_merges_with_it() {
+    let document = "[1\u{301}]";
+    assert_eq!(
+        failure(&UTF8Reader::new(document)),
+        (
+            String::from("Invalid value at $[0]: Invalid character in interger part: \"\\u{301}\""),
+            1,
+            3,
+            2
+        )
+    );
+    assert_eq!(
+        failure(&UTF8Reader::new_grapheme(document)),
+        (
+            String::from("Invalid value at $[0]: Unknown character: \"1\u{301}\""),
+            1,
+            2,
+            1
+        )
+    );
+}
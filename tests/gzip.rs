@@ -0,0 +1,65 @@
+#![allow(clippy::needless_return)]
+#![cfg(feature = "gzip")]
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rust_json::{validate_file, JsonFileError};
+use std::io::Write;
+use std::path::PathBuf;
+
+fn gzip_file(name: &str, content: &[u8]) -> PathBuf {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(content).unwrap();
+    let path = std::env::temp_dir().join(format!("rust-json-{}.json.gz", name));
+    std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+    return path;
+}
+
+fn invalid_reason(result: Result<(), JsonFileError>) -> String {
+    return match result {
+        Err(JsonFileError::Invalid(error)) => String::from(error.reason()),
+        other => panic!("expected a validation error, got {:?}", other),
+    };
+}
+
+#[test]
+fn validates_characters_split_between_reads() {
+    // Pushes a multi-byte character across every read boundary.
+    let text = "é€😀".repeat(5000);
+    let path = gzip_file("split", format!("[\"{}\"]", text).as_bytes());
+    assert!(validate_file(&path).is_ok());
+}
+
+#[test]
+fn reports_json_errors_in_the_inflated_text() {
+    let path = gzip_file("invalid", b"{\"a\": [1, 2,]}");
+    assert_eq!(
+        invalid_reason(validate_file(&path)),
+        "Invalid value at $.a[2]: Trailing comma before \"]\""
+    );
+}
+
+#[test]
+fn reports_invalid_utf8() {
+    let path = gzip_file("bad-byte", b"[\"a\xFF\"]");
+    assert_eq!(
+        invalid_reason(validate_file(&path)),
+        "Invalid UTF-8 byte 0xFF"
+    );
+
+    let path = gzip_file("cut", b"[\"\xE2\x82");
+    assert_eq!(
+        invalid_reason(validate_file(&path)),
+        "Input ends inside a UTF-8 sequence"
+    );
+}
+
+#[test]
+fn reports_corrupt_archives_as_decompression_errors() {
+    let path = std::env::temp_dir().join("rust-json-corrupt.json.gz");
+    std::fs::write(&path, [0x1F, 0x8B, 0x08, 0x00, 0x01, 0x02]).unwrap();
+    assert!(matches!(
+        validate_file(&path),
+        Err(JsonFileError::Decompress(_))
+    ));
+}
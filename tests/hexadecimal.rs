@@ -0,0 +1,94 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, ErrorKind, UTF8Reader, ValidateOptions,
+};
+
+fn check(input: &str, options: &ValidateOptions) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate_with_options(&UTF8Reader::new(input), options).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+fn hexadecimal() -> ValidateOptions {
+    return ValidateOptions::default().allow_hex_numbers(true);
+}
+
+#[test]
+fn hexadecimal_is_rejected_by_default() {
+    assert_eq!(
+        check("0xFF", &ValidateOptions::default()),
+        Err((
+            ErrorKind::InvalidNumber,
+            String::from("Invalid character after leading zero: \"x\""),
+            1,
+            2
+        ))
+    );
+    assert_eq!(
+        check("[0x10]", &ValidateOptions::default()),
+        Err((
+            ErrorKind::InvalidNumber,
+            String::from("Invalid value at $[0]: Invalid character after leading zero: \"x\""),
+            1,
+            3
+        ))
+    );
+}
+
+#[test]
+fn hexadecimal_integers_are_accepted_when_allowed() {
+    // `e` is a hexadecimal digit, not an exponent.
+    for input in ["0xFF", "-0x1a", "0X1", "[0x10, 0x1e5]"] {
+        assert_eq!(check(input, &hexadecimal()), Ok(()), "{}", input);
+    }
+
+    let value = parse_with_options(&UTF8Reader::new("[-0xFF, 0x1e5]"), &hexadecimal()).unwrap();
+    assert_eq!(value.get_index(0).and_then(|n| n.as_i64()), Some(-255));
+    assert_eq!(value.get_index(1).and_then(|n| n.as_i64()), Some(0x1e5));
+}
+
+#[test]
+fn hexadecimal_numbers_need_digits_and_no_fraction() {
+    assert_eq!(
+        check("0x", &hexadecimal()),
+        Err((
+            ErrorKind::InvalidNumber,
+            String::from("Incomplete number value"),
+            1,
+            1
+        ))
+    );
+    assert_eq!(
+        check("0xG", &hexadecimal()),
+        Err((
+            ErrorKind::InvalidNumber,
+            String::from("Invalid character after hexadecimal prefix: \"G\""),
+            1,
+            3
+        ))
+    );
+    assert_eq!(
+        check("0x1.5", &hexadecimal()),
+        Err((
+            ErrorKind::InvalidNumber,
+            String::from("Invalid character in hexadecimal number: \".\""),
+            1,
+            4
+        ))
+    );
+    assert_eq!(
+        check("00x1", &hexadecimal()),
+        Err((
+            ErrorKind::LeadingZero,
+            String::from("Leading zeros are not allowed"),
+            1,
+            2
+        ))
+    );
+}
@@ -0,0 +1,174 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    validate_str_with_options, BomPolicy, ErrorKind, IncrementalValidator, TopLevelType,
+    ValidateOptions, ValidationError,
+};
+
+// Documents that pass or fail anywhere in the grammar, for the strict options.
+const STRICT: [&str; 34] = [
+    "[1, \"a\", true, false, null, {\"b\": [2.5e-3]}]",
+    "  {\"a\" :\r\n 1 }  ",
+    "[tru]",
+    "[truex]",
+    "[True]",
+    "{\"a\": nul}",
+    "[Infinity]",
+    "[01]",
+    "[1.]",
+    "[-]",
+    "-",
+    "1e",
+    "[1x]",
+    "[1:]",
+    "[1,]",
+    "{\"a\": 1,}",
+    "{\"a\" 1}",
+    "{1: 2}",
+    "{\"a\": 1 \"b\"}",
+    "[1 2]",
+    "[}",
+    "{\"a\": }",
+    "[\"\\x\"]",
+    "[\"\\u12G4\"]",
+    "[\"a\u{1}\"]",
+    "{\"a\u{1}\": 1}",
+    "[\"abc",
+    "{\"k\": [\"abc",
+    "[true-]",
+    "[\u{a0}1]",
+    "[1]]",
+    "[1] x",
+    "{\"a\": {\"b\": [1, {\"c\": tru}]}}",
+    "\u{feff}[1]",
+];
+
+// Documents that only the relaxed options accept, and mistakes in them.
+const RELAXED: [&str; 14] = [
+    "{a: 'b', c_1: +1, d: .5, e: 5., f: 0x1F, g: -Infinity, h: NaN}",
+    "{a-b: 1}",
+    "{a b: 1}",
+    "{'a': 'b\\'c'}",
+    "['a', \"b\"]",
+    "[Infinit]",
+    "[-Infinit]",
+    "[NaNa]",
+    "[0x]",
+    "[+]",
+    "{1a: 2}",
+    "['abc",
+    "[Infinity\"a\"]",
+    "{a: 1,}",
+];
+
+fn incremental(
+    document: &str,
+    options: &ValidateOptions,
+    chunk: usize,
+) -> Result<(), ValidationError> {
+    let mut validator = IncrementalValidator::with_options(options.clone());
+    let chars: Vec<char> = document.chars().collect();
+    for piece in chars.chunks(chunk) {
+        validator.feed(&piece.iter().collect::<String>())?;
+    }
+    return validator.finish();
+}
+
+fn summary(
+    result: Result<(), ValidationError>,
+) -> Result<(), (String, usize, usize, usize, ErrorKind)> {
+    return result.map_err(|error| {
+        return (
+            String::from(error.reason()),
+            error.index(),
+            error.line(),
+            error.column(),
+            error.kind().clone(),
+        );
+    });
+}
+
+fn assert_agrees(documents: &[&str], options: &ValidateOptions) {
+    for document in documents {
+        let expected = summary(validate_str_with_options(document, options));
+        for chunk in [1, 2, 5, document.len().max(1)] {
+            let actual = summary(incremental(document, options, chunk));
+            assert_eq!(actual, expected, "{:?} in chunks of {}", document, chunk);
+        }
+    }
+}
+
+#[test]
+fn agrees_with_the_validator_on_strict_documents() {
+    assert_agrees(&STRICT, &ValidateOptions::default());
+}
+
+#[test]
+fn agrees_with_the_validator_on_relaxed_documents() {
+    assert_agrees(&RELAXED, &ValidateOptions::json5());
+    assert_agrees(&RELAXED, &ValidateOptions::default());
+}
+
+#[test]
+fn applies_the_options() {
+    let documents = [
+        "[01]",
+        "[1, 2, 3]",
+        "{\"a\": 1, \"b\": 2}",
+        "[[[1]]]",
+        "\"a/b\"",
+        "1",
+    ];
+    for options in [
+        ValidateOptions::default().allow_leading_zeros(true),
+        ValidateOptions::default().max_array_elements(2),
+        ValidateOptions::default().max_object_members(1),
+        ValidateOptions::default().max_total_nodes(3),
+        ValidateOptions::default().max_string_length(2),
+        ValidateOptions::default().require_top_level(TopLevelType::Object),
+        ValidateOptions::default().bom_policy(BomPolicy::Require),
+    ] {
+        assert_agrees(&documents, &options);
+    }
+}
+
+#[test]
+fn reports_a_mistyped_name_as_written() {
+    let error = incremental("[tru", &ValidateOptions::default(), 1).unwrap_err();
+    assert_eq!(
+        error.reason(),
+        "Invalid value at $[0]: Expected \"true\", but found \"tru\""
+    );
+    assert_eq!(error.kind(), &ErrorKind::InvalidLiteral);
+}
+
+#[test]
+fn leaves_a_name_open_at_the_end_of_a_chunk() {
+    let mut validator = IncrementalValidator::new();
+    assert!(!validator.feed("true").unwrap().complete);
+    assert!(validator.feed("x").is_ok());
+    assert_eq!(
+        validator.finish().unwrap_err().reason(),
+        "Expected \"true\", but found \"truex\""
+    );
+}
+
+#[test]
+fn counts_fed_bytes_against_the_size_limit() {
+    let mut validator = IncrementalValidator::with_options(ValidateOptions::default().max_bytes(4));
+    assert!(validator.feed("[12").is_ok());
+    let error = validator.feed(", 3]").unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &ErrorKind::DocumentTooLarge {
+            limit: 4,
+            actual: 7
+        }
+    );
+}
+
+#[test]
+fn agrees_on_nesting_too_deep() {
+    let document = format!("{}{}", "[".repeat(101), "]".repeat(101));
+    assert_agrees(&[&document], &ValidateOptions::default());
+}
@@ -0,0 +1,84 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    build_index, build_index_with_options, locate_pointer, UTF8Reader, ValidateOptions,
+};
+
+const DOCUMENT: &str = r#" {
+    "name": "index",
+    "list": [1, [2, 3], {"deep": [true, null]}],
+    "a/b": {"m~n": "escaped", "": 0},
+    "dup": 1,
+    "dup": 2,
+    "éé": "unicode",
+    "empty": {}
+} "#;
+
+#[test]
+fn agrees_with_locate_pointer() {
+    let document = UTF8Reader::new(DOCUMENT);
+    let index = build_index(&document).unwrap();
+
+    for pointer in [
+        "",
+        "/name",
+        "/list",
+        "/list/0",
+        "/list/1/1",
+        "/list/2/deep/1",
+        "/a~1b/m~0n",
+        "/a~1b/",
+        "/dup",
+        "/éé",
+        "/empty",
+        "/missing",
+        "/list/3",
+        "/list/01",
+        "/list/-",
+        "/name/0",
+        "/empty/x",
+        "name",
+        "/a~2b",
+    ] {
+        assert_eq!(
+            index.get(pointer),
+            locate_pointer(&document, pointer),
+            "{}",
+            pointer
+        );
+    }
+}
+
+#[test]
+fn spans_cover_the_value_as_written() {
+    let document = UTF8Reader::new(DOCUMENT);
+    let index = build_index(&document).unwrap();
+
+    let (start, end) = index.get("/list/2").unwrap();
+    let begin = document.byte_offset_of(start);
+    let finish = document.byte_offset_of(end);
+    assert_eq!(&DOCUMENT[begin..finish], r#"{"deep": [true, null]}"#);
+
+    // The first of duplicate keys wins.
+    let (start, end) = index.get("/dup").unwrap();
+    assert_eq!(
+        &DOCUMENT[document.byte_offset_of(start)..document.byte_offset_of(end)],
+        "1"
+    );
+}
+
+#[test]
+fn rejects_invalid_documents() {
+    let error = build_index(&UTF8Reader::new("{\"a\": [1,]}")).unwrap_err();
+    assert_eq!(error.column(), 10);
+}
+
+#[test]
+fn follows_the_options() {
+    let input = "{ key: ['a', +1] }";
+    let document = UTF8Reader::new(input);
+    assert!(build_index(&document).is_err());
+
+    let index = build_index_with_options(&document, &ValidateOptions::json5()).unwrap();
+    assert_eq!(index.get("/key/1"), Some((13, 15)));
+}
@@ -0,0 +1,37 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{is_valid, validate_str, ValidateResult};
+
+#[test]
+fn agrees_with_validate_str() {
+    for input in [
+        "{}",
+        " [1] ",
+        "\"a\"",
+        "0",
+        "",
+        "[1,]",
+        "nul",
+        "\"\u{0}\"",
+        "{} {}",
+    ] {
+        assert_eq!(is_valid(input), validate_str(input).is_ok(), "{:?}", input);
+    }
+}
+
+#[test]
+fn reads_well_in_conditionals() {
+    let documents = ["[1, 2]", "[1, 2", "{\"a\": null}", "{a: null}"];
+    let valid: Vec<&str> = documents
+        .iter()
+        .copied()
+        .filter(|input| is_valid(input))
+        .collect();
+    assert_eq!(valid, ["[1, 2]", "{\"a\": null}"]);
+}
+
+#[test]
+fn validate_result_is_the_public_result_type() {
+    let result: ValidateResult = validate_str("[]");
+    assert_eq!(result, Ok(()));
+}
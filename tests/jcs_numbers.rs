@@ -0,0 +1,65 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{format_number_canonical, JsonNumber};
+
+/// IEEE 754 bit patterns and their expected serialization, from RFC 8785
+/// Appendix B.
+const VECTORS: &[(u64, &str)] = &[
+    (0x0000000000000000, "0"),
+    (0x8000000000000000, "0"),
+    (0x0000000000000001, "5e-324"),
+    (0x8000000000000001, "-5e-324"),
+    (0x7fefffffffffffff, "1.7976931348623157e+308"),
+    (0xffefffffffffffff, "-1.7976931348623157e+308"),
+    (0x4340000000000000, "9007199254740992"),
+    (0xc340000000000000, "-9007199254740992"),
+    (0x4430000000000000, "295147905179352830000"),
+    (0x44b52d02c7e14af5, "9.999999999999997e+22"),
+    (0x44b52d02c7e14af6, "1e+23"),
+    (0x44b52d02c7e14af7, "1.0000000000000001e+23"),
+    (0x444b1ae4d6e2ef4e, "999999999999999700000"),
+    (0x444b1ae4d6e2ef4f, "999999999999999900000"),
+    (0x444b1ae4d6e2ef50, "1e+21"),
+    (0x3eb0c6f7a0b5ed8c, "9.999999999999997e-7"),
+    (0x3eb0c6f7a0b5ed8d, "0.000001"),
+    (0x41b3de4355555553, "333333333.3333332"),
+    (0x41b3de4355555554, "333333333.33333325"),
+    (0x41b3de4355555555, "333333333.3333333"),
+    (0x41b3de4355555556, "333333333.3333334"),
+    (0x41b3de4355555557, "333333333.33333343"),
+    (0xbecbf647612f3696, "-0.0000033333333333333333"),
+    (0x43143ff3c1cb0959, "1424953923781206.2"),
+];
+
+#[test]
+fn rfc8785_vectors() {
+    for (bits, expected) in VECTORS {
+        // `{:e}` is a lexeme that reads back as exactly this `f64`.
+        let lexeme = format!("{:e}", f64::from_bits(*bits));
+        let actual = format_number_canonical(&JsonNumber::new(&lexeme)).unwrap();
+        assert_eq!(actual, *expected, "bits {:016x}", bits);
+    }
+}
+
+#[test]
+fn source_spelling_is_ignored() {
+    for (lexeme, expected) in [
+        ("1.10", "1.1"),
+        ("1E2", "100"),
+        ("-0.0", "0"),
+        ("1e21", "1e+21"),
+    ] {
+        assert_eq!(
+            format_number_canonical(&JsonNumber::new(lexeme)).unwrap(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn non_finite_numbers_have_no_canonical_form() {
+    for lexeme in ["NaN", "Infinity", "-Infinity", "1e400", "-1e400"] {
+        let error = format_number_canonical(&JsonNumber::new(lexeme)).unwrap_err();
+        assert_eq!(error.lexeme(), lexeme);
+    }
+}
@@ -0,0 +1,108 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, to_string_with_options, validate_str, SerializeOptions, UTF8Reader,
+    ValidateOptions,
+};
+
+fn relaxed() -> ValidateOptions {
+    return ValidateOptions::json5().allow_leading_zeros(true);
+}
+
+fn serialize(input: &str, options: &SerializeOptions) -> Result<String, String> {
+    let value = parse_with_options(&UTF8Reader::new(input), &relaxed()).unwrap();
+    return to_string_with_options(&value, options).map_err(|error| error.to_string());
+}
+
+#[test]
+fn json5_numbers_are_written_in_decimal() {
+    let output = serialize(
+        "[0xFF, -0x10, +1, .5, -.5, 5., 5.e3, 007, -00.5, 1.50, 1E+02]",
+        &SerializeOptions::default(),
+    );
+    assert_eq!(
+        output.as_deref(),
+        Ok("[255,-16,1,0.5,-0.5,5,5e3,7,-0.5,1.50,1E+02]")
+    );
+}
+
+#[test]
+fn hexadecimal_wider_than_any_integer_type() {
+    let output = serialize(
+        "0x1000000000000000000000000000000000",
+        &SerializeOptions::default(),
+    );
+    assert_eq!(
+        output.as_deref(),
+        Ok("5444517870735015415413993718908291383296")
+    );
+}
+
+#[test]
+fn json5_documents_serialize_to_strict_json() {
+    for input in [
+        "{unquoted: 'single', \"list\": [0xff, +2, .25, 3., 010]}",
+        "[-0x0, +0, .0e1, 0.]",
+        "{'a': {b: [+.5]}}",
+    ] {
+        for options in [
+            SerializeOptions::default(),
+            SerializeOptions {
+                canonical_numbers: true,
+                ..SerializeOptions::default()
+            },
+        ] {
+            let output = serialize(input, &options).unwrap();
+            assert!(
+                validate_str(&output).is_ok(),
+                "{} became {}: {:?}",
+                input,
+                output,
+                validate_str(&output)
+            );
+        }
+    }
+}
+
+#[test]
+fn canonical_numbers_use_the_jcs_form() {
+    let options = SerializeOptions {
+        canonical_numbers: true,
+        ..SerializeOptions::default()
+    };
+    assert_eq!(
+        serialize("[0xFF, +1, .5, 5., 007, 1.10, 1e2, 1e21]", &options).as_deref(),
+        Ok("[255,1,0.5,5,7,1.1,100,1e+21]")
+    );
+}
+
+#[test]
+fn non_finite_numbers_are_an_error() {
+    let canonical = SerializeOptions {
+        canonical_numbers: true,
+        ..SerializeOptions::default()
+    };
+    for (input, lexeme) in [
+        ("[1, Infinity]", "Infinity"),
+        ("[-Infinity]", "-Infinity"),
+        ("{\"a\": NaN}", "NaN"),
+    ] {
+        let expected = format!("{} is not a finite number", lexeme);
+        assert_eq!(
+            serialize(input, &SerializeOptions::default()),
+            Err(expected.clone())
+        );
+        assert_eq!(serialize(input, &canonical), Err(expected));
+    }
+
+    // Valid JSON, but too large for the `f64` that canonical numbers go
+    // through.
+    assert_eq!(
+        serialize("1e400", &SerializeOptions::default()).as_deref(),
+        Ok("1e400")
+    );
+    assert_eq!(
+        serialize("1e400", &canonical),
+        Err(String::from("1e400 is not a finite number"))
+    );
+}
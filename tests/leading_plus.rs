@@ -0,0 +1,86 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, ErrorKind, JsonValue, UTF8Reader, ValidateOptions,
+};
+
+fn check(input: &str, options: &ValidateOptions) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate_with_options(&UTF8Reader::new(input), options).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+fn leading_plus() -> ValidateOptions {
+    return ValidateOptions::default().allow_leading_plus(true);
+}
+
+#[test]
+fn a_leading_plus_is_rejected_by_default() {
+    assert_eq!(
+        check("+1", &ValidateOptions::default()),
+        Err((
+            ErrorKind::Syntax,
+            String::from("A value may not begin with \"+\""),
+            1,
+            1
+        ))
+    );
+    assert_eq!(
+        check("[+1.5e3]", &ValidateOptions::default()),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Invalid value at $[0]: A value may not begin with \"+\""),
+            1,
+            2
+        ))
+    );
+}
+
+#[test]
+fn a_leading_plus_is_accepted_when_allowed() {
+    for input in ["+1", "[+1.5e3, +0, -1]"] {
+        assert_eq!(check(input, &leading_plus()), Ok(()), "{}", input);
+    }
+
+    let value = parse_with_options(&UTF8Reader::new("+1.5"), &leading_plus()).unwrap();
+    assert_eq!(value.as_f64(), Some(1.5));
+    assert!(matches!(value, JsonValue::Number(ref n) if n.as_str() == "+1.5"));
+}
+
+#[test]
+fn a_plus_must_be_followed_by_digits() {
+    assert_eq!(
+        check("+", &leading_plus()),
+        Err((
+            ErrorKind::InvalidNumber,
+            String::from("Number has no digits after leading plus"),
+            1,
+            2
+        ))
+    );
+    for (input, character) in [("++1", "+"), ("+-1", "-"), ("+x", "x")] {
+        assert_eq!(
+            check(input, &leading_plus()),
+            Err((
+                ErrorKind::InvalidNumber,
+                format!("Invalid character after leading plus: \"{}\"", character),
+                1,
+                2
+            ))
+        );
+    }
+    assert_eq!(
+        check("-+1", &leading_plus()),
+        Err((
+            ErrorKind::InvalidNumber,
+            String::from("Invalid character after leading minus: \"+\""),
+            1,
+            2
+        ))
+    );
+}
@@ -0,0 +1,44 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{parse_with_options, validate_str, JsonValue, UTF8Reader, ValidateOptions};
+
+fn parse_lenient(document: &str) -> JsonValue {
+    let options = ValidateOptions::default().allow_leading_zeros(true);
+    return parse_with_options(&UTF8Reader::new(document), &options).unwrap();
+}
+
+#[test]
+fn read_as_decimal() {
+    assert_eq!(parse_lenient("007").as_i64(), Some(7));
+    assert_eq!(parse_lenient("0123").as_i64(), Some(123));
+    assert_eq!(parse_lenient("-0089").as_i64(), Some(-89));
+    assert_eq!(
+        parse_lenient("[00]").get_index(0).unwrap().as_i64(),
+        Some(0)
+    );
+    assert_eq!(parse_lenient("007.50").as_f64(), Some(7.5));
+    assert_eq!(parse_lenient("010e1").as_f64(), Some(100.0));
+}
+
+#[test]
+fn ordinary_numbers_are_unchanged() {
+    assert_eq!(parse_lenient("0.5").as_f64(), Some(0.5));
+    assert_eq!(parse_lenient("0").as_i64(), Some(0));
+    assert!(validate_str("0.5").is_ok());
+}
+
+#[test]
+fn rejected_by_default() {
+    for document in ["007", "00", "-0123", "[1, 02]"] {
+        assert_eq!(
+            validate_str(document)
+                .unwrap_err()
+                .reason()
+                .rsplit(": ")
+                .next(),
+            Some("Leading zeros are not allowed"),
+            "{}",
+            document
+        );
+    }
+}
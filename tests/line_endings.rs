@@ -0,0 +1,72 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse, to_string_with_options, LineEnding, PrettyOptions, SerializeOptions, UTF8Reader,
+};
+
+const INPUT: &str = r#"{"a": [1, {}], "b": "x\ny"}"#;
+
+fn render(indent: Option<usize>, line_ending: LineEnding, trailing_newline: bool) -> String {
+    let value = parse(&UTF8Reader::new(INPUT)).unwrap();
+    let options = SerializeOptions {
+        indent,
+        line_ending,
+        trailing_newline,
+        ..SerializeOptions::default()
+    };
+    return to_string_with_options(&value, &options).unwrap();
+}
+
+#[test]
+fn pretty_prints_with_either_line_ending() {
+    assert_eq!(
+        render(Some(2), LineEnding::Lf, false),
+        "{\n  \"a\": [\n    1,\n    {}\n  ],\n  \"b\": \"x\\ny\"\n}"
+    );
+    assert_eq!(
+        render(Some(2), LineEnding::CrLf, false),
+        "{\r\n  \"a\": [\r\n    1,\r\n    {}\r\n  ],\r\n  \"b\": \"x\\ny\"\r\n}"
+    );
+}
+
+#[test]
+fn toggles_the_trailing_newline() {
+    assert!(render(Some(2), LineEnding::Lf, true).ends_with("}\n"));
+    assert!(render(Some(2), LineEnding::CrLf, true).ends_with("}\r\n"));
+    assert!(render(Some(2), LineEnding::CrLf, false).ends_with('}'));
+
+    // Compact output has no lines to end but the last.
+    assert_eq!(
+        render(None, LineEnding::CrLf, true),
+        "{\"a\":[1,{}],\"b\":\"x\\ny\"}\r\n"
+    );
+}
+
+#[test]
+fn defaults_keep_the_previous_output() {
+    let options = SerializeOptions::default();
+    assert_eq!(options.line_ending, LineEnding::Lf);
+    assert!(!options.trailing_newline);
+    assert_eq!(
+        render(None, LineEnding::Lf, false),
+        "{\"a\":[1,{}],\"b\":\"x\\ny\"}"
+    );
+}
+
+#[test]
+fn pretty_options_fill_in_the_serializer_options() {
+    let pretty = PrettyOptions::default();
+    assert_eq!(pretty.indent, 2);
+    assert_eq!(pretty.line_ending, LineEnding::Lf);
+    assert!(!pretty.trailing_newline);
+
+    let options = SerializeOptions::from(PrettyOptions {
+        indent: 4,
+        line_ending: LineEnding::CrLf,
+        trailing_newline: true,
+    });
+    assert_eq!(options.indent, Some(4));
+    assert_eq!(options.line_ending, LineEnding::CrLf);
+    assert!(options.trailing_newline);
+    assert!(!options.canonical_numbers);
+}
@@ -0,0 +1,104 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate, ErrorKind, IncrementalValidator, UTF8Reader};
+
+fn check(input: &str) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate(&UTF8Reader::new(input)).map_err(|error| {
+        (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        )
+    });
+}
+
+fn invalid(reason: &str, column: usize) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return Err((ErrorKind::InvalidLiteral, String::from(reason), 1, column));
+}
+
+#[test]
+fn reports_a_literal_cut_short() {
+    assert_eq!(
+        check("tru"),
+        invalid("Expected \"true\", but found \"tru\"", 1)
+    );
+    assert_eq!(
+        check("nul"),
+        invalid("Expected \"null\", but found \"nul\"", 1)
+    );
+    assert_eq!(check("t"), invalid("Expected \"true\", but found \"t\"", 1));
+    assert_eq!(
+        check("[fals, 1]"),
+        invalid(
+            "Invalid value at $[0]: Expected \"false\", but found \"fals\"",
+            2
+        )
+    );
+}
+
+#[test]
+fn reports_a_literal_run_on_with_more_letters() {
+    assert_eq!(
+        check("truer"),
+        invalid("Expected \"true\", but found \"truer\"", 1)
+    );
+    assert_eq!(
+        check("{\"a\": nulll}"),
+        invalid(
+            "Invalid value at $.a: Expected \"null\", but found \"nulll\"",
+            7
+        )
+    );
+    assert_eq!(
+        check("[tru3]"),
+        invalid(
+            "Invalid value at $[0]: Expected \"true\", but found \"tru3\"",
+            2
+        )
+    );
+    assert_eq!(
+        check("[true1]"),
+        invalid(
+            "Invalid value at $[0]: Expected \"true\", but found \"true1\"",
+            2
+        )
+    );
+}
+
+#[test]
+fn points_out_that_literal_names_are_lowercase() {
+    assert_eq!(
+        check("False"),
+        invalid(
+            "Expected \"false\", but found \"False\"; literal names are lowercase",
+            1
+        )
+    );
+    assert_eq!(
+        check("nULL"),
+        invalid("Expected \"null\", but found \"nULL\"", 1)
+    );
+}
+
+#[test]
+fn reports_the_same_token_incrementally() {
+    for input in ["tru", "truer", "nul", "False", "[tru3]"] {
+        let mut validator = IncrementalValidator::new();
+        let error = validator
+            .feed(input)
+            .and_then(|_| validator.finish())
+            .unwrap_err();
+        assert_eq!(
+            Err((
+                error.kind().clone(),
+                String::from(error.reason()),
+                error.line(),
+                error.column()
+            )),
+            check(input),
+            "{:?}",
+            input
+        );
+    }
+}
@@ -0,0 +1,83 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    decode_string, decode_string_with_options, parse_with_options, JsonValue, LoneSurrogatePolicy,
+    UTF8Reader, ValidateOptions,
+};
+
+const LONE_HIGH: &str = r#""a\uD800b""#;
+const LONE_LOW: &str = r#""a\uDC00b""#;
+const PAIR: &str = r#""a\uD83D\uDE00b""#;
+
+fn decode(input: &str, policy: LoneSurrogatePolicy) -> Result<String, String> {
+    let options = ValidateOptions::default().lone_surrogate_policy(policy);
+    let (result, _) = decode_string_with_options(&UTF8Reader::new(input), 0, &options);
+    return result.map_err(|error| String::from(error.reason()));
+}
+
+#[test]
+fn errors_by_default() {
+    for input in [LONE_HIGH, LONE_LOW] {
+        let (result, _) = decode_string(&UTF8Reader::new(input), 0);
+        assert!(result.is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn error_policy_rejects_lone_surrogates() {
+    assert_eq!(
+        decode(LONE_HIGH, LoneSurrogatePolicy::Error),
+        Err(String::from(
+            "Unpaired surrogate \\uD800 can not be decoded"
+        ))
+    );
+    assert_eq!(
+        decode(LONE_LOW, LoneSurrogatePolicy::Error),
+        Err(String::from(
+            "Unpaired surrogate \\uDC00 can not be decoded"
+        ))
+    );
+    assert_eq!(
+        decode(PAIR, LoneSurrogatePolicy::Error),
+        Ok(String::from("a\u{1F600}b"))
+    );
+}
+
+#[test]
+fn replace_policy_substitutes_the_replacement_character() {
+    assert_eq!(
+        decode(LONE_HIGH, LoneSurrogatePolicy::Replace),
+        Ok(String::from("a\u{FFFD}b"))
+    );
+    assert_eq!(
+        decode(LONE_LOW, LoneSurrogatePolicy::Replace),
+        Ok(String::from("a\u{FFFD}b"))
+    );
+    assert_eq!(
+        decode(PAIR, LoneSurrogatePolicy::Replace),
+        Ok(String::from("a\u{1F600}b"))
+    );
+    // A high surrogate followed by another high one is two lone surrogates.
+    assert_eq!(
+        decode(r#""\uD800\uD800""#, LoneSurrogatePolicy::Replace),
+        Ok(String::from("\u{FFFD}\u{FFFD}"))
+    );
+}
+
+#[test]
+fn parse_follows_the_policy() {
+    let input = r#"{"\uDC00": ["\uD800"]}"#;
+    let reader = UTF8Reader::new(input);
+
+    assert!(parse_with_options(&reader, &ValidateOptions::default()).is_err());
+
+    let options = ValidateOptions::default().lone_surrogate_policy(LoneSurrogatePolicy::Replace);
+    let value = parse_with_options(&reader, &options).unwrap();
+    assert_eq!(
+        value,
+        JsonValue::Object(vec![(
+            String::from("\u{FFFD}"),
+            JsonValue::Array(vec![JsonValue::String(String::from("\u{FFFD}"))])
+        )])
+    );
+}
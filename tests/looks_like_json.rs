@@ -0,0 +1,40 @@
+use rust_json::looks_like_json;
+
+#[test]
+fn json_starts() {
+    for input in [
+        "{\"a\": 1}",
+        "  [1, 2",
+        "\u{FEFF}\r\n\t\"text\"",
+        "42",
+        "-0.5",
+        "-",
+        "true",
+        "fals",
+        "null\n",
+        "[]",
+    ] {
+        assert!(looks_like_json(input), "{:?}", input);
+    }
+}
+
+#[test]
+fn other_formats() {
+    for input in [
+        "",
+        "   \n",
+        "---\nname: x\n",
+        "- item\n- other\n",
+        "name: value\n",
+        "null: value\n",
+        "nothing here",
+        "trueish",
+        "id,name,email\n1,a,b\n",
+        "<?xml version=\"1.0\"?><root/>",
+        "<html>",
+        "# comment",
+        "'single'",
+    ] {
+        assert!(!looks_like_json(input), "{:?}", input);
+    }
+}
@@ -0,0 +1,80 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, ErrorKind, UTF8Reader, ValidateOptions,
+};
+
+fn check(input: &str, limit: usize) -> Result<(), (ErrorKind, String, usize, usize)> {
+    let options = ValidateOptions::default().max_bytes(limit);
+    return validate_with_options(&UTF8Reader::new(input), &options).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+#[test]
+fn accepts_a_document_exactly_at_the_limit() {
+    assert_eq!(check("[1,2,3]", 7), Ok(()));
+    assert_eq!(check("[1,2,3]", 8), Ok(()));
+}
+
+#[test]
+fn rejects_a_document_one_byte_over_the_limit() {
+    assert_eq!(
+        check("[1, 2,3]", 7),
+        Err((
+            ErrorKind::DocumentTooLarge {
+                limit: 7,
+                actual: 8
+            },
+            String::from("JSON document is 8 bytes long, which exceeds the limit of 7 bytes"),
+            1,
+            1
+        ))
+    );
+}
+
+#[test]
+fn counts_bytes_rather_than_characters() {
+    // Four characters, six bytes.
+    let input = "\"éé\"";
+    assert_eq!(check(input, 6), Ok(()));
+    assert_eq!(
+        check(input, 5),
+        Err((
+            ErrorKind::DocumentTooLarge {
+                limit: 5,
+                actual: 6
+            },
+            String::from("JSON document is 6 bytes long, which exceeds the limit of 5 bytes"),
+            1,
+            1
+        ))
+    );
+}
+
+#[test]
+fn is_checked_before_anything_else() {
+    let error = check("[1,2,3] trailing", 8).unwrap_err();
+    assert_eq!(
+        error.0,
+        ErrorKind::DocumentTooLarge {
+            limit: 8,
+            actual: 16
+        }
+    );
+
+    let options = ValidateOptions::default().max_bytes(2);
+    let error = parse_with_options(&UTF8Reader::new("[1]"), &options).unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &ErrorKind::DocumentTooLarge {
+            limit: 2,
+            actual: 3
+        }
+    );
+}
@@ -0,0 +1,71 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, ErrorKind, IncrementalValidator, UTF8Reader,
+    ValidateOptions, Validator,
+};
+
+fn nested(depth: usize) -> String {
+    return format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+}
+
+fn check(input: &str, options: &ValidateOptions) -> Result<(), (ErrorKind, usize, usize)> {
+    return validate_with_options(&UTF8Reader::new(input), options)
+        .map_err(|error| (error.kind().clone(), error.line(), error.column()));
+}
+
+#[test]
+fn defaults_to_100_levels() {
+    let options = ValidateOptions::default();
+    assert_eq!(check(&nested(100), &options), Ok(()));
+    assert_eq!(
+        check(&nested(101), &options),
+        Err((ErrorKind::TooDeep, 1, 101))
+    );
+}
+
+#[test]
+fn lowers_the_limit() {
+    let options = ValidateOptions::default().max_depth(2);
+    assert_eq!(check("[[1]]", &options), Ok(()));
+    assert_eq!(check("{\"a\": [1]}", &options), Ok(()));
+    assert_eq!(check("[[[1]]]", &options), Err((ErrorKind::TooDeep, 1, 3)));
+    assert_eq!(
+        check("{\"a\": {\"b\": {}}}", &options),
+        Err((ErrorKind::TooDeep, 1, 13))
+    );
+}
+
+#[test]
+fn raises_the_limit() {
+    let options = ValidateOptions::default().max_depth(200);
+    assert_eq!(check(&nested(200), &options), Ok(()));
+    assert_eq!(
+        check(&nested(201), &options),
+        Err((ErrorKind::TooDeep, 1, 201))
+    );
+    assert!(parse_with_options(&UTF8Reader::new(&nested(200)), &options).is_ok());
+}
+
+#[test]
+fn is_enforced_by_the_incremental_validator() {
+    let mut validator = IncrementalValidator::with_options(ValidateOptions::default().max_depth(2));
+    validator.feed("[[").unwrap();
+    let error = validator.feed("[").unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::TooDeep);
+    assert_eq!(error.column(), 3);
+
+    let mut validator =
+        IncrementalValidator::with_options(ValidateOptions::default().max_depth(150));
+    validator.feed(&nested(150)).unwrap();
+    assert!(validator.finish().is_ok());
+}
+
+#[test]
+fn builder_and_options_share_the_setter() {
+    let validator = Validator::new().max_depth(200).warn_depth(150);
+    assert_eq!(validator.options().max_depth, Some(200));
+    assert_eq!(validator.options().warn_depth, Some(150));
+    assert!(validator.validate(&nested(200)).is_ok());
+    assert!(Validator::new().validate(&nested(200)).is_err());
+}
@@ -0,0 +1,69 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    validate_with_options, ErrorKind, IncrementalValidator, UTF8Reader, ValidateOptions,
+};
+
+fn check(input: &str, limit: usize) -> Result<(), (ErrorKind, String, usize, usize)> {
+    let options = ValidateOptions::default().max_escapes_per_string(limit);
+    return validate_with_options(&UTF8Reader::new(input), &options).map_err(|error| {
+        (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        )
+    });
+}
+
+fn too_many(limit: usize, column: usize) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return Err((
+        ErrorKind::TooManyEscapes { limit },
+        format!("String has more than {} escape sequences", limit),
+        1,
+        column,
+    ));
+}
+
+#[test]
+fn accepts_a_string_at_the_limit() {
+    assert_eq!(check(r#""\n\t""#, 2), Ok(()));
+    assert_eq!(check(r#""\\\\""#, 2), Ok(()));
+    assert_eq!(check(r#""\u0041\u0042""#, 2), Ok(()));
+    assert_eq!(check(r#""\u0041\u0042\u0043""#, 2), too_many(2, 14));
+}
+
+#[test]
+fn rejects_the_escape_past_the_limit() {
+    assert_eq!(check(r#""\n\t\r""#, 2), too_many(2, 6));
+    assert_eq!(check(r#""a\n""#, 0), too_many(0, 3));
+}
+
+#[test]
+fn counts_each_string_separately() {
+    assert_eq!(check(r#"["\n\t", "\r\n", {"\b\f": "\"\/"}]"#, 2), Ok(()));
+}
+
+#[test]
+fn limits_keys_too() {
+    assert_eq!(check(r#"{"a\nb\tc\"": 1}"#, 2), too_many(2, 10));
+}
+
+#[test]
+fn has_no_limit_by_default() {
+    let input = format!("\"{}\"", "\\n".repeat(10_000));
+    assert!(validate_with_options(&UTF8Reader::new(&input), &ValidateOptions::default()).is_ok());
+}
+
+#[test]
+fn is_enforced_by_the_incremental_validator() {
+    let options = ValidateOptions::default().max_escapes_per_string(2);
+    let mut validator = IncrementalValidator::with_options(options);
+    validator.feed(r#"["\n\t", "\n"#).unwrap();
+    let error = validator
+        .feed(r#"\t\r"]"#)
+        .and_then(|_| validator.finish())
+        .unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::TooManyEscapes { limit: 2 });
+    assert_eq!((error.line(), error.column()), (1, 15));
+}
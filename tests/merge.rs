@@ -0,0 +1,71 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{merge, parse, to_string, JsonValue, MergeOptions, UTF8Reader};
+
+fn value(input: &str) -> JsonValue {
+    return parse(&UTF8Reader::new(input)).unwrap();
+}
+
+fn merged(base: &str, over: &str, concat_arrays: bool) -> String {
+    let options = MergeOptions { concat_arrays };
+    return to_string(&merge(value(base), value(over), options)).unwrap();
+}
+
+#[test]
+fn merges_nested_objects_key_by_key() {
+    assert_eq!(
+        merged(
+            r#"{"server": {"host": "localhost", "port": 80}, "debug": false}"#,
+            r#"{"server": {"port": 8080, "tls": true}}"#,
+            false
+        ),
+        r#"{"server":{"host":"localhost","port":8080,"tls":true},"debug":false}"#
+    );
+}
+
+#[test]
+fn appends_new_keys_after_the_base_keys() {
+    assert_eq!(
+        merged(r#"{"b": 1, "a": 2}"#, r#"{"c": 3, "a": 4}"#, false),
+        r#"{"b":1,"a":4,"c":3}"#
+    );
+}
+
+#[test]
+fn replaces_arrays_by_default() {
+    assert_eq!(
+        merged(r#"{"tags": [1, 2]}"#, r#"{"tags": [3]}"#, false),
+        r#"{"tags":[3]}"#
+    );
+}
+
+#[test]
+fn concatenates_arrays_when_asked() {
+    assert_eq!(
+        merged(r#"{"tags": [1, 2]}"#, r#"{"tags": [3]}"#, true),
+        r#"{"tags":[1,2,3]}"#
+    );
+    assert_eq!(merged("[[1]]", "[[2]]", true), "[[1],[2]]");
+}
+
+#[test]
+fn takes_scalars_from_the_override() {
+    assert_eq!(merged("1", "2", false), "2");
+    assert_eq!(
+        merged(r#"{"a": "x"}"#, r#"{"a": null}"#, false),
+        r#"{"a":null}"#
+    );
+}
+
+#[test]
+fn takes_the_override_when_the_types_differ() {
+    assert_eq!(
+        merged(r#"{"a": {"b": 1}}"#, r#"{"a": [1]}"#, true),
+        r#"{"a":[1]}"#
+    );
+    assert_eq!(
+        merged(r#"{"a": [1]}"#, r#"{"a": {"b": 1}}"#, true),
+        r#"{"a":{"b":1}}"#
+    );
+    assert_eq!(merged(r#"{"a": 1}"#, "[1]", true), "[1]");
+}
@@ -0,0 +1,59 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{parse, to_string, JsonValue, UTF8Reader};
+
+fn value(document: &str) -> JsonValue {
+    return parse(&UTF8Reader::new(document)).unwrap();
+}
+
+#[test]
+fn insert_replaces_or_appends() {
+    let mut object = value(r#"{"a": 1, "b": 2}"#);
+    assert_eq!(object.insert("a", value("true")), Some(value("1")));
+    assert_eq!(object.insert("c", value("null")), None);
+    assert_eq!(to_string(&object).unwrap(), r#"{"a":true,"b":2,"c":null}"#);
+}
+
+#[test]
+fn remove_takes_the_first_member() {
+    let mut object = value(r#"{"a": 1, "b": 2, "a": 3}"#);
+    assert_eq!(object.remove("a"), Some(value("1")));
+    assert_eq!(object.remove("z"), None);
+    assert_eq!(to_string(&object).unwrap(), r#"{"b":2,"a":3}"#);
+}
+
+#[test]
+fn push_and_remove_index() {
+    let mut array = value("[1, 2]");
+    assert!(array.push(value("3")));
+    assert_eq!(array.remove_index(0), Some(value("1")));
+    assert_eq!(array.remove_index(5), None);
+    assert_eq!(to_string(&array).unwrap(), "[2,3]");
+}
+
+#[test]
+fn take_leaves_null() {
+    let mut object = value(r#"{"a": [1]}"#);
+    if let JsonValue::Object(members) = &mut object {
+        assert_eq!(members[0].1.take(), value("[1]"));
+    }
+    assert_eq!(to_string(&object).unwrap(), r#"{"a":null}"#);
+}
+
+#[test]
+fn wrong_variants_are_left_alone() {
+    for document in ["null", "true", "1", "\"a\"", "[]", "{}"] {
+        let original = value(document);
+        let mut edited = original.clone();
+
+        if !matches!(original, JsonValue::Object(_)) {
+            assert_eq!(edited.insert("a", value("1")), None);
+            assert_eq!(edited.remove("a"), None);
+        }
+        if !matches!(original, JsonValue::Array(_)) {
+            assert!(!edited.push(value("1")));
+            assert_eq!(edited.remove_index(0), None);
+        }
+        assert_eq!(edited, original, "{}", document);
+    }
+}
@@ -0,0 +1,77 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_with_options, ErrorKind, UTF8Reader, ValidateOptions};
+
+fn check(input: &str) -> Result<(), (String, usize)> {
+    let options = ValidateOptions::default().allow_non_finite(true);
+    return validate_with_options(&UTF8Reader::new(input), &options).map_err(|error| {
+        assert_eq!(error.kind(), &ErrorKind::InvalidLiteral, "{}", input);
+        return (String::from(error.reason()), error.column());
+    });
+}
+
+fn rejected(reason: &str, column: usize) -> Result<(), (String, usize)> {
+    return Err((String::from(reason), column));
+}
+
+#[test]
+fn accepts_the_names_when_enabled() {
+    for input in ["Infinity", "-Infinity", "NaN", "[NaN, -Infinity, Infinity]"] {
+        assert_eq!(check(input), Ok(()), "{}", input);
+    }
+}
+
+#[test]
+fn rejects_the_names_by_default() {
+    for input in ["Infinity", "-Infinity", "NaN"] {
+        assert!(
+            validate_with_options(&UTF8Reader::new(input), &ValidateOptions::default()).is_err()
+        );
+    }
+}
+
+#[test]
+fn reports_a_truncated_name_as_written() {
+    assert_eq!(
+        check("Infinit"),
+        rejected("Expected \"Infinity\", but found \"Infinit\"", 1)
+    );
+    assert_eq!(
+        check("[-Infinit]"),
+        rejected(
+            "Invalid value at $[0]: Expected \"Infinity\", but found \"Infinit\"",
+            3
+        )
+    );
+    assert_eq!(
+        check("Na"),
+        rejected("Expected \"NaN\", but found \"Na\"", 1)
+    );
+}
+
+#[test]
+fn reports_an_overlong_or_miscased_name_as_written() {
+    assert_eq!(
+        check("Infinityy"),
+        rejected("Expected \"Infinity\", but found \"Infinityy\"", 1)
+    );
+    assert_eq!(
+        check("NaNa"),
+        rejected("Expected \"NaN\", but found \"NaNa\"", 1)
+    );
+    assert_eq!(
+        check("[1, Nan]"),
+        rejected(
+            "Invalid value at $[1]: Expected \"NaN\", but found \"Nan\"",
+            5
+        )
+    );
+}
+
+#[test]
+fn words_a_truncated_name_like_a_truncated_literal() {
+    assert_eq!(
+        check("tru"),
+        rejected("Expected \"true\", but found \"tru\"", 1)
+    );
+}
@@ -0,0 +1,79 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_with_warnings, ErrorKind, UTF8Reader, ValidateOptions};
+
+/// The reason and column of each warning.
+fn warnings(input: &str) -> Vec<(String, usize)> {
+    let options = ValidateOptions::default().warn_noncharacters(true);
+    let (result, warnings) = validate_with_warnings(&UTF8Reader::new(input), &options);
+    assert!(result.is_ok(), "{}", input);
+    return warnings
+        .iter()
+        .map(|warning| {
+            assert_eq!(warning.kind(), &ErrorKind::Noncharacter);
+            return (String::from(warning.reason()), warning.column());
+        })
+        .collect();
+}
+
+#[test]
+fn warns_about_a_noncharacter_escape() {
+    assert_eq!(
+        warnings(r#"["ok", "a\uFFFF"]"#),
+        vec![(
+            String::from("Escape decodes to the noncharacter U+FFFF"),
+            10
+        )]
+    );
+    assert_eq!(
+        warnings(r#""\uFDD0\uFDEF\uFDF0""#),
+        vec![
+            (String::from("Escape decodes to the noncharacter U+FDD0"), 2),
+            (String::from("Escape decodes to the noncharacter U+FDEF"), 8),
+        ]
+    );
+}
+
+#[test]
+fn warns_about_a_noncharacter_from_a_surrogate_pair() {
+    // U+1FFFE and U+10FFFF
+    assert_eq!(
+        warnings(r#"{"k": "\uD83F\uDFFE", "m": "\uDBFF\uDFFF"}"#),
+        vec![
+            (
+                String::from("Escape decodes to the noncharacter U+1FFFE"),
+                8
+            ),
+            (
+                String::from("Escape decodes to the noncharacter U+10FFFF"),
+                29
+            ),
+        ]
+    );
+}
+
+#[test]
+fn checks_keys_too() {
+    assert_eq!(warnings(r#"{"\uFFFE": 1}"#).len(), 1);
+}
+
+#[test]
+fn leaves_other_text_alone() {
+    // Escaped but not a noncharacter, a pair for an ordinary character, an
+    // escaped backslash, a lone surrogate, and a raw U+FFFF that no escape
+    // produced.
+    let input = concat!(
+        r#"["\uFFFD", "\uD83D\uDE00", "\\uFFFF", "\uD800", ""#,
+        "\u{FFFF}",
+        r#""]"#
+    );
+    assert!(warnings(input).is_empty());
+}
+
+#[test]
+fn is_off_by_default() {
+    let (result, warnings) =
+        validate_with_warnings(&UTF8Reader::new(r#""\uFFFF""#), &ValidateOptions::default());
+    assert!(result.is_ok());
+    assert!(warnings.is_empty());
+}
@@ -0,0 +1,122 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_str, ErrorKind};
+
+fn check(input: &str) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate_str(input).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+#[test]
+fn empty_and_single_member_objects_are_valid() {
+    for input in [
+        "{}",
+        "{ \n }",
+        "{\"a\":1}",
+        "{ \"a\" : 1 }",
+        "{\"a\":{},\"b\":[]}",
+    ] {
+        assert_eq!(check(input), Ok(()), "{:?}", input);
+    }
+}
+
+#[test]
+fn a_trailing_comma_is_rejected() {
+    assert_eq!(
+        check("{\"a\":1,}"),
+        Err((
+            ErrorKind::TrailingComma,
+            String::from("Trailing comma before \"}\""),
+            1,
+            8
+        ))
+    );
+    assert_eq!(
+        check("{\"a\":1 , }"),
+        Err((
+            ErrorKind::TrailingComma,
+            String::from("Trailing comma before \"}\""),
+            1,
+            10
+        ))
+    );
+}
+
+#[test]
+fn a_closing_brace_is_only_allowed_where_a_member_could_end() {
+    assert_eq!(
+        check("{"),
+        Err((
+            ErrorKind::UnexpectedEnd,
+            String::from("Unexpected end of input, expected an object key or \"}\""),
+            1,
+            2
+        ))
+    );
+    assert_eq!(
+        check("{\"a\":1,"),
+        Err((
+            ErrorKind::UnexpectedEnd,
+            String::from("Unexpected end of input, expected an object key"),
+            1,
+            8
+        ))
+    );
+    assert_eq!(
+        check("{\"a\":1"),
+        Err((
+            ErrorKind::UnexpectedEnd,
+            String::from("Unexpected end of input, expected \",\" or \"}\""),
+            1,
+            7
+        ))
+    );
+}
+
+#[test]
+fn members_need_a_key_a_colon_and_a_value() {
+    let invalid_key = |column| {
+        return Err((
+            ErrorKind::InvalidKey,
+            String::from("Object key should be a valid string"),
+            1,
+            column,
+        ));
+    };
+    assert_eq!(check("{,}"), invalid_key(2));
+    assert_eq!(check("{\"a\":1,,\"b\":2}"), invalid_key(8));
+
+    assert_eq!(
+        check("{\"a\" 1}"),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Invalid character after object key: \"1\""),
+            1,
+            6
+        ))
+    );
+    assert_eq!(
+        check("{\"a\":}"),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Invalid value at $.a: Unknown character: \"}\""),
+            1,
+            6
+        ))
+    );
+    assert_eq!(
+        check("{\"a\":1 \"b\":2}"),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Invalid character after object value: \"\"\""),
+            1,
+            8
+        ))
+    );
+}
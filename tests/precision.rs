@@ -0,0 +1,59 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_with_warnings, ErrorKind, UTF8Reader, ValidateOptions};
+
+/// The reasons of every precision warning for `document`, which must be valid.
+fn warnings(document: &str) -> Vec<String> {
+    let options = ValidateOptions::default().warn_precision_loss(true);
+    let (result, warnings) = validate_with_warnings(&UTF8Reader::new(document), &options);
+    assert!(result.is_ok());
+    return warnings
+        .into_iter()
+        .filter(|warning| *warning.kind() == ErrorKind::PrecisionLoss)
+        .map(|warning| String::from(warning.reason()))
+        .collect();
+}
+
+#[test]
+fn integers_beyond_2_pow_53() {
+    assert_eq!(
+        warnings("[9007199254740992, 9007199254740993, -9007199254740993]"),
+        [
+            "Integer \"9007199254740993\" is beyond 2^53 and may lose precision elsewhere",
+            "Integer \"-9007199254740993\" is beyond 2^53 and may lose precision elsewhere",
+        ]
+    );
+}
+
+#[test]
+fn long_mantissa_floats() {
+    assert_eq!(
+        warnings("[0.1, 0.30000000000000004, 3.141592653589793238462643383279]"),
+        ["Number \"3.141592653589793238462643383279\" does not survive a round trip through a double"]
+    );
+}
+
+#[test]
+fn out_of_range_floats() {
+    assert_eq!(warnings("[1e400, 1e-400]").len(), 2);
+}
+
+#[test]
+fn warnings_carry_positions() {
+    let options = ValidateOptions::default().warn_precision_loss(true);
+    let (_, warnings) =
+        validate_with_warnings(&UTF8Reader::new("[1,\n 9007199254740993]"), &options);
+    assert_eq!((warnings[0].line(), warnings[0].column()), (2, 2));
+}
+
+#[test]
+fn extreme_exponents() {
+    assert_eq!(
+        warnings("[0e9223372036854775807, 1e-9223372036854775808]"),
+        ["Number \"1e-9223372036854775808\" does not survive a round trip through a double"]
+    );
+    assert_eq!(
+        warnings("[1e9223372036854775807, 0.01e-9223372036854775808]").len(),
+        2
+    );
+}
@@ -0,0 +1,133 @@
+#![allow(clippy::needless_return)]
+
+use proptest::prelude::*;
+
+use rust_json::{
+    parse, parse_document, parse_document_with_options, to_string, BomPolicy, Document, JsonNumber,
+    JsonValue, Node, UTF8Reader, ValidateOptions,
+};
+
+fn round_trip(input: &str) -> String {
+    return parse_document(&UTF8Reader::new(input)).unwrap().to_string();
+}
+
+#[test]
+fn keeps_whitespace_and_lexemes() {
+    for input in [
+        "0",
+        "  \"\\u00e9\\/\"  \n",
+        "[]",
+        "[ \n ]",
+        "{\t}",
+        "[1.50E+02 , -0,1e-0\r\n]",
+        "{ \"b\" : 1 ,\"a\":[ true,false , null ] }",
+        "\r\n{\n  \"nested\": {\n    \"deep\": [[], [{}], {\"x\": \"y\"}]\n  }\n}\n",
+        "{\"dup\": 1, \"dup\": 2}",
+    ] {
+        assert_eq!(round_trip(input), input);
+    }
+}
+
+#[test]
+fn keeps_relaxed_syntax_the_options_allow() {
+    let input = "\u{FEFF}\u{00A0}{ 'a' \u{2003}: 0xFF, b: [.5, +1] }\u{3000}";
+    let options = ValidateOptions::json5().bom_policy(BomPolicy::Allow);
+    let document = parse_document_with_options(&UTF8Reader::new(input), &options).unwrap();
+    assert_eq!(document.to_string(), input);
+}
+
+#[test]
+fn rejects_invalid_input() {
+    assert!(parse_document(&UTF8Reader::new("[1,]")).is_err());
+}
+
+#[test]
+fn edits_leave_the_rest_alone() {
+    let input = "{\n  \"name\": \"old\",\n  \"list\": [ 1, 2 ]\n}\n";
+    let mut document = parse_document(&UTF8Reader::new(input)).unwrap();
+
+    if let Node::Object(members, _) = &mut document.value {
+        members[0].value = Node::from(&JsonValue::String(String::from("new")));
+        members[1].value = Node::from(&parse(&UTF8Reader::new("{\"a\": [3]}")).unwrap());
+    }
+    assert_eq!(
+        document.to_string(),
+        "{\n  \"name\": \"new\",\n  \"list\": {\"a\":[3]}\n}\n"
+    );
+}
+
+fn gap(gaps: &[&str], next: &mut usize, output: &mut String) {
+    output.push_str(gaps[*next % gaps.len()]);
+    *next += 1;
+}
+
+/// Writes `value` with whitespace drawn in turn from `gaps` wherever JSON
+/// allows it.
+fn render(value: &JsonValue, gaps: &[&str], next: &mut usize, output: &mut String) {
+    match value {
+        JsonValue::Array(elements) => {
+            output.push('[');
+            gap(gaps, next, output);
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                    gap(gaps, next, output);
+                }
+                render(element, gaps, next, output);
+                gap(gaps, next, output);
+            }
+            output.push(']');
+        }
+        JsonValue::Object(members) => {
+            output.push('{');
+            gap(gaps, next, output);
+            for (i, (key, member)) in members.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                    gap(gaps, next, output);
+                }
+                output.push_str(&to_string(&JsonValue::String(key.clone())).unwrap());
+                gap(gaps, next, output);
+                output.push(':');
+                gap(gaps, next, output);
+                render(member, gaps, next, output);
+                gap(gaps, next, output);
+            }
+            output.push('}');
+        }
+        _ => output.push_str(&to_string(value).unwrap()),
+    }
+}
+
+fn value() -> impl Strategy<Value = JsonValue> {
+    let scalar = prop_oneof![
+        Just(JsonValue::Null),
+        any::<bool>().prop_map(JsonValue::Bool),
+        "-?(0|[1-9][0-9]{0,5})(\\.[0-9]{1,4})?([eE][+-]?[0-9]{1,3})?"
+            .prop_map(|lexeme| JsonValue::Number(JsonNumber::new(&lexeme))),
+        any::<String>().prop_map(JsonValue::String),
+    ];
+    return scalar.prop_recursive(6, 128, 6, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..6).prop_map(JsonValue::Array),
+            prop::collection::vec((any::<String>(), inner), 0..6).prop_map(JsonValue::Object),
+        ]
+    });
+}
+
+proptest! {
+    #[test]
+    fn any_formatting_round_trips(
+        value in value(),
+        gaps in prop::collection::vec(prop::sample::select(vec!["", " ", "\n", "\r\n", "\t", "  \n  "]), 1..8),
+    ) {
+        let mut input = String::new();
+        let mut next = 0;
+        input.push_str(gaps[0]);
+        render(&value, &gaps, &mut next, &mut input);
+        input.push_str(gaps[gaps.len() - 1]);
+
+        let document: Document = parse_document(&UTF8Reader::new(&input)).unwrap();
+        prop_assert_eq!(document.to_string(), input);
+    }
+}
@@ -0,0 +1,47 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_with_progress, UTF8Reader};
+
+fn large_document() -> String {
+    let records: Vec<String> = (0..20_000)
+        .map(|i| {
+            format!(
+                "  {{\"id\": {}, \"name\": \"record {}\", \"tags\": [1, 2]}}",
+                i, i
+            )
+        })
+        .collect();
+    return format!("[\n{}\n]", records.join(",\n"));
+}
+
+fn reports(document: &str) -> (bool, Vec<(usize, usize)>) {
+    let reader = UTF8Reader::new(document);
+    let mut reports = vec![];
+    let result = validate_with_progress(&reader, &mut |done, total| reports.push((done, total)));
+    return (result.is_ok(), reports);
+}
+
+#[test]
+fn increases_up_to_the_total() {
+    let document = large_document();
+    let total = document.chars().count();
+    let (valid, reports) = reports(&document);
+
+    assert!(valid);
+    assert!(reports.len() > 5, "{:?}", reports);
+    assert!(reports.iter().all(|(_, t)| *t == total));
+    assert!(reports.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert_eq!(reports.last(), Some(&(total, total)));
+}
+
+#[test]
+fn stops_short_on_invalid_input() {
+    let mut document = large_document();
+    document.push(',');
+    let total = document.chars().count();
+    let (valid, reports) = reports(&document);
+
+    assert!(!valid);
+    assert!(!reports.is_empty());
+    assert!(reports.iter().all(|(done, _)| *done < total));
+}
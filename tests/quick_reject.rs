@@ -0,0 +1,76 @@
+#![allow(clippy::needless_return)]
+
+use proptest::prelude::*;
+
+use rust_json::{quick_reject, to_string, validate_str, JsonNumber, JsonValue};
+
+fn value() -> impl Strategy<Value = JsonValue> {
+    let scalar = prop_oneof![
+        Just(JsonValue::Null),
+        any::<bool>().prop_map(JsonValue::Bool),
+        "-?(0|[1-9][0-9]{0,5})(\\.[0-9]{1,4})?([eE][+-]?[0-9]{1,3})?"
+            .prop_map(|lexeme| JsonValue::Number(JsonNumber::new(&lexeme))),
+        // Brackets, quotes and backslashes inside strings must not count.
+        "[\\[\\]{}\"\\\\a é]{0,8}".prop_map(JsonValue::String),
+        any::<String>().prop_map(JsonValue::String),
+    ];
+    return scalar.prop_recursive(8, 64, 6, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..6).prop_map(JsonValue::Array),
+            prop::collection::vec(("[\\[\\]{}\"\\\\a]{0,4}", inner), 0..6)
+                .prop_map(JsonValue::Object),
+        ]
+    });
+}
+
+proptest! {
+    #[test]
+    fn never_rejects_a_valid_document(value in value(), padding in "[ \t\r\n]{0,3}") {
+        let input = format!("{}{}{}", padding, to_string(&value).unwrap(), padding);
+        prop_assert!(validate_str(&input).is_ok());
+        prop_assert!(!quick_reject(&input), "{}", input);
+    }
+
+    #[test]
+    fn only_rejects_what_validation_rejects(input in "[\\[\\]{}\",:\\\\ a1]{0,16}") {
+        if quick_reject(&input) {
+            prop_assert!(validate_str(&input).is_err(), "{}", input);
+        }
+    }
+}
+
+#[test]
+fn rejects_obviously_broken_documents() {
+    for input in [
+        "",
+        " \n\t",
+        "[",
+        "{\"a\": [1, 2}",
+        "[1]]",
+        "]",
+        "{\"a\": \"unterminated}",
+        "[\"escaped quote \\\"]",
+    ] {
+        assert!(quick_reject(input), "{:?}", input);
+        assert!(validate_str(input).is_err(), "{:?}", input);
+    }
+}
+
+#[test]
+fn leaves_subtler_errors_to_validation() {
+    for input in ["[1,]", "{\"a\" 1}", "tru", "[01]", "\u{FEFF}[]"] {
+        assert!(!quick_reject(input), "{:?}", input);
+        assert!(validate_str(input).is_err(), "{:?}", input);
+    }
+}
+
+#[test]
+fn rejects_nesting_past_the_validation_limit() {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+    assert!(validate_str(&nested(100)).is_ok());
+    assert!(!quick_reject(&nested(100)));
+
+    assert!(validate_str(&nested(101)).is_err());
+    assert!(quick_reject(&nested(101)));
+}
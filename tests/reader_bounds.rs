@@ -0,0 +1,52 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{UTF8Reader, UTF8ReaderResult};
+
+fn out_of_bound(result: UTF8ReaderResult) -> Option<usize> {
+    return match result {
+        UTF8ReaderResult::Ok(_) => None,
+        UTF8ReaderResult::OutOfBoundError(remaining) => Some(remaining),
+    };
+}
+
+#[test]
+fn look_ahead_rejects_indices_that_would_overflow() {
+    for reader in [
+        UTF8Reader::new("[1, \"é\"]"),
+        UTF8Reader::new_grapheme("[1, \"é\"]"),
+    ] {
+        let len = reader.len();
+
+        assert_eq!(out_of_bound(reader.look_ahead(usize::MAX, 1)), Some(0));
+        assert_eq!(
+            out_of_bound(reader.look_ahead(usize::MAX, usize::MAX)),
+            Some(0)
+        );
+        assert_eq!(
+            out_of_bound(reader.look_ahead(1, usize::MAX)),
+            Some(len - 1)
+        );
+        assert_eq!(out_of_bound(reader.look_ahead(len, usize::MAX)), Some(0));
+    }
+}
+
+#[test]
+fn look_ahead_past_the_end_reports_nothing_left() {
+    let reader = UTF8Reader::new("[1]");
+
+    assert!(matches!(reader.look_ahead(3, 0), UTF8ReaderResult::Ok("")));
+    assert_eq!(out_of_bound(reader.look_ahead(3, 1)), Some(0));
+    assert_eq!(out_of_bound(reader.look_ahead(4, 0)), Some(0));
+    assert_eq!(out_of_bound(reader.look_ahead(1_000, 2)), Some(0));
+    assert_eq!(out_of_bound(reader.look_ahead(2, 5)), Some(1));
+}
+
+#[test]
+fn other_lookups_tolerate_huge_indices() {
+    let reader = UTF8Reader::new("[1,\n2]");
+
+    assert_eq!(reader.byte_offset_of(usize::MAX), 6);
+    assert_eq!(reader.scalar_index_of_byte(usize::MAX), None);
+    assert_eq!(reader.line_col(usize::MAX).0, 2);
+    assert_eq!(UTF8Reader::new("[1]").line_col(usize::MAX), (1, usize::MAX));
+}
@@ -0,0 +1,46 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{repair, validate_str};
+
+fn repaired(input: &str) -> String {
+    let output = repair(input).unwrap();
+    assert!(validate_str(&output).is_ok(), "{} -> {}", input, output);
+    return output;
+}
+
+#[test]
+fn valid_input_is_unchanged() {
+    assert_eq!(repaired(" [1, 2] "), " [1, 2] ");
+}
+
+#[test]
+fn drops_trailing_commas() {
+    assert_eq!(repaired("[1, 2,]"), "[1,2]");
+    assert_eq!(repaired("{\"a\": [true,], }"), "{\"a\":[true]}");
+    assert_eq!(repaired("[\"a,]\",]"), "[\"a,]\"]");
+}
+
+#[test]
+fn requotes_single_quoted_strings() {
+    assert_eq!(repaired("['it\\'s \"x\"']"), "[\"it's \\\"x\\\"\"]");
+}
+
+#[test]
+fn quotes_unquoted_keys() {
+    assert_eq!(repaired("{foo: 1, $bar_2: 2}"), "{\"foo\":1,\"$bar_2\":2}");
+}
+
+#[test]
+fn closes_containers_left_open() {
+    assert_eq!(repaired("[1, [2, {\"a\": 3"), "[1,[2,{\"a\":3}]]");
+    assert_eq!(repaired("[1, 2,"), "[1,2]");
+}
+
+#[test]
+fn declines_to_guess() {
+    for input in [
+        "[\"abc", "{\"a\":", "[1,,2]", "[tru", "[,]", "{,}", "[1,,]", "[,",
+    ] {
+        assert_eq!(repair(input), validate_str(input).map(|_| String::new()));
+    }
+}
@@ -0,0 +1,97 @@
+#![allow(clippy::needless_return)]
+
+use proptest::prelude::*;
+
+use rust_json::{
+    parse, to_string, to_string_with_options, validate_str, JsonNumber, JsonValue,
+    SerializeOptions, UTF8Reader,
+};
+
+/// Nesting the validator accepts; one more level is rejected.
+const MAX_DEPTH: usize = 100;
+
+/// Number lexemes as RFC 8259 spells them, plus whatever Rust prints for
+/// arbitrary finite floats and integers.
+fn number() -> impl Strategy<Value = JsonValue> {
+    let lexeme = prop_oneof![
+        "-?(0|[1-9][0-9]{0,20})(\\.[0-9]{1,12})?([eE][+-]?[0-9]{1,3})?",
+        any::<i64>().prop_map(|n| format!("{}", n)),
+        any::<u64>().prop_map(|n| format!("{}", n)),
+        any::<f64>()
+            .prop_filter("JSON has no infinities or NaN", |f| f.is_finite())
+            .prop_map(|f| format!("{:?}", f)),
+    ];
+    return lexeme.prop_map(|lexeme| JsonValue::Number(JsonNumber::new(&lexeme)));
+}
+
+fn scalar() -> impl Strategy<Value = JsonValue> {
+    return prop_oneof![
+        Just(JsonValue::Null),
+        any::<bool>().prop_map(JsonValue::Bool),
+        number(),
+        any::<String>().prop_map(JsonValue::String),
+    ];
+}
+
+/// Arbitrary well-formed trees, shrinking towards smaller and flatter ones.
+fn value() -> impl Strategy<Value = JsonValue> {
+    return scalar().prop_recursive(8, 256, 8, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..8).prop_map(JsonValue::Array),
+            prop::collection::vec((any::<String>(), inner), 0..8).prop_map(JsonValue::Object),
+        ]
+    });
+}
+
+fn nested_arrays(depth: usize) -> JsonValue {
+    let mut value = JsonValue::Null;
+    for _ in 0..depth {
+        value = JsonValue::Array(vec![value]);
+    }
+    return value;
+}
+
+fn assert_round_trip(value: &JsonValue, output: &str) -> Result<(), TestCaseError> {
+    prop_assert!(
+        validate_str(output).is_ok(),
+        "serialized output does not validate: {:?}",
+        validate_str(output)
+    );
+    prop_assert_eq!(&parse(&UTF8Reader::new(output)).unwrap(), value);
+    return Ok(());
+}
+
+proptest! {
+    #[test]
+    fn compact_output_round_trips(value in value()) {
+        assert_round_trip(&value, &to_string(&value).unwrap())?;
+    }
+
+    #[test]
+    fn pretty_output_round_trips(value in value()) {
+        let options = SerializeOptions { indent: Some(2), ..SerializeOptions::default() };
+        assert_round_trip(&value, &to_string_with_options(&value, &options).unwrap())?;
+    }
+
+    #[test]
+    fn canonical_numbers_validate(value in value()) {
+        let options = SerializeOptions { canonical_numbers: true, ..SerializeOptions::default() };
+        match to_string_with_options(&value, &options) {
+            Ok(output) => prop_assert!(validate_str(&output).is_ok(), "{:?}", validate_str(&output)),
+            // Only a number too large for an `f64`, such as `1e999`, has no
+            // canonical form.
+            Err(error) => prop_assert!(JsonNumber::new(error.lexeme()).as_f64().is_infinite()),
+        }
+    }
+
+    #[test]
+    fn nesting_up_to_the_limit_round_trips(depth in (MAX_DEPTH - 8)..=MAX_DEPTH) {
+        let value = nested_arrays(depth);
+        assert_round_trip(&value, &to_string(&value).unwrap())?;
+    }
+
+    #[test]
+    fn nesting_past_the_limit_is_rejected(depth in (MAX_DEPTH + 1)..(MAX_DEPTH + 8)) {
+        prop_assert!(validate_str(&to_string(&nested_arrays(depth)).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{parse, validate_against, JsonValue, Schema, UTF8Reader};
+
+fn value(input: &str) -> JsonValue {
+    return parse(&UTF8Reader::new(input)).unwrap();
+}
+
+fn user() -> Schema {
+    return Schema::Object {
+        fields: vec![
+            (String::from("name"), Schema::String),
+            (String::from("age"), Schema::Number),
+            (
+                String::from("tags"),
+                Schema::Array(Box::new(Schema::String)),
+            ),
+            (
+                String::from("email"),
+                Schema::AnyOf(vec![Schema::String, Schema::Null]),
+            ),
+        ],
+        required: vec![String::from("name")],
+    };
+}
+
+fn errors(input: &str, schema: &Schema) -> Vec<String> {
+    return match validate_against(&value(input), schema) {
+        Ok(()) => vec![],
+        Err(errors) => errors.iter().map(|error| error.to_string()).collect(),
+    };
+}
+
+#[test]
+fn accepts_a_matching_value() {
+    assert_eq!(
+        errors(
+            r#"{"name": "Ann", "age": 30, "tags": ["a"], "email": null}"#,
+            &user()
+        ),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn allows_optional_and_unlisted_fields() {
+    assert_eq!(
+        errors(r#"{"name": "Ann", "extra": [1, 2]}"#, &user()),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn reports_a_missing_required_field_at_the_object() {
+    assert_eq!(
+        errors(r#"{"age": 30}"#, &user()),
+        vec![r#"/: Missing required field "name""#]
+    );
+}
+
+#[test]
+fn collects_every_violation() {
+    assert_eq!(
+        errors(
+            r#"{"age": "old", "tags": ["a", 1, true], "email": 5}"#,
+            &user()
+        ),
+        vec![
+            r#"/: Missing required field "name""#,
+            "/age: Expected number, found string",
+            "/tags/1: Expected string, found number",
+            "/tags/2: Expected string, found boolean",
+            "/email: Expected any of string, null, found number",
+        ]
+    );
+}
+
+#[test]
+fn reports_a_mismatched_root() {
+    assert_eq!(
+        errors("[]", &user()),
+        vec!["/: Expected object, found array"]
+    );
+    assert_eq!(
+        errors("null", &Schema::Bool),
+        vec!["/: Expected boolean, found null"]
+    );
+}
+
+#[test]
+fn locates_nested_errors_with_escaped_pointers() {
+    let schema = Schema::Object {
+        fields: vec![(String::from("a/b"), Schema::Array(Box::new(user())))],
+        required: vec![],
+    };
+    let errors =
+        validate_against(&value(r#"{"a/b": [{"name": "x"}, {"name": 1}]}"#), &schema).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "/a~1b/1/name");
+    assert_eq!(errors[0].reason, "Expected string, found number");
+}
@@ -0,0 +1,79 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{parse_with_options, semantic_eq, JsonValue, UTF8Reader, ValidateOptions};
+
+fn value(input: &str) -> JsonValue {
+    return parse_with_options(&UTF8Reader::new(input), &ValidateOptions::json5()).unwrap();
+}
+
+fn equal(a: &str, b: &str) -> bool {
+    return semantic_eq(&value(a), &value(b));
+}
+
+#[test]
+fn numbers_compare_by_exact_value() {
+    for (a, b) in [
+        ("1.0", "1"),
+        ("1", "1e0"),
+        ("12.50", "1.25e1"),
+        ("100", "1E+2"),
+        ("-0", "0"),
+        ("-0.0e5", "0"),
+        ("0x10", "16"),
+        ("-0xFF", "-255.0"),
+        ("+1", "1"),
+        (".5", "0.5"),
+        ("18446744073709551616", "1.8446744073709551616e19"),
+        ("-Infinity", "-Infinity"),
+        ("NaN", "NaN"),
+    ] {
+        assert!(equal(a, b), "{} should equal {}", a, b);
+    }
+
+    for (a, b) in [
+        ("18446744073709551616", "18446744073709551617"),
+        ("9007199254740993", "9007199254740992.0"),
+        ("0.1", "0.10000000000000001"),
+        ("1", "-1"),
+        ("0x10", "17"),
+        ("Infinity", "-Infinity"),
+        ("NaN", "0"),
+    ] {
+        assert!(!equal(a, b), "{} should not equal {}", a, b);
+    }
+}
+
+#[test]
+fn object_members_compare_in_any_order() {
+    assert!(equal(
+        r#"{"a": 1, "b": [1, 2]}"#,
+        r#"{"b": [1.0, 2], "a": 1e0}"#
+    ));
+    assert!(!equal(r#"{"a": 1, "b": 2}"#, r#"{"a": 1, "c": 2}"#));
+    assert!(!equal(r#"{"a": 1}"#, r#"{"a": 1, "b": 2}"#));
+
+    // Duplicates are matched in the order they appear.
+    assert!(equal(
+        r#"{"a": 1, "b": 0, "a": 2}"#,
+        r#"{"b": 0, "a": 1, "a": 2}"#
+    ));
+    assert!(!equal(r#"{"a": 1, "a": 2}"#, r#"{"a": 2, "a": 1}"#));
+}
+
+#[test]
+fn array_elements_compare_in_order() {
+    assert!(equal("[1, [true, null]]", "[1.0, [true, null]]"));
+    assert!(!equal("[1, 2]", "[2, 1]"));
+    assert!(!equal("[1]", "[1, 1]"));
+    assert!(!equal("[\"1\"]", "[1]"));
+}
+
+#[test]
+fn extreme_exponents_compare_by_lexeme() {
+    assert!(equal("0e9223372036854775807", "0"));
+    assert!(equal("-0e-9223372036854775808", "0"));
+    assert!(equal("1e9223372036854775807", "1e9223372036854775807"));
+    assert!(equal("1e-9223372036854775808", "1e-9223372036854775808"));
+    assert!(!equal("1e9223372036854775807", "10e9223372036854775806"));
+    assert!(!equal("0.01e-9223372036854775808", "0"));
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "serde")]
+#![allow(clippy::needless_return)]
+
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use rust_json::{
+    parse, parse_with_options, to_serde_value, ErrorKind, JsonNumber, JsonValue, UTF8Reader,
+    ValidateOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Envelope {
+    id: u32,
+    payload: JsonValue,
+    extra: Option<JsonValue>,
+}
+
+fn number(lexeme: &str) -> JsonValue {
+    return JsonValue::Number(JsonNumber::new(lexeme));
+}
+
+#[test]
+fn works_as_a_field_of_a_serde_struct() {
+    let input = r#"{"id":7,"payload":{"b":[1,-2,1.0,2.5e-3,18446744073709551615],"a":null,"s":"x"},"extra":null}"#;
+    let envelope: Envelope = serde_json::from_str(input).unwrap();
+
+    assert_eq!(envelope.id, 7);
+    assert_eq!(envelope.extra, None);
+    assert_eq!(
+        envelope.payload,
+        JsonValue::Object(vec![
+            (
+                String::from("b"),
+                JsonValue::Array(vec![
+                    number("1"),
+                    number("-2"),
+                    number("1.0"),
+                    number("0.0025"),
+                    number("18446744073709551615"),
+                ])
+            ),
+            (String::from("a"), JsonValue::Null),
+            (String::from("s"), JsonValue::String(String::from("x"))),
+        ])
+    );
+
+    assert_eq!(
+        serde_json::to_string(&envelope).unwrap(),
+        r#"{"id":7,"payload":{"b":[1,-2,1.0,0.0025,18446744073709551615],"a":null,"s":"x"},"extra":null}"#
+    );
+}
+
+#[test]
+fn keeps_integers_and_floats_apart() {
+    let input = UTF8Reader::new("[1, 1.0, 1e2, -0, 0x10, +.5]");
+    let value = parse_with_options(&input, &ValidateOptions::json5()).unwrap();
+    let written = serde_json::to_string(&value).unwrap();
+    // serde_json reads `-0` as a float, to keep its sign.
+    assert_eq!(written, "[1,1.0,100.0,-0.0,16,0.5]");
+
+    let read: serde_json::Value = serde_json::from_str(&written).unwrap();
+    assert!(read[0].is_u64());
+    assert!(read[1].is_f64());
+    assert!(read[4].is_u64());
+
+    let back: JsonValue = serde_json::from_str(&written).unwrap();
+    assert_eq!(back.get_index(0), Some(&number("1")));
+    assert_eq!(back.get_index(1), Some(&number("1.0")));
+}
+
+#[test]
+fn round_trips_through_serde_json() {
+    let input = r#"{"nested":{"list":[true,false,null,"é\n"]},"empty":{},"dup":1,"dup":2}"#;
+    let value = parse(&UTF8Reader::new(input)).unwrap();
+    assert_eq!(serde_json::to_string(&value).unwrap(), input);
+}
+
+#[test]
+fn converts_numbers_exactly_or_not_at_all() {
+    for (lexeme, expected) in [
+        ("18446744073709551615", "18446744073709551615"),
+        ("-9223372036854775808", "-9223372036854775808"),
+        ("0xFF", "255"),
+        ("-0x10", "-16"),
+        ("+.5", "0.5"),
+        ("2.5e-3", "0.0025"),
+    ] {
+        let converted = Value::try_from(number(lexeme)).unwrap();
+        assert_eq!(converted.to_string(), expected, "{}", lexeme);
+    }
+
+    for lexeme in [
+        "18446744073709551616",
+        "-9223372036854775809",
+        "0x10000000000000000",
+        "9007199254740993.0",
+        "1e400",
+        "NaN",
+        "-Infinity",
+    ] {
+        let error = Value::try_from(number(lexeme)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected i64, u64 or f64 at $, found number",
+            "{}",
+            lexeme
+        );
+    }
+}
+
+#[test]
+fn conversion_errors_point_at_the_number() {
+    let value = JsonValue::Object(vec![(
+        String::from("a"),
+        JsonValue::Array(vec![number("1"), number("1e400")]),
+    )]);
+    let error = Value::try_from(value).unwrap_err();
+    assert_eq!(error.path(), "$.a[1]");
+
+    let error =
+        to_serde_value(&UTF8Reader::new(r#"{"a": [1, 18446744073709551616]}"#)).unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::PrecisionLoss);
+    assert_eq!(
+        error.reason(),
+        "Invalid value at $.a[1]: Number 18446744073709551616 has no exact i64, u64 or f64 value"
+    );
+    assert_eq!((error.line(), error.column()), (1, 11));
+}
+
+#[test]
+fn serializing_an_inexact_number_is_an_error() {
+    let value = JsonValue::Array(vec![number("1"), number("1e400")]);
+    let error = serde_json::to_string(&value).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Number 1e400 has no exact i64, u64 or f64 value"
+    );
+}
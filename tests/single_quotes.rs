@@ -0,0 +1,90 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, ErrorKind, JsonValue, UTF8Reader, ValidateOptions,
+};
+
+fn check(input: &str, options: &ValidateOptions) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate_with_options(&UTF8Reader::new(input), options).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+fn single_quotes() -> ValidateOptions {
+    return ValidateOptions::default().allow_single_quotes(true);
+}
+
+#[test]
+fn single_quotes_are_rejected_by_default() {
+    let options = ValidateOptions::default();
+    assert_eq!(
+        check("'a'", &options),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Unknown character: \"'\""),
+            1,
+            1
+        ))
+    );
+    assert_eq!(
+        check("{'k': 1}", &options),
+        Err((
+            ErrorKind::InvalidKey,
+            String::from("Object key should be a valid string"),
+            1,
+            2
+        ))
+    );
+    assert_eq!(
+        check("\"\\'\"", &options),
+        Err((
+            ErrorKind::InvalidEscape,
+            String::from("Invalid escaping character: \"'\""),
+            1,
+            3
+        ))
+    );
+}
+
+#[test]
+fn single_quoted_strings_and_keys_are_accepted() {
+    for input in ["'a'", "{'k': 'v'}", "['', 'a \"quoted\" word']", "\"\\'\""] {
+        assert_eq!(check(input, &single_quotes()), Ok(()), "{}", input);
+    }
+}
+
+#[test]
+fn escapes_decode_inside_single_quotes() {
+    let value = parse_with_options(
+        &UTF8Reader::new("['it\\'s', 'say \"hi\"', '\\u0041']"),
+        &single_quotes(),
+    );
+    assert_eq!(
+        value,
+        Ok(JsonValue::Array(vec![
+            JsonValue::String(String::from("it's")),
+            JsonValue::String(String::from("say \"hi\"")),
+            JsonValue::String(String::from("A")),
+        ]))
+    );
+}
+
+#[test]
+fn a_string_must_close_with_its_own_quote() {
+    for (input, column) in [("'unterminated", 14), ("'a\"", 4)] {
+        assert_eq!(
+            check(input, &single_quotes()),
+            Err((
+                ErrorKind::UnterminatedString,
+                String::from("Incomplete string value, opened at line 1, column 1"),
+                1,
+                column
+            ))
+        );
+    }
+}
@@ -0,0 +1,82 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{decode_string, skip_value, UTF8Reader, UTF8ReaderResult};
+
+fn skip_whitespace(document: &UTF8Reader, mut ptr: usize) -> usize {
+    while let UTF8ReaderResult::Ok(" " | "\n") = document.look_ahead(ptr, 1) {
+        ptr += 1;
+    }
+    return ptr;
+}
+
+/// Walks a top-level object, decoding keys and skipping every value but the
+/// one named `wanted`, which comes back as its source text.
+fn extract(document: &str, wanted: &str) -> Option<String> {
+    let reader = UTF8Reader::new(document);
+    let mut ptr = skip_whitespace(&reader, 1);
+
+    loop {
+        let (key, step) = decode_string(&reader, ptr);
+        let key = key.ok()?;
+        ptr = skip_whitespace(&reader, ptr + step) + 1;
+        ptr = skip_whitespace(&reader, ptr);
+
+        let (result, step) = skip_value(&reader, ptr);
+        result.ok()?;
+        if key == wanted {
+            let begin = reader.byte_offset_of(ptr);
+            let end = reader.byte_offset_of(ptr + step);
+            return Some(String::from(&document[begin..end]));
+        }
+
+        ptr = skip_whitespace(&reader, ptr + step);
+        match reader.look_ahead(ptr, 1) {
+            UTF8ReaderResult::Ok(",") => ptr = skip_whitespace(&reader, ptr + 1),
+            _ => return None,
+        }
+    }
+}
+
+#[test]
+fn skips_each_kind_of_value() {
+    for value in [
+        "null",
+        "true",
+        "-1.5e3",
+        "\"a \\\"quoted\\\" é\"",
+        "[]",
+        "{}",
+    ] {
+        let document = format!("{} ,", value);
+        let (result, step) = skip_value(&UTF8Reader::new(&document), 0);
+        assert!(result.is_ok(), "{}", value);
+        assert_eq!(step, value.chars().count(), "{}", value);
+    }
+}
+
+#[test]
+fn skips_nested_objects_and_arrays() {
+    let document = r#"{
+        "skipped": {"a": [1, {"b": [[], {}]}], "c": {"d": null}},
+        "also skipped": [[1, 2], [{"e": "]}"}], []],
+        "target": {"keep": [true]}
+    }"#;
+    assert_eq!(
+        extract(document, "target").as_deref(),
+        Some(r#"{"keep": [true]}"#)
+    );
+    assert_eq!(
+        extract(document, "also skipped").as_deref(),
+        Some(r#"[[1, 2], [{"e": "]}"}], []]"#)
+    );
+    assert_eq!(extract(document, "missing"), None);
+}
+
+#[test]
+fn reports_where_an_invalid_value_stops() {
+    let reader = UTF8Reader::new("[1, {\"a\" 2}]");
+    let (result, step) = skip_value(&reader, 0);
+    let error = result.unwrap_err();
+    assert_eq!(step, 9);
+    assert_eq!(error.column(), 10);
+}
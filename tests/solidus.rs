@@ -0,0 +1,73 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_with_options, ErrorKind, SolidusPolicy, UTF8Reader, ValidateOptions};
+
+fn check(input: &str, options: &ValidateOptions) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate_with_options(&UTF8Reader::new(input), options).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+fn policy(policy: SolidusPolicy) -> ValidateOptions {
+    return ValidateOptions::default().solidus_policy(policy);
+}
+
+#[test]
+fn both_spellings_are_allowed_by_default() {
+    assert_eq!(
+        ValidateOptions::default().solidus_policy,
+        SolidusPolicy::Allow
+    );
+    for input in ["\"a/b\"", "\"a\\/b\"", "{\"a/b\":1}"] {
+        assert_eq!(
+            check(input, &policy(SolidusPolicy::Allow)),
+            Ok(()),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn forbid_escaped_rejects_an_escaped_solidus() {
+    let options = policy(SolidusPolicy::ForbidEscaped);
+    assert_eq!(check("\"a/b\"", &options), Ok(()));
+    assert_eq!(
+        check("\"a\\/b\"", &options),
+        Err((
+            ErrorKind::InvalidEscape,
+            String::from("Solidus should not be escaped"),
+            1,
+            4
+        ))
+    );
+}
+
+#[test]
+fn require_escaped_rejects_a_bare_solidus_in_values_and_keys() {
+    let options = policy(SolidusPolicy::RequireEscaped);
+    assert_eq!(check("\"<\\/script>\"", &options), Ok(()));
+    assert_eq!(
+        check("\"a/b\"", &options),
+        Err((
+            ErrorKind::UnescapedCharacter,
+            String::from("Solidus should be escaped as \"\\/\""),
+            1,
+            3
+        ))
+    );
+    assert_eq!(
+        check("{\"a/b\":1}", &options),
+        Err((
+            ErrorKind::UnescapedCharacter,
+            String::from("Solidus should be escaped as \"\\/\""),
+            1,
+            4
+        ))
+    );
+}
@@ -0,0 +1,22 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{parse, to_string, UTF8Reader};
+
+#[test]
+fn sorts_objects_at_every_depth() {
+    let document =
+        r#"{"b": [{"z": 1, "é": 2, "a": 3}, 2, 1], "a": {"y": {"q": 0, "p": 0}, "x": null}}"#;
+    let mut value = parse(&UTF8Reader::new(document)).unwrap();
+    value.sort_keys();
+    assert_eq!(
+        to_string(&value).unwrap(),
+        r#"{"a":{"x":null,"y":{"p":0,"q":0}},"b":[{"a":3,"z":1,"é":2},2,1]}"#
+    );
+}
+
+#[test]
+fn duplicate_keys_keep_their_order() {
+    let mut value = parse(&UTF8Reader::new(r#"{"b": 1, "a": 2, "b": 3}"#)).unwrap();
+    value.sort_keys();
+    assert_eq!(to_string(&value).unwrap(), r#"{"a":2,"b":1,"b":3}"#);
+}
@@ -0,0 +1,76 @@
+#![allow(clippy::needless_return)]
+
+use std::borrow::Cow;
+
+use rust_json::{
+    parse_borrowed_with_options, parse_document_with_options, parse_with_spans_with_options,
+    BomPolicy, JsonValueRef, SpannedKind, UTF8Reader, ValidateOptions,
+};
+
+const DOCUMENT: &str = "\u{FEFF}{ a : ['x', +1 ], \"b\":{} } ";
+
+fn options() -> ValidateOptions {
+    return ValidateOptions::json5().bom_policy(BomPolicy::Allow);
+}
+
+#[test]
+fn spans_skip_bom_and_relaxed_tokens() {
+    let document = UTF8Reader::new(DOCUMENT);
+    let root = parse_with_spans_with_options(&document, &options()).unwrap();
+    assert_eq!(root.span, (1, 27));
+
+    let members = match root.kind {
+        SpannedKind::Object(members) => members,
+        kind => panic!("expected an object, got {:?}", kind),
+    };
+    assert_eq!(members.len(), 2);
+
+    assert_eq!(members[0].key, "a");
+    assert_eq!(members[0].key_span, (3, 4));
+    assert_eq!(members[0].value.span, (7, 17));
+    match &members[0].value.kind {
+        SpannedKind::Array(elements) => {
+            let spans: Vec<_> = elements.iter().map(|element| element.span).collect();
+            assert_eq!(spans, vec![(8, 11), (13, 15)]);
+            assert_eq!(elements[0].kind, SpannedKind::String(String::from("x")));
+        }
+        kind => panic!("expected an array, got {:?}", kind),
+    }
+
+    assert_eq!(members[1].key, "b");
+    assert_eq!(members[1].key_span, (19, 22));
+    assert_eq!(members[1].value.span, (23, 25));
+}
+
+#[test]
+fn borrowed_keys_and_strings_without_escapes() {
+    let document = UTF8Reader::new(DOCUMENT);
+    let root = parse_borrowed_with_options(&document, &options()).unwrap();
+
+    let members = match root {
+        JsonValueRef::Object(members) => members,
+        value => panic!("expected an object, got {:?}", value),
+    };
+    assert!(matches!(members[0].0, Cow::Borrowed("a")));
+    match &members[0].1 {
+        JsonValueRef::Array(elements) => {
+            assert!(matches!(
+                elements[0],
+                JsonValueRef::String(Cow::Borrowed("x"))
+            ));
+            assert!(matches!(elements[1], JsonValueRef::Number("+1")));
+        }
+        value => panic!("expected an array, got {:?}", value),
+    }
+    assert!(matches!(members[1].0, Cow::Borrowed("b")));
+}
+
+#[test]
+fn preserving_round_trip_keeps_bom_and_whitespace() {
+    let document = UTF8Reader::new(DOCUMENT);
+    let parsed = parse_document_with_options(&document, &options()).unwrap();
+
+    assert_eq!(parsed.leading, "\u{FEFF}");
+    assert_eq!(parsed.trailing, " ");
+    assert_eq!(parsed.to_string(), DOCUMENT);
+}
@@ -0,0 +1,42 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{analyze, UTF8Reader};
+
+#[test]
+fn profiles_uneven_nesting() {
+    let input = r#"{
+        "flat": 1,
+        "wide": [1, 2, 3, 4, 5],
+        "deep": {"a": [0, [true, {"x/y": [null, "bottom"]}]]},
+        "later": [[["not as deep"]]]
+    }"#;
+    let stats = analyze(&UTF8Reader::new(input)).unwrap();
+
+    // 0: the root
+    // 1: flat, wide, deep, later
+    // 2: five numbers, [0, ...], [["not as deep"]]
+    // 3: 0, [true, ...], ["not as deep"]
+    // 4: true, {"x/y": ...}, "not as deep"
+    // 5: [null, "bottom"]
+    // 6: null, "bottom"
+    assert_eq!(stats.nodes_per_depth, vec![1, 4, 7, 3, 3, 1, 2]);
+    assert_eq!(stats.nodes_per_depth.iter().sum::<usize>(), 21);
+    assert_eq!(stats.max_depth, 6);
+    assert_eq!(stats.deepest_path, "/deep/a/1/1/x~1y/0");
+}
+
+#[test]
+fn a_lone_scalar_is_its_own_deepest_value() {
+    let stats = analyze(&UTF8Reader::new("42")).unwrap();
+    assert_eq!(stats.nodes_per_depth, vec![1]);
+    assert_eq!(stats.deepest_path, "");
+    assert_eq!(stats.max_depth, 0);
+}
+
+#[test]
+fn an_empty_container_is_a_value_at_its_own_depth() {
+    let stats = analyze(&UTF8Reader::new("[1, [], [{}]]")).unwrap();
+    assert_eq!(stats.nodes_per_depth, vec![1, 3, 1]);
+    assert_eq!(stats.deepest_path, "/2/0");
+    assert_eq!(stats.max_depth, 3);
+}
@@ -0,0 +1,87 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_str, IncrementalValidator};
+
+/// Every place between tokens of a valid document, with `X` standing for
+/// the stray character. A reason may carry the path of the value it
+/// follows, so only its end is checked; the incremental validator must
+/// agree with it exactly.
+const GAPS: [&str; 9] = [
+    "[X1]",
+    "[1X]",
+    "[1,X2]",
+    "[\"a\"X]",
+    "{X\"a\":1}",
+    "{\"a\"X:1}",
+    "{\"a\":X1}",
+    "{\"a\":1X}",
+    "[1]X",
+];
+
+fn reasons(stray: &str) -> Vec<(String, String, String)> {
+    return GAPS
+        .iter()
+        .map(|gap| {
+            let document = gap.replace('X', stray);
+            let error = validate_str(&document).unwrap_err();
+            let mut incremental = IncrementalValidator::new();
+            let incremental_error = incremental
+                .feed(&document)
+                .err()
+                .or_else(|| incremental.finish().err())
+                .unwrap();
+            return (
+                String::from(*gap),
+                String::from(error.reason()),
+                String::from(incremental_error.reason()),
+            );
+        })
+        .collect();
+}
+
+#[test]
+fn nul_between_tokens() {
+    for (gap, reason, incremental) in reasons("\u{0}") {
+        assert!(
+            reason.ends_with("Unexpected NUL byte"),
+            "{}: {}",
+            gap,
+            reason
+        );
+        assert_eq!(incremental, reason, "{}", gap);
+    }
+}
+
+#[test]
+fn byte_order_mark_between_tokens() {
+    for (gap, reason, incremental) in reasons("\u{FEFF}") {
+        assert!(
+            reason.ends_with("Unexpected byte order mark"),
+            "{}: {}",
+            gap,
+            reason
+        );
+        assert_eq!(incremental, reason, "{}", gap);
+    }
+}
+
+#[test]
+fn leading_nul() {
+    assert_eq!(
+        validate_str("\u{0}[1]").unwrap_err().reason(),
+        "Unexpected NUL byte"
+    );
+}
+
+#[test]
+fn inside_strings() {
+    assert!(validate_str("[\"\u{FEFF}\"]").is_ok());
+    assert_eq!(
+        validate_str("[\"\u{0}\"]").unwrap_err().reason(),
+        validate_str("[\"\u{1}\"]")
+            .unwrap_err()
+            .reason()
+            .replace("\u{1}", "\u{0}")
+            .replace("0001", "0000")
+    );
+}
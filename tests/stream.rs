@@ -0,0 +1,139 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    validate_stream, validate_stream_with_options, BomPolicy, ErrorKind, UTF8Reader,
+    ValidateOptions,
+};
+
+// Each value's start, and for a failure its reason, line and column.
+type Outcome = (usize, Result<(), (String, usize, usize)>);
+
+fn stream(input: &str, options: &ValidateOptions) -> Vec<Outcome> {
+    return validate_stream_with_options(&UTF8Reader::new(input), options)
+        .into_iter()
+        .map(|(start, result)| {
+            let result = result.map_err(|error| {
+                return (String::from(error.reason()), error.line(), error.column());
+            });
+            return (start, result);
+        })
+        .collect();
+}
+
+fn failed(reason: &str, line: usize, column: usize) -> Result<(), (String, usize, usize)> {
+    return Err((String::from(reason), line, column));
+}
+
+#[test]
+fn splits_values_separated_by_whitespace() {
+    let results = validate_stream(&UTF8Reader::new("{\"a\":1}\n{\"b\":2}  3"));
+    let starts: Vec<usize> = results.iter().map(|(start, _)| *start).collect();
+    assert_eq!(starts, vec![0, 8, 17]);
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+
+    assert!(validate_stream(&UTF8Reader::new("")).is_empty());
+    assert!(validate_stream(&UTF8Reader::new(" \n ")).is_empty());
+}
+
+#[test]
+fn splits_a_number_at_the_value_after_it() {
+    let options = ValidateOptions::default();
+    assert_eq!(stream("1\"a\"", &options), vec![(0, Ok(())), (1, Ok(()))]);
+    assert_eq!(
+        stream("1[2]{}", &options),
+        vec![(0, Ok(())), (1, Ok(())), (4, Ok(()))]
+    );
+    assert_eq!(
+        stream("true\"a\"null", &options),
+        vec![(0, Ok(())), (4, Ok(())), (7, Ok(()))]
+    );
+    assert_eq!(
+        stream("'a'1'b'", &ValidateOptions::json5()),
+        vec![(0, Ok(())), (3, Ok(())), (4, Ok(()))]
+    );
+}
+
+#[test]
+fn requires_whitespace_between_names_and_numbers() {
+    let options = ValidateOptions::default();
+    assert_eq!(
+        stream("truenull", &options),
+        vec![(0, failed("Expected \"true\", but found \"truenull\"", 1, 1))]
+    );
+    assert_eq!(
+        stream("1true", &options),
+        vec![(0, failed("Invalid character in interger part: \"t\"", 1, 2))]
+    );
+    assert_eq!(
+        stream("true null", &options),
+        vec![(0, Ok(())), (5, Ok(()))]
+    );
+}
+
+#[test]
+fn stops_at_the_first_invalid_value() {
+    assert_eq!(
+        stream("1\n[01] 2", &ValidateOptions::default()),
+        vec![
+            (0, Ok(())),
+            (
+                2,
+                failed("Invalid value at $[0]: Leading zeros are not allowed", 2, 3)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn applies_the_options_to_each_value() {
+    assert_eq!(
+        stream(
+            "[01] [1,]",
+            &ValidateOptions::default().allow_leading_zeros(true)
+        ),
+        vec![
+            (0, Ok(())),
+            (
+                5,
+                failed("Invalid value at $[1]: Trailing comma before \"]\"", 1, 9)
+            )
+        ]
+    );
+
+    let results = validate_stream_with_options(
+        &UTF8Reader::new("[1] [1, 2]"),
+        &ValidateOptions::default().max_array_elements(1),
+    );
+    assert!(results[0].1.is_ok());
+    assert_eq!(
+        results[1].1.as_ref().unwrap_err().kind(),
+        &ErrorKind::TooManyArrayElements { limit: 1 }
+    );
+}
+
+#[test]
+fn applies_the_size_limit_and_bom_policy_to_the_whole_stream() {
+    let options = ValidateOptions::default().max_bytes(5);
+    let results = validate_stream_with_options(&UTF8Reader::new("[1, 2] [3]"), &options);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].1.as_ref().unwrap_err().kind(),
+        &ErrorKind::DocumentTooLarge {
+            limit: 5,
+            actual: 10
+        }
+    );
+
+    let options = ValidateOptions::default().bom_policy(BomPolicy::Require);
+    assert_eq!(
+        stream("\u{feff}1 2", &options),
+        vec![(1, Ok(())), (3, Ok(()))]
+    );
+    assert_eq!(
+        stream("1 2", &options),
+        vec![(
+            0,
+            failed("JSON document should start with a byte order mark", 1, 1)
+        )]
+    );
+}
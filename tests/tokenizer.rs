@@ -0,0 +1,146 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{BomPolicy, ErrorKind, TokenKind, Tokenizer, UTF8Reader, ValidateOptions};
+
+type Lexed = Result<(TokenKind, usize, usize), (ErrorKind, String, usize, usize)>;
+
+fn tokens(input: &str, options: ValidateOptions) -> Vec<Lexed> {
+    let document = UTF8Reader::new(input);
+    return Tokenizer::with_options(&document, options)
+        .map(|token| {
+            return token
+                .map(|token| (token.kind, token.start, token.length))
+                .map_err(|error| {
+                    (
+                        error.kind().clone(),
+                        String::from(error.reason()),
+                        error.line(),
+                        error.column(),
+                    )
+                });
+        })
+        .collect();
+}
+
+#[test]
+fn splits_strict_json() {
+    let document = UTF8Reader::new("{\"a\": [1, true, null]}");
+    let kinds: Vec<TokenKind> = Tokenizer::new(&document)
+        .map(|token| token.unwrap().kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::BeginObject,
+            TokenKind::String,
+            TokenKind::Colon,
+            TokenKind::BeginArray,
+            TokenKind::Number,
+            TokenKind::Comma,
+            TokenKind::True,
+            TokenKind::Comma,
+            TokenKind::Null,
+            TokenKind::EndArray,
+            TokenKind::EndObject,
+        ]
+    );
+}
+
+#[test]
+fn lexes_what_the_options_relax() {
+    let options = ValidateOptions::json5().bom_policy(BomPolicy::Allow);
+    assert_eq!(
+        tokens("\u{FEFF}{ key: 'x', true: +0x1F, n: .5 }", options),
+        [
+            Ok((TokenKind::BeginObject, 1, 1)),
+            Ok((TokenKind::Identifier, 3, 3)),
+            Ok((TokenKind::Colon, 6, 1)),
+            Ok((TokenKind::String, 8, 3)),
+            Ok((TokenKind::Comma, 11, 1)),
+            Ok((TokenKind::Identifier, 13, 4)),
+            Ok((TokenKind::Colon, 17, 1)),
+            Ok((TokenKind::Number, 19, 5)),
+            Ok((TokenKind::Comma, 24, 1)),
+            Ok((TokenKind::Identifier, 26, 1)),
+            Ok((TokenKind::Colon, 27, 1)),
+            Ok((TokenKind::Number, 29, 2)),
+            Ok((TokenKind::EndObject, 32, 1)),
+        ]
+    );
+    assert_eq!(
+        tokens("[Infinity,\u{00A0}NaN]", ValidateOptions::json5()),
+        [
+            Ok((TokenKind::BeginArray, 0, 1)),
+            Ok((TokenKind::Number, 1, 8)),
+            Ok((TokenKind::Comma, 9, 1)),
+            Ok((TokenKind::Number, 11, 3)),
+            Ok((TokenKind::EndArray, 14, 1)),
+        ]
+    );
+}
+
+#[test]
+fn stops_at_the_first_error() {
+    assert_eq!(
+        tokens("[1, tru]", ValidateOptions::default()),
+        [
+            Ok((TokenKind::BeginArray, 0, 1)),
+            Ok((TokenKind::Number, 1, 1)),
+            Ok((TokenKind::Comma, 2, 1)),
+            Err((
+                ErrorKind::InvalidLiteral,
+                String::from("Expected \"true\", but found \"tru\""),
+                1,
+                5
+            )),
+        ]
+    );
+    assert_eq!(
+        tokens("{a: 1}", ValidateOptions::default()),
+        [
+            Ok((TokenKind::BeginObject, 0, 1)),
+            Err((
+                ErrorKind::Syntax,
+                String::from("Unknown character: \"a\""),
+                1,
+                2
+            )),
+        ]
+    );
+}
+
+#[test]
+fn skips_a_bom_only_when_allowed() {
+    assert_eq!(
+        tokens("\u{FEFF}[1]", ValidateOptions::default()),
+        [Err((
+            ErrorKind::Syntax,
+            String::from("Unknown character: \"\u{FEFF}\""),
+            1,
+            1
+        ))]
+    );
+
+    // Columns do not count the skipped mark.
+    let options = ValidateOptions::default().bom_policy(BomPolicy::Allow);
+    assert_eq!(
+        tokens("\u{FEFF}[1, x]", options).pop(),
+        Some(Err((
+            ErrorKind::Syntax,
+            String::from("Unknown character: \"x\""),
+            1,
+            5
+        )))
+    );
+}
+
+#[test]
+fn reports_its_position() {
+    let document = UTF8Reader::new(" [1] ");
+    let mut tokenizer = Tokenizer::new(&document);
+    assert_eq!(tokenizer.position(), 0);
+    tokenizer.next();
+    assert_eq!(tokenizer.position(), 2);
+    assert_eq!(tokenizer.by_ref().count(), 2);
+    assert_eq!(tokenizer.position(), 5);
+}
@@ -0,0 +1,67 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, TopLevelType, UTF8Reader, ValidateOptions,
+};
+
+fn check(input: &str, top_level: TopLevelType) -> Result<(), String> {
+    let options = ValidateOptions::default().require_top_level(top_level);
+    return validate_with_options(&UTF8Reader::new(input), &options)
+        .map_err(|error| String::from(error.reason()));
+}
+
+#[test]
+fn rejects_a_bare_number_when_an_object_is_required() {
+    let options = ValidateOptions::default().require_top_level(TopLevelType::Object);
+    let error = validate_with_options(&UTF8Reader::new("  42 "), &options).unwrap_err();
+    assert_eq!(
+        error.reason(),
+        "Top-level value should be an object, but found number"
+    );
+    assert_eq!(error.column(), 3);
+
+    assert!(parse_with_options(&UTF8Reader::new("42"), &options).is_err());
+}
+
+#[test]
+fn accepts_only_the_required_types() {
+    let samples = ["{}", "[]", "1", "\"s\"", "true", "null"];
+    for (top_level, allowed) in [
+        (TopLevelType::Any, &samples[..]),
+        (TopLevelType::Object, &samples[..1]),
+        (TopLevelType::Array, &samples[1..2]),
+        (TopLevelType::ObjectOrArray, &samples[..2]),
+    ] {
+        for sample in samples {
+            assert_eq!(
+                check(sample, top_level).is_ok(),
+                allowed.contains(&sample),
+                "{} under {:?}",
+                sample,
+                top_level
+            );
+        }
+    }
+}
+
+#[test]
+fn names_what_was_found() {
+    assert_eq!(
+        check("[1]", TopLevelType::Object),
+        Err(String::from(
+            "Top-level value should be an object, but found array"
+        ))
+    );
+    assert_eq!(
+        check("\"text\"", TopLevelType::ObjectOrArray),
+        Err(String::from(
+            "Top-level value should be an object or an array, but found string"
+        ))
+    );
+}
+
+#[test]
+fn syntax_errors_come_first() {
+    let reason = check("[1,]", TopLevelType::Object).unwrap_err();
+    assert!(reason.starts_with("Invalid value at $[1]"), "{}", reason);
+}
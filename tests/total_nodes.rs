@@ -0,0 +1,50 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, ErrorKind, UTF8Reader, ValidateOptions,
+};
+
+fn flat_array(elements: usize) -> String {
+    return format!("[{}]", vec!["0"; elements].join(","));
+}
+
+#[test]
+fn counts_the_array_and_every_element() {
+    let options = ValidateOptions::default().max_total_nodes(1_000);
+
+    // The array itself is one of the values.
+    let at_cap = flat_array(999);
+    assert!(validate_with_options(&UTF8Reader::new(&at_cap), &options).is_ok());
+
+    let over_cap = flat_array(1_000);
+    let reader = UTF8Reader::new(&over_cap);
+    let error = validate_with_options(&reader, &options).unwrap_err();
+    assert_eq!(error.kind(), &ErrorKind::TooManyNodes { limit: 1_000 });
+    assert_eq!(error.kind().code(), "TOO_MANY_NODES");
+    assert_eq!(error.path(), "$[999]");
+    assert_eq!(error.index(), reader.len() - 2);
+    assert!(error
+        .reason()
+        .ends_with("Document has more than 1000 values"));
+}
+
+#[test]
+fn counts_across_nesting_and_keys_are_not_values() {
+    let input = r#"{"a": [1, {"b": null}], "c": "d"}"#;
+    let reader = UTF8Reader::new(input);
+
+    // The object, the array, 1, the inner object, null and "d".
+    let options = ValidateOptions::default().max_total_nodes(6);
+    assert!(validate_with_options(&reader, &options).is_ok());
+    assert!(parse_with_options(&reader, &options).is_ok());
+
+    let options = ValidateOptions::default().max_total_nodes(5);
+    let error = validate_with_options(&reader, &options).unwrap_err();
+    assert_eq!(error.path(), "$.c");
+}
+
+#[test]
+fn no_limit_by_default() {
+    let input = flat_array(100_000);
+    assert!(validate_with_options(&UTF8Reader::new(&input), &ValidateOptions::default()).is_ok());
+}
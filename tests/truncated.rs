@@ -0,0 +1,96 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{iter_top_level_array, validate_str, IncrementalValidator, UTF8Reader};
+
+// Each document ends right where a container still wants more.
+const CASES: [(&str, &str); 11] = [
+    ("{", r#"an object key or "}""#),
+    ("{ ", r#"an object key or "}""#),
+    (r#"{"a""#, r#"":""#),
+    (r#"{"a":"#, "a value"),
+    (r#"{"a": 1"#, r#""," or "}""#),
+    (r#"{"a": 1,"#, "an object key"),
+    ("[", r#"a value or "]""#),
+    ("[\n", r#"a value or "]""#),
+    ("[1", r#""," or "]""#),
+    ("[1,", "the next element"),
+    (r#"[[1, 2], {"b": ["#, r#"a value or "]""#),
+];
+
+fn expected_reason(expected: &str) -> String {
+    return format!("Unexpected end of input, expected {}", expected);
+}
+
+#[test]
+fn validator_names_what_was_expected() {
+    for (document, expected) in CASES {
+        let error = validate_str(document).unwrap_err();
+        let reason = error.reason();
+        assert!(reason.ends_with(&expected_reason(expected)), "{}", reason);
+        assert_eq!(error.index(), document.chars().count(), "{}", document);
+    }
+}
+
+#[test]
+fn incremental_validator_agrees() {
+    for (document, expected) in CASES {
+        let mut validator = IncrementalValidator::new();
+        validator.feed(document).unwrap();
+        let error = validator.finish().unwrap_err();
+        let reason = error.reason();
+        assert!(reason.ends_with(&expected_reason(expected)), "{}", reason);
+        assert_eq!(reason, validate_str(document).unwrap_err().reason());
+        assert_eq!(error.index(), document.chars().count(), "{}", document);
+    }
+}
+
+#[test]
+fn chunk_boundaries_do_not_matter() {
+    for (document, _) in CASES {
+        let mut validator = IncrementalValidator::new();
+        for chr in document.chars() {
+            validator.feed(&chr.to_string()).unwrap();
+        }
+        let error = validator.finish().unwrap_err();
+        assert_eq!(
+            error.reason(),
+            validate_str(document).unwrap_err().reason(),
+            "{}",
+            document
+        );
+    }
+}
+
+#[test]
+fn array_iterator_agrees() {
+    for (document, expected) in [
+        ("[", r#"a value or "]""#),
+        ("[1", r#""," or "]""#),
+        ("[1, ", "the next element"),
+    ] {
+        let reader = UTF8Reader::new(document);
+        let error = iter_top_level_array(&reader)
+            .unwrap()
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(error.reason(), expected_reason(expected), "{}", document);
+    }
+}
+
+#[test]
+fn nested_containers_keep_their_path() {
+    let error = validate_str(r#"[[1, 2], {"b": ["#).unwrap_err();
+    assert_eq!(error.path(), "$[1].b");
+}
+
+#[test]
+fn truncated_values_keep_their_own_reasons() {
+    assert_eq!(
+        validate_str("[1, -").unwrap_err().reason(),
+        "Invalid value at $[1]: Number has no digits after leading minus"
+    );
+    assert_eq!(
+        validate_str(r#"{"a": tr"#).unwrap_err().reason(),
+        r#"Invalid value at $.a: Expected "true", but found "tr""#
+    );
+}
@@ -0,0 +1,128 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_with_options, ErrorKind, UTF8Reader, ValidateOptions};
+
+fn check(input: &str, options: &ValidateOptions) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate_with_options(&UTF8Reader::new(input), options).map_err(|error| {
+        (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        )
+    });
+}
+
+fn stray(name: &str) -> String {
+    return format!(
+        "Unexpected {}; only space, tab, line feed and carriage return may separate tokens, so remove it",
+        name
+    );
+}
+
+#[test]
+fn names_the_code_point_in_strict_mode() {
+    let strict = ValidateOptions::default();
+    assert_eq!(
+        check("[1,\u{00A0}2]", &strict),
+        Err((
+            ErrorKind::StrayCharacter,
+            stray("U+00A0 (no-break space)"),
+            1,
+            4
+        ))
+    );
+    assert_eq!(
+        check("\u{2028}{}", &strict),
+        Err((
+            ErrorKind::StrayCharacter,
+            stray("U+2028 (line separator)"),
+            1,
+            1
+        ))
+    );
+    assert_eq!(
+        check("{\"a\":\u{3000}1}", &strict),
+        Err((
+            ErrorKind::StrayCharacter,
+            stray("U+3000 (ideographic space)"),
+            1,
+            6
+        ))
+    );
+    assert_eq!(
+        check("1\u{000B}", &strict),
+        Err((
+            ErrorKind::StrayCharacter,
+            stray("U+000B (line tabulation)"),
+            1,
+            2
+        ))
+    );
+}
+
+#[test]
+fn locates_whitespace_right_after_a_value() {
+    assert_eq!(
+        check("[1\u{2029}]", &ValidateOptions::default()),
+        Err((
+            ErrorKind::StrayCharacter,
+            format!(
+                "Invalid value at $[0]: {}",
+                stray("U+2029 (paragraph separator)")
+            ),
+            1,
+            3
+        ))
+    );
+}
+
+#[test]
+fn names_a_byte_order_mark_in_the_middle() {
+    assert_eq!(
+        check("[1,\u{FEFF}2]", &ValidateOptions::default()),
+        Err((
+            ErrorKind::StrayCharacter,
+            String::from("Unexpected byte order mark"),
+            1,
+            4
+        ))
+    );
+}
+
+#[test]
+fn accepts_unicode_whitespace_when_allowed() {
+    let options = ValidateOptions::default().allow_unicode_whitespace(true);
+    for input in [
+        "[1,\u{00A0}2]",
+        "\u{2028}{}",
+        "{\"a\":\u{3000}1}",
+        "[1\u{2029}]",
+        "1\u{000B}",
+    ] {
+        assert_eq!(check(input, &options), Ok(()), "{:?}", input);
+    }
+    assert!(check("[1]\u{00A0}", &ValidateOptions::json5()).is_ok());
+}
+
+#[test]
+fn still_rejects_a_nul_byte_when_allowed() {
+    let options = ValidateOptions::default().allow_unicode_whitespace(true);
+    assert_eq!(
+        check("[\u{0}]", &options),
+        Err((
+            ErrorKind::StrayCharacter,
+            String::from("Unexpected NUL byte"),
+            1,
+            2
+        ))
+    );
+}
+
+#[test]
+fn leaves_unicode_whitespace_inside_strings_alone() {
+    assert_eq!(
+        check("\"a\u{00A0}b\u{2028}\"", &ValidateOptions::default()),
+        Ok(())
+    );
+}
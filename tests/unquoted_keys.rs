@@ -0,0 +1,105 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    parse_with_options, validate_with_options, ErrorKind, JsonValue, UTF8Reader, ValidateOptions,
+};
+
+fn check(input: &str, options: &ValidateOptions) -> Result<(), (ErrorKind, String, usize, usize)> {
+    return validate_with_options(&UTF8Reader::new(input), options).map_err(|error| {
+        return (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+        );
+    });
+}
+
+fn unquoted_keys() -> ValidateOptions {
+    return ValidateOptions::default().allow_unquoted_keys(true);
+}
+
+#[test]
+fn unquoted_keys_are_rejected_by_default() {
+    assert_eq!(
+        check("{a: 1}", &ValidateOptions::default()),
+        Err((
+            ErrorKind::InvalidKey,
+            String::from("Object key should be a valid string"),
+            1,
+            2
+        ))
+    );
+}
+
+#[test]
+fn identifier_keys_are_accepted() {
+    for input in [
+        "{a: 1}",
+        "{_x$1: 1, $: 2}",
+        "{é: 1}",
+        "{true: 1, null: 2}",
+        "{a: 1, \"b\": 2}",
+    ] {
+        assert_eq!(check(input, &unquoted_keys()), Ok(()), "{}", input);
+    }
+
+    let value = parse_with_options(&UTF8Reader::new("{_x$1: 1, $: true}"), &unquoted_keys());
+    assert_eq!(
+        value.unwrap().get("_x$1").and_then(JsonValue::as_f64),
+        Some(1.0)
+    );
+}
+
+#[test]
+fn keys_that_are_not_identifiers_are_rejected() {
+    assert_eq!(
+        check("{1a: 1}", &unquoted_keys()),
+        Err((
+            ErrorKind::InvalidKey,
+            String::from("Identifier should start with a letter, \"_\" or \"$\""),
+            1,
+            2
+        ))
+    );
+    assert_eq!(
+        check("{a-b: 1}", &unquoted_keys()),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Invalid character after object key: \"-\""),
+            1,
+            3
+        ))
+    );
+    assert_eq!(
+        check("{a b: 1}", &unquoted_keys()),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Invalid character after object key: \"b\""),
+            1,
+            4
+        ))
+    );
+    assert_eq!(
+        check("{a", &unquoted_keys()),
+        Err((
+            ErrorKind::UnexpectedEnd,
+            String::from("Unexpected end of input, expected \":\""),
+            1,
+            3
+        ))
+    );
+}
+
+#[test]
+fn identifiers_are_only_keys() {
+    assert_eq!(
+        check("[a]", &unquoted_keys()),
+        Err((
+            ErrorKind::Syntax,
+            String::from("Invalid value at $[0]: Unknown character: \"a\""),
+            1,
+            2
+        ))
+    );
+}
@@ -0,0 +1,77 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    validate, validate_with_options, BomPolicy, IncrementalValidator, UTF8Reader, ValidateOptions,
+};
+
+/// A pretty printed object cut off partway through the long string value
+/// of member `cut`, as a truncated upload would be.
+fn truncated_object(members: usize, cut: usize) -> String {
+    let mut document = String::from("{\n");
+    for i in 0..members {
+        let value = "lorem ipsum dolor sit amet ".repeat(20);
+        document.push_str(&format!("  \"key{}\": \"{}\",\n", i, value));
+        if i == cut {
+            document.truncate(document.len() - 100);
+            break;
+        }
+    }
+    return document;
+}
+
+#[test]
+fn points_back_at_the_opening_quote() {
+    let document = truncated_object(5_000, 1_234);
+    let reader = UTF8Reader::new(&document);
+    let error = validate(&reader).unwrap_err();
+
+    // The string swallows the rest of the document, so the error is at the end.
+    assert_eq!(error.index(), reader.len());
+
+    let opened_at = error.opened_at().unwrap();
+    let offset = reader.byte_offset_of(opened_at);
+    assert!(document[offset..].starts_with("\"lorem ipsum"));
+    assert!(document[..offset].ends_with("\"key1234\": "));
+    assert!(
+        error
+            .reason()
+            .ends_with("Incomplete string value, opened at line 1236, column 14"),
+        "{}",
+        error.reason()
+    );
+}
+
+#[test]
+fn counts_the_opening_column_past_a_byte_order_mark() {
+    let document = "\u{FEFF}[\"abc";
+    let options = ValidateOptions::default().bom_policy(BomPolicy::Allow);
+    let error = validate_with_options(&UTF8Reader::new(document), &options).unwrap_err();
+    assert_eq!(error.opened_at(), Some(2));
+    assert!(error.reason().ends_with("opened at line 1, column 2"));
+}
+
+#[test]
+fn only_unterminated_strings_have_an_opening() {
+    let error = validate(&UTF8Reader::new("[1, 2")).unwrap_err();
+    assert_eq!(error.opened_at(), None);
+}
+
+#[test]
+fn incremental_validation_agrees() {
+    let document = truncated_object(200, 150);
+    let mut validator = IncrementalValidator::new();
+    for chunk in document.as_bytes().chunks(64) {
+        validator
+            .feed(core::str::from_utf8(chunk).unwrap())
+            .unwrap();
+    }
+    let error = validator.finish().unwrap_err();
+
+    let expected = validate(&UTF8Reader::new(&document)).unwrap_err();
+    assert_eq!(error.opened_at(), expected.opened_at());
+    assert_eq!(error.reason(), expected.reason());
+    assert_eq!(
+        error.reason(),
+        "Invalid value at $.key150: Incomplete string value, opened at line 152, column 13"
+    );
+}
@@ -0,0 +1,131 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_utf16, Endianness, ErrorKind, ValidateResult};
+
+fn encode(text: &str, endianness: Endianness, bom: bool) -> Vec<u8> {
+    let mut units: Vec<u16> = text.encode_utf16().collect();
+    if bom {
+        units.insert(0, 0xFEFF);
+    }
+    return units
+        .iter()
+        .flat_map(|unit| match endianness {
+            Endianness::Little => unit.to_le_bytes(),
+            Endianness::Big => unit.to_be_bytes(),
+        })
+        .collect();
+}
+
+fn check(result: ValidateResult) -> Result<(), (ErrorKind, String, usize, usize, usize)> {
+    return result.map_err(|error| {
+        (
+            error.kind().clone(),
+            String::from(error.reason()),
+            error.line(),
+            error.column(),
+            error.byte_offset(),
+        )
+    });
+}
+
+#[test]
+fn validates_both_byte_orders_with_or_without_a_bom() {
+    let text = "{\"a\": [\"😀\", null]}";
+    for endianness in [Endianness::Little, Endianness::Big] {
+        for bom in [false, true] {
+            let input = encode(text, endianness, bom);
+            assert_eq!(check(validate_utf16(&input, endianness)), Ok(()));
+        }
+    }
+}
+
+#[test]
+fn locates_json_errors_in_utf16_bytes() {
+    let expected = |byte_offset| {
+        return Err((
+            ErrorKind::InvalidLiteral,
+            String::from("Invalid value at $[1]: Expected \"true\", but found \"tru\""),
+            1,
+            7,
+            byte_offset,
+        ));
+    };
+    let text = "[\"😀\", tru]";
+    assert_eq!(
+        check(validate_utf16(
+            &encode(text, Endianness::Little, true),
+            Endianness::Little
+        )),
+        expected(16)
+    );
+    assert_eq!(
+        check(validate_utf16(
+            &encode(text, Endianness::Big, false),
+            Endianness::Big
+        )),
+        expected(14)
+    );
+}
+
+#[test]
+fn counts_lines_in_the_decoded_text() {
+    let input = encode("[1,\n2,]", Endianness::Little, false);
+    assert_eq!(
+        check(validate_utf16(&input, Endianness::Little)),
+        Err((
+            ErrorKind::TrailingComma,
+            String::from("Invalid value at $[2]: Trailing comma before \"]\""),
+            2,
+            3,
+            12
+        ))
+    );
+}
+
+#[test]
+fn rejects_a_bom_of_the_other_byte_order() {
+    let input = encode("[1]", Endianness::Big, true);
+    assert_eq!(
+        check(validate_utf16(&input, Endianness::Little)),
+        Err((
+            ErrorKind::Encoding,
+            String::from("Byte order mark shows the input is big-endian, not little-endian"),
+            1,
+            1,
+            0
+        ))
+    );
+}
+
+#[test]
+fn rejects_an_unpaired_surrogate() {
+    let mut input = encode("[\"a", Endianness::Little, false);
+    input.extend([0x00, 0xD8]);
+    input.extend(encode("\"]", Endianness::Little, false));
+    assert_eq!(
+        check(validate_utf16(&input, Endianness::Little)),
+        Err((
+            ErrorKind::Encoding,
+            String::from("Unpaired surrogate 0xD800 in UTF-16 input"),
+            1,
+            4,
+            6
+        ))
+    );
+}
+
+#[test]
+fn rejects_a_lone_trailing_byte() {
+    let mut input = encode("[1]", Endianness::Little, false);
+    input.push(0);
+    assert_eq!(
+        check(validate_utf16(&input, Endianness::Little)),
+        Err((
+            ErrorKind::Encoding,
+            String::from("UTF-16 input ends with a lone byte"),
+            1,
+            4,
+            6
+        ))
+    );
+}
@@ -0,0 +1,34 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{validate_many, validate_str};
+
+/// Valid and invalid documents interleaved, so a result landing in the
+/// wrong slot would show.
+fn documents() -> Vec<String> {
+    return (0..2_000)
+        .map(|i| match i % 5 {
+            0 => format!("{{\"id\": {}, \"tags\": [\"a\", \"b\"]}}", i),
+            1 => format!("[{}, ]", i),
+            2 => format!("\"unterminated {}", i),
+            3 => format!("{}", i),
+            _ => format!("{{\"id\": {}\n  \"missing\": \"comma\"}}", i),
+        })
+        .collect();
+}
+
+#[test]
+fn matches_sequential_validation() {
+    let documents = documents();
+    let documents: Vec<&str> = documents.iter().map(String::as_str).collect();
+
+    let results = validate_many(&documents);
+    assert_eq!(results.len(), documents.len());
+    for (document, result) in documents.iter().zip(results) {
+        assert_eq!(result, validate_str(document), "{}", document);
+    }
+}
+
+#[test]
+fn empty_slice() {
+    assert!(validate_many(&[]).is_empty());
+}
@@ -0,0 +1,88 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{parse, walk, JsonValue, PathSegment, UTF8Reader};
+
+fn format_path(path: &[PathSegment]) -> String {
+    return path
+        .iter()
+        .map(|segment| match segment {
+            PathSegment::Key(key) => format!(".{}", key),
+            PathSegment::Index(index) => format!("[{}]", index),
+        })
+        .collect();
+}
+
+#[test]
+fn collects_every_string_with_its_path() {
+    let document = r#"{
+        "name": "rust-json",
+        "tags": ["json", 5, {"nested": "deep"}],
+        "meta": {"empty": {}, "owner": {"login": "afterwind"}},
+        "version": 1
+    }"#;
+    let value = parse(&UTF8Reader::new(document)).unwrap();
+
+    let mut strings = vec![];
+    walk(&value, |node, path| {
+        if let Some(s) = node.as_str() {
+            strings.push((format_path(path), String::from(s)));
+        }
+    });
+
+    assert_eq!(
+        strings,
+        vec![
+            (String::from(".name"), String::from("rust-json")),
+            (String::from(".tags[0]"), String::from("json")),
+            (String::from(".tags[2].nested"), String::from("deep")),
+            (String::from(".meta.owner.login"), String::from("afterwind")),
+        ]
+    );
+}
+
+#[test]
+fn visits_containers_before_their_children() {
+    let value = parse(&UTF8Reader::new(r#"[[1], {"a": null}]"#)).unwrap();
+
+    let mut visited = vec![];
+    walk(&value, |node, path| {
+        let kind = match node {
+            JsonValue::Array(_) => "array",
+            JsonValue::Object(_) => "object",
+            JsonValue::Number(_) => "number",
+            JsonValue::Null => "null",
+            _ => "other",
+        };
+        visited.push(format!("{} {}", format_path(path), kind));
+    });
+
+    assert_eq!(
+        visited,
+        vec![
+            " array",
+            "[0] array",
+            "[0][0] number",
+            "[1] object",
+            "[1].a null"
+        ]
+    );
+}
+
+#[test]
+fn scalar_roots_are_visited_once() {
+    let mut count = 0;
+    walk(&JsonValue::Bool(true), |_, path| {
+        assert!(path.is_empty());
+        count += 1;
+    });
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn sums_numbers() {
+    let value = parse(&UTF8Reader::new(r#"{"a": [1, 2.5], "b": {"c": -0.5}}"#)).unwrap();
+
+    let mut sum = 0.0;
+    walk(&value, |node, _| sum += node.as_f64().unwrap_or_default());
+    assert_eq!(sum, 3.0);
+}
@@ -0,0 +1,77 @@
+#![allow(clippy::needless_return)]
+
+use rust_json::{
+    validate_with_options, validate_with_warnings, ErrorKind, UTF8Reader, ValidateOptions,
+};
+
+fn warnings(input: &str, limit: usize) -> Vec<(ErrorKind, String, usize, usize)> {
+    let options = ValidateOptions::default().warn_depth(limit);
+    let (result, warnings) = validate_with_warnings(&UTF8Reader::new(input), &options);
+    assert!(result.is_ok(), "{:?}", input);
+    return warnings
+        .iter()
+        .map(|warning| {
+            (
+                warning.kind().clone(),
+                String::from(warning.reason()),
+                warning.line(),
+                warning.column(),
+            )
+        })
+        .collect();
+}
+
+fn too_deep(limit: usize, line: usize, column: usize) -> (ErrorKind, String, usize, usize) {
+    return (
+        ErrorKind::DeepNesting { limit },
+        format!(
+            "Nesting depth exceeds the warning threshold of {} levels",
+            limit
+        ),
+        line,
+        column,
+    );
+}
+
+#[test]
+fn stays_quiet_at_the_threshold() {
+    assert_eq!(warnings("[[1]]", 2), vec![]);
+    assert_eq!(warnings("{\"a\": {\"b\": 1}}", 2), vec![]);
+}
+
+#[test]
+fn warns_past_the_soft_limit_without_rejecting() {
+    let input = "[[[1]]]";
+    assert_eq!(warnings(input, 2), vec![too_deep(2, 1, 3)]);
+
+    let options = ValidateOptions::default().warn_depth(2);
+    assert!(validate_with_options(&UTF8Reader::new(input), &options).is_ok());
+}
+
+#[test]
+fn warns_at_each_container_crossing_the_threshold() {
+    assert_eq!(
+        warnings("{\"a\": [{\"b\": 1}, [[2]]]}", 2),
+        vec![too_deep(2, 1, 8), too_deep(2, 1, 18)]
+    );
+    assert_eq!(
+        warnings("[[[1], [2]],\n [[[3]]]]", 2),
+        vec![too_deep(2, 1, 3), too_deep(2, 1, 8), too_deep(2, 2, 3)]
+    );
+}
+
+#[test]
+fn keeps_warnings_when_the_document_is_invalid() {
+    let options = ValidateOptions::default().warn_depth(2);
+    let (result, warnings) = validate_with_warnings(&UTF8Reader::new("[[[1]]"), &options);
+    assert_eq!(result.unwrap_err().kind(), &ErrorKind::UnexpectedEnd);
+    assert_eq!(warnings.len(), 1);
+}
+
+#[test]
+fn still_rejects_past_the_hard_limit() {
+    let input = format!("{}{}", "[".repeat(101), "]".repeat(101));
+    let options = ValidateOptions::default().warn_depth(50);
+    let (result, _) = validate_with_warnings(&UTF8Reader::new(&input), &options);
+    assert_eq!(result.unwrap_err().kind(), &ErrorKind::TooDeep);
+}
@@ -0,0 +1,76 @@
+#![allow(clippy::needless_return)]
+#![cfg(feature = "std")]
+
+use rust_json::{
+    parse, parse_with_options, to_string, to_string_with_options, to_writer_pretty, write_json,
+    write_json_pretty, write_json_with_options, LineEnding, PrettyOptions, SerializeOptions,
+    UTF8Reader, ValidateOptions,
+};
+
+const INPUT: &str = r#"{"a": [1, {}], "b": "x\ny"}"#;
+
+fn written(write: impl FnOnce(&mut Vec<u8>) -> std::io::Result<()>) -> String {
+    let mut output = Vec::new();
+    write(&mut output).unwrap();
+    return String::from_utf8(output).unwrap();
+}
+
+#[test]
+fn write_json_reports_non_finite_numbers_as_invalid_data() {
+    let value = parse_with_options(&UTF8Reader::new("[NaN]"), &ValidateOptions::json5()).unwrap();
+    let error = to_string(&value).unwrap_err();
+    assert_eq!(error.lexeme(), "NaN");
+
+    let mut written = Vec::new();
+    let error = write_json(&value, &mut written).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(error.to_string(), "NaN is not a finite number");
+}
+
+#[test]
+fn writers_follow_the_same_options() {
+    let value = parse(&UTF8Reader::new(INPUT)).unwrap();
+    let options = SerializeOptions {
+        indent: Some(4),
+        line_ending: LineEnding::CrLf,
+        trailing_newline: true,
+        ..SerializeOptions::default()
+    };
+
+    assert_eq!(
+        written(|out| write_json_with_options(&value, out, &options)),
+        to_string_with_options(&value, &options).unwrap()
+    );
+}
+
+#[test]
+fn to_writer_pretty_uses_the_line_ending_and_trailing_newline() {
+    let value = parse(&UTF8Reader::new(INPUT)).unwrap();
+    let options = PrettyOptions {
+        indent: 2,
+        line_ending: LineEnding::CrLf,
+        trailing_newline: true,
+    };
+    assert_eq!(
+        written(|out| to_writer_pretty(&value, out, &options)),
+        "{\r\n  \"a\": [\r\n    1,\r\n    {}\r\n  ],\r\n  \"b\": \"x\\ny\"\r\n}\r\n"
+    );
+
+    let options = PrettyOptions {
+        indent: 1,
+        ..PrettyOptions::default()
+    };
+    assert_eq!(
+        written(|out| to_writer_pretty(&value, out, &options)),
+        "{\n \"a\": [\n  1,\n  {}\n ],\n \"b\": \"x\\ny\"\n}"
+    );
+}
+
+#[test]
+fn to_writer_pretty_defaults_match_write_json_pretty() {
+    let value = parse(&UTF8Reader::new(INPUT)).unwrap();
+    assert_eq!(
+        written(|out| to_writer_pretty(&value, out, &PrettyOptions::default())),
+        written(|out| write_json_pretty(&value, out))
+    );
+}